@@ -54,7 +54,7 @@ fn parsing_combinators_benchmark(c: &mut Criterion) {
     });
 }
 
-fn parse_bod_discard_comma(i: &str) -> Result<MockBodData, Error> {
+fn parse_bod_discard_comma(i: &str) -> Result<MockBodData<'_>, Error<'_>> {
     // 1. Bearing Degrees, True
     let (i, bearing_true) = opt(map_parser(take_until(","), float))(i)?;
     let (i, _) = char(',')(i)?;
@@ -88,7 +88,7 @@ fn parse_bod_discard_comma(i: &str) -> Result<MockBodData, Error> {
     })
 }
 
-fn parse_bod_with_preceded(i: &str) -> Result<MockBodData, Error> {
+fn parse_bod_with_preceded(i: &str) -> Result<MockBodData<'_>, Error<'_>> {
     // 1. Bearing Degrees, True
     let (i, bearing_true) = opt(map_parser(take_until(","), float))(i)?;
 
@@ -0,0 +1,141 @@
+//! Compact formatting of optional fields.
+//!
+//! Most of the sentence fields are optional and logging a sentence with the
+//! derived formatting renders every absent field as `None`. [`Compact`]
+//! renders an absent value as `-` and a present one as the bare value, which
+//! keeps the (embedded) logs short. [`core::fmt::Display`], [`core::fmt::Debug`]
+//! and, with the `defmt-03` feature, `defmt::Format` are implemented.
+//!
+//! [`ApaData`](crate::sentences::ApaData) and
+//! [`TtmData`](crate::sentences::TtmData) use it for both their `Display`
+//! and `defmt::Format` implementations, which share the same layout. The
+//! other data structs keep the derived formatting.
+
+use core::fmt;
+
+/// Formats `None` as `-` and `Some(value)` as `value`.
+///
+/// # Examples
+///
+/// ```
+/// use nmea::compact::Compact;
+///
+/// assert_eq!(format!("{}", Compact(&Some(1.5))), "1.5");
+/// assert_eq!(format!("{}", Compact(&None::<f32>)), "-");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Compact<'a, T>(pub &'a Option<T>);
+
+impl<T: fmt::Display> fmt::Display for Compact<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(value) => value.fmt(f),
+            None => f.write_str("-"),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Compact<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(value) => value.fmt(f),
+            None => f.write_str("-"),
+        }
+    }
+}
+
+#[cfg(feature = "defmt-03")]
+impl<T: defmt::Format> defmt::Format for Compact<'_, T> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        match self.0 {
+            Some(value) => defmt::write!(f, "{}", value),
+            None => defmt::write!(f, "-"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::fmt::Write;
+
+    use heapless::String;
+
+    use super::*;
+    use crate::sentences::{
+        ApaData, Reference, SteerDirection, TtmData, TtmDistanceUnit, TtmTypeOfAcquisition,
+    };
+
+    #[test]
+    fn test_compact_none_fields() {
+        let mut buffer = String::<64>::new();
+        write!(
+            buffer,
+            "{} {} {} {}",
+            Compact(&Some(0.1_f32)),
+            Compact(&None::<f32>),
+            Compact(&Some(true)),
+            Compact(&None::<bool>)
+        )
+        .unwrap();
+        assert_eq!(buffer, "0.1 - true -");
+    }
+
+    #[test]
+    fn test_compact_debug() {
+        let mut buffer = String::<64>::new();
+        write!(
+            buffer,
+            "{:?} {:?}",
+            Compact(&Some(SteerDirection::Left)),
+            Compact(&None::<SteerDirection>)
+        )
+        .unwrap();
+        assert_eq!(buffer, "Left -");
+    }
+
+    #[test]
+    fn test_compact_apa_data() {
+        let apa: ApaData = ApaData {
+            status_warning: Some(true),
+            status_cycle_warning: None,
+            cross_track_error_magnitude: Some(0.5),
+            steer_direction: Some(SteerDirection::Right),
+            cross_track_units: None,
+            status_arrived: None,
+            status_passed: Some(false),
+            bearing_origin_destination: None,
+            magnetic_true: Some(Reference::Magnetic),
+            waypoint_id: None,
+        };
+
+        let mut buffer = String::<128>::new();
+        write!(buffer, "{}", apa).unwrap();
+        assert_eq!(buffer, "APA true - 0.5 Right - - false - Magnetic -");
+    }
+
+    #[test]
+    fn test_compact_ttm_data() {
+        let ttm = TtmData {
+            target_number: Some(1),
+            target_distance: None,
+            bearing_from_own_ship: None,
+            target_speed: Some(12.1),
+            target_course: None,
+            distance_of_cpa: None,
+            time_to_cpa: None,
+            speed_or_distance_unit: Some(TtmDistanceUnit::NauticalMile),
+            target_name: Some(heapless::String::try_from("TGT01").unwrap()),
+            target_status: None,
+            is_target_reference: false,
+            time_of_data: None,
+            type_of_acquisition: Some(TtmTypeOfAcquisition::Automatic),
+        };
+
+        let mut buffer = String::<128>::new();
+        write!(buffer, "{}", ttm).unwrap();
+        assert_eq!(
+            buffer,
+            "TTM 1 - - 12.1 - - - NauticalMile TGT01 - false - Automatic"
+        );
+    }
+}
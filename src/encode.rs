@@ -0,0 +1,459 @@
+//! Encoding of the parsed data back into NMEA sentences.
+//!
+//! The data structs implementing [`ToNmeaSentence`] can be written as a
+//! complete sentence, including the `*hh` checksum, into any
+//! [`core::fmt::Write`] (e.g. a `heapless::String` or an `arrayvec::ArrayString`
+//! on `no_std` targets), into a byte slice or, with the `alloc` feature,
+//! encoded into a `String`.
+
+use core::fmt::{self, Write};
+
+use cfg_if::cfg_if;
+#[cfg(feature = "chrono")]
+use chrono::Timelike;
+
+use crate::{
+    sentences::{Duration, Time},
+    Error, NmeaSentence, SentenceType, SENTENCE_MAX_LEN,
+};
+
+/// Encodes a data struct as a NMEA 0183 sentence.
+///
+/// # Examples
+///
+/// ```
+/// use nmea::{encode::ToNmeaSentence, sentences::ThsData};
+///
+/// let ths = ThsData {
+///     heading: Some(338.01),
+///     mode: 'A',
+/// };
+///
+/// let mut sentence = heapless::String::<82>::new();
+/// ths.write_sentence("GP", &mut sentence).unwrap();
+/// assert_eq!(sentence, "$GPTHS,338.01,A*0E");
+/// ```
+pub trait ToNmeaSentence {
+    /// The type of the encoded sentence.
+    fn sentence_type(&self) -> SentenceType;
+
+    /// Writes the data fields, i.e. everything between the comma following
+    /// the sentence type and the `*` of the checksum.
+    ///
+    /// Returns [`fmt::Error`] if `f` fails or a field has a value which can't
+    /// be encoded, e.g. a negative duration.
+    fn write_data<W: Write>(&self, f: &mut W) -> fmt::Result;
+
+    /// Writes the complete `$<talker_id><type>,<data>*hh` sentence to `out`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::SentenceLength`] if the sentence would be longer than
+    ///   [`SENTENCE_MAX_LEN`] or doesn't fit into `out`.
+    /// - [`Error::InvalidFieldValue`] if a field has a value which can't be encoded.
+    fn write_sentence<W: Write>(&self, talker_id: &str, out: &mut W) -> Result<(), Error<'static>> {
+        let mut data = heapless::String::<SENTENCE_MAX_LEN>::new();
+        let written = self.write_data(&mut data);
+
+        let sentence_type = self.sentence_type();
+        let sentence_len = if written.is_ok() {
+            data.len() + talker_id.len() + sentence_type.as_str().len() + "$,*hh".len()
+        } else {
+            let mut counter = LengthCounter(0);
+            // counting only fails on the values which can't be encoded
+            self.write_data(&mut counter)
+                .map_err(|_| Error::InvalidFieldValue(sentence_type))?;
+            counter.0 + talker_id.len() + sentence_type.as_str().len() + "$,*hh".len()
+        };
+        if sentence_len > SENTENCE_MAX_LEN {
+            return Err(Error::SentenceLength(sentence_len));
+        }
+
+        let checksum = NmeaSentence {
+            talker_id,
+            message_id: sentence_type,
+            data: &data,
+            checksum: None,
+            tag_block: None,
+        }
+        .calc_checksum();
+
+        write!(
+            out,
+            "${}{},{}*{:02X}",
+            talker_id, sentence_type, data, checksum
+        )
+        .map_err(|_| Error::SentenceLength(sentence_len))
+    }
+
+    /// Writes the complete `$<talker_id><type>,<data>*hh` sentence into
+    /// `buf`, returning the number of written bytes.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`ToNmeaSentence::write_sentence`], with `buf` instead of `out`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nmea::{encode::ToNmeaSentence, sentences::ThsData};
+    ///
+    /// let ths = ThsData {
+    ///     heading: Some(338.01),
+    ///     mode: 'A',
+    /// };
+    ///
+    /// let mut buf = [0; 82];
+    /// let len = ths.write_to_slice("GP", &mut buf).unwrap();
+    /// assert_eq!(&buf[..len], b"$GPTHS,338.01,A*0E");
+    /// ```
+    fn write_to_slice(&self, talker_id: &str, buf: &mut [u8]) -> Result<usize, Error<'static>> {
+        let mut writer = SliceWriter { buf, len: 0 };
+        self.write_sentence(talker_id, &mut writer)?;
+        Ok(writer.len)
+    }
+
+    /// Encodes the complete `$<talker_id><type>,<data>*hh` sentence.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`ToNmeaSentence::write_sentence`].
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn encode(&self, talker_id: &str) -> Result<alloc::string::String, Error<'static>> {
+        let mut sentence = alloc::string::String::new();
+        self.write_sentence(talker_id, &mut sentence)?;
+        Ok(sentence)
+    }
+}
+
+/// Builds a sentence of any type field by field.
+///
+/// Unlike [`ToNmeaSentence`], which encodes a parsed data struct, the fields
+/// are given as raw values, e.g. to build sentences for test harnesses or
+/// simulators. NMEA 0183 has no escaping, so a field containing a `,` or `*`
+/// is rejected.
+///
+/// # Examples
+///
+/// ```
+/// use nmea::{encode::SentenceBuilder, Error, SentenceType};
+///
+/// let sentence = SentenceBuilder::new("GP", SentenceType::HDT)
+///     .field(274.07)?
+///     .field('T')?
+///     .build()?;
+/// assert_eq!(sentence, "$GPHDT,274.07,T*03");
+///
+/// let sentence = SentenceBuilder::new("GP", SentenceType::VTG)
+///     .field(360.0)?
+///     .field('T')?
+///     .field_opt(None::<f32>)?
+///     .field('M')?
+///     .build()?;
+/// assert_eq!(sentence, "$GPVTG,360,T,,M*7E");
+///
+/// assert_eq!(
+///     SentenceBuilder::new("GP", SentenceType::TXT).field("a,b").err(),
+///     Some(Error::InvalidFieldCharacter(','))
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct SentenceBuilder<'a> {
+    talker_id: &'a str,
+    sentence_type: SentenceType,
+    data: heapless::String<SENTENCE_MAX_LEN>,
+    fields: usize,
+}
+
+impl<'a> SentenceBuilder<'a> {
+    /// Starts a sentence without any field.
+    pub fn new(talker_id: &'a str, sentence_type: SentenceType) -> Self {
+        Self {
+            talker_id,
+            sentence_type,
+            data: heapless::String::new(),
+            fields: 0,
+        }
+    }
+
+    /// Appends a field written with its [`Display`](fmt::Display)
+    /// implementation, `""` appends an empty field.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidFieldCharacter`] if the value contains a `,` or `*`.
+    /// - [`Error::SentenceLength`] if the sentence gets longer than [`SENTENCE_MAX_LEN`].
+    pub fn field<T: fmt::Display>(mut self, value: T) -> Result<Self, Error<'static>> {
+        let sentence_len = self.sentence_len();
+        let separator_len = if self.fields > 0 {
+            // a full `data` is caught by the length check below
+            let _ = self.data.push(',');
+            1
+        } else {
+            0
+        };
+
+        let mut writer = FieldWriter {
+            data: &mut self.data,
+            len: 0,
+            invalid: None,
+        };
+        if write!(writer, "{}", value).is_err() {
+            if let Some(c) = writer.invalid {
+                return Err(Error::InvalidFieldCharacter(c));
+            }
+        }
+
+        let sentence_len = sentence_len + separator_len + writer.len;
+        if sentence_len > SENTENCE_MAX_LEN {
+            return Err(Error::SentenceLength(sentence_len));
+        }
+        self.fields += 1;
+        Ok(self)
+    }
+
+    /// Appends a field with the value if present, an empty field otherwise.
+    ///
+    /// # Errors
+    ///
+    /// The same as [`SentenceBuilder::field()`].
+    pub fn field_opt<T: fmt::Display>(self, value: Option<T>) -> Result<Self, Error<'static>> {
+        match value {
+            Some(value) => self.field(value),
+            None => self.field(""),
+        }
+    }
+
+    /// Writes the complete `$<talker_id><type>,<fields>*hh` sentence to `out`.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::SentenceLength`] if the sentence doesn't fit into `out`.
+    pub fn write_sentence<W: Write>(&self, out: &mut W) -> Result<(), Error<'static>> {
+        let checksum = NmeaSentence {
+            talker_id: self.talker_id,
+            message_id: self.sentence_type,
+            data: &self.data,
+            checksum: None,
+            tag_block: None,
+        }
+        .calc_checksum();
+
+        write!(
+            out,
+            "${}{},{}*{:02X}",
+            self.talker_id, self.sentence_type, self.data, checksum
+        )
+        .map_err(|_| Error::SentenceLength(self.sentence_len()))
+    }
+
+    /// Returns the complete `$<talker_id><type>,<fields>*hh` sentence.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::SentenceLength`] if the talker id is too long for the
+    /// sentence to fit in [`SENTENCE_MAX_LEN`].
+    pub fn build(&self) -> Result<heapless::String<SENTENCE_MAX_LEN>, Error<'static>> {
+        let mut sentence = heapless::String::new();
+        self.write_sentence(&mut sentence)?;
+        Ok(sentence)
+    }
+
+    /// Length of the complete sentence with the fields appended so far.
+    fn sentence_len(&self) -> usize {
+        self.talker_id.len() + self.sentence_type.as_str().len() + self.data.len() + "$,*hh".len()
+    }
+}
+
+/// [`Write`] appending a field to the data of a [`SentenceBuilder`].
+///
+/// Fails on a `,` or `*`, the written bytes are counted even once `data` is full.
+struct FieldWriter<'a> {
+    data: &'a mut heapless::String<SENTENCE_MAX_LEN>,
+    len: usize,
+    invalid: Option<char>,
+}
+
+impl Write for FieldWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if let Some(c) = s.chars().find(|c| matches!(c, ',' | '*')) {
+            self.invalid = Some(c);
+            return Err(fmt::Error);
+        }
+        self.len += s.len();
+        // the length is checked once the whole field is written
+        let _ = self.data.push_str(s);
+        Ok(())
+    }
+}
+
+/// [`Write`] counting the written bytes.
+struct LengthCounter(usize);
+
+impl Write for LengthCounter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+/// [`Write`] into a byte slice, failing once it's full.
+struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.buf
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Writes the value if present, nothing otherwise.
+pub(crate) fn write_opt<W: Write, T: fmt::Display>(f: &mut W, value: &Option<T>) -> fmt::Result {
+    match value {
+        Some(value) => write!(f, "{}", value),
+        None => Ok(()),
+    }
+}
+
+/// Writes a status as `A` (valid, `true`) or `V` (void, `false`).
+pub(crate) fn write_status<W: Write>(f: &mut W, status: Option<bool>) -> fmt::Result {
+    match status {
+        Some(true) => f.write_char('A'),
+        Some(false) => f.write_char('V'),
+        None => Ok(()),
+    }
+}
+
+/// Writes a time as `hhmmss.ss`.
+pub(crate) fn write_hms<W: Write>(f: &mut W, time: &Option<Time>) -> fmt::Result {
+    let Some(time) = time else {
+        return Ok(());
+    };
+    cfg_if! {
+        if #[cfg(feature = "chrono")] {
+            let (hour, minute, second) = (time.hour(), time.minute(), time.second());
+            let nanosecond = time.nanosecond();
+        } else {
+            let (hour, minute, second) = (time.hour, time.minute, time.second);
+            let nanosecond = time.nanosecond;
+        }
+    }
+    // a leap second is represented by the nanoseconds overflowing a second
+    let centis = ((nanosecond + 5_000_000) / 10_000_000).min(99);
+    write!(f, "{:02}{:02}{:02}.{:02}", hour, minute, second, centis)
+}
+
+/// Writes a duration as `hhmmss.ss`.
+///
+/// Fails on negative durations and durations of 24 hours or more, which
+/// `hhmmss.ss` can't represent.
+pub(crate) fn write_duration_hms<W: Write>(f: &mut W, duration: &Option<Duration>) -> fmt::Result {
+    let Some(duration) = duration else {
+        return Ok(());
+    };
+    cfg_if! {
+        if #[cfg(feature = "chrono")] {
+            if *duration < Duration::zero() {
+                return Err(fmt::Error);
+            }
+            let millis = duration.num_milliseconds();
+        } else {
+            let millis = i64::from(duration.milliseconds());
+        }
+    }
+    let centis = (millis + 5) / 10;
+    if centis >= 8_640_000 {
+        return Err(fmt::Error);
+    }
+    write!(
+        f,
+        "{:02}{:02}{:02}.{:02}",
+        centis / 360_000,
+        centis / 6_000 % 60,
+        centis / 100 % 60,
+        centis % 100
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sentences::ThsData;
+
+    #[test]
+    fn test_write_to_slice() {
+        let ths = ThsData {
+            heading: Some(338.01),
+            mode: 'A',
+        };
+
+        let mut buf = [0; 18];
+        assert_eq!(ths.write_to_slice("GP", &mut buf), Ok(18));
+        assert_eq!(&buf, b"$GPTHS,338.01,A*0E");
+
+        let mut buf = [0; 17];
+        assert_eq!(
+            ths.write_to_slice("GP", &mut buf),
+            Err(Error::SentenceLength(18))
+        );
+    }
+
+    #[test]
+    fn test_sentence_builder() {
+        let sentence = SentenceBuilder::new("GP", SentenceType::GLL)
+            .field("4916.45")
+            .and_then(|b| b.field('N'))
+            .and_then(|b| b.field_opt(None::<&str>))
+            .and_then(|b| b.field(""))
+            .and_then(|b| b.field_opt(Some(225444)))
+            .and_then(|b| b.field('A'))
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(sentence, "$GPGLL,4916.45,N,,,225444,A*7B");
+        assert!(crate::verify(&sentence));
+
+        let builder = SentenceBuilder::new("GP", SentenceType::TXT);
+        assert_eq!(
+            builder.clone().field("1*2").err(),
+            Some(Error::InvalidFieldCharacter('*'))
+        );
+        assert_eq!(
+            builder.field(format_args!("{}{}", "a", ",")).err(),
+            Some(Error::InvalidFieldCharacter(','))
+        );
+    }
+
+    #[test]
+    fn test_sentence_builder_length() {
+        let long = [b'x'; 90];
+        let long = core::str::from_utf8(&long).unwrap();
+
+        let builder = SentenceBuilder::new("GP", SentenceType::TXT)
+            .field(long)
+            .unwrap();
+        // 90 characters of data and 10 of the envelope
+        assert_eq!(builder.build().unwrap().len(), 100);
+        assert_eq!(
+            builder.clone().field("").map(|b| b.build().unwrap().len()),
+            Ok(101)
+        );
+        assert_eq!(builder.field("xx").err(), Some(Error::SentenceLength(103)));
+
+        let builder = SentenceBuilder::new("GP", SentenceType::TXT);
+        assert_eq!(
+            builder.field(format_args!("{}{}", long, long)).err(),
+            Some(Error::SentenceLength(190))
+        );
+    }
+}
@@ -1,7 +1,13 @@
 use core::fmt;
 
-use crate::{sentences::GnssType, SentenceType};
+use crate::{sentences::GnssType, SentenceType, SENTENCE_MAX_LEN};
 
+/// The error type of the crate.
+///
+/// Neither the [`Display`](fmt::Display) nor the `defmt::Format` implementation
+/// allocate, the messages are written directly into the formatter which keeps
+/// them usable on `no_std` targets without an allocator.
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[derive(Debug, PartialEq)]
 pub enum Error<'a> {
     /// The provided input was not a proper UTF-8 string
@@ -16,10 +22,18 @@ pub enum Error<'a> {
         expected: SentenceType,
         found: SentenceType,
     },
+    /// A sentence was passed to a parser of several sentence types, none of
+    /// which is the found one.
+    WrongSentenceHeaders {
+        expected: &'static [SentenceType],
+        found: SentenceType,
+    },
     /// An unknown [`GnssType`] was found in the NMEA message.
     UnknownGnssType(&'a str),
     /// The sentence could not be parsed because its format was invalid.
-    ParsingError(nom::Err<nom::error::Error<&'a str>>),
+    ParsingError(
+        #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))] nom::Err<nom::error::Error<&'a str>>,
+    ),
     /// The sentence was too long to be parsed, our current limit is `SENTENCE_MAX_LEN` characters.
     SentenceLength(usize),
     /// Parameter was too long to fit into fixed ArrayString.
@@ -31,10 +45,44 @@ pub enum Error<'a> {
     Unsupported(SentenceType),
     /// The sentence type is unknown for this crate.
     Unknown(&'a str),
+    /// A proprietary `$P` sentence unknown to the crate, with the manufacturer
+    /// mnemonic, e.g. `UBX`. Use [`parse_proprietary_sentence`](crate::parse_proprietary_sentence)
+    /// to split it.
+    Proprietary(&'a str),
     /// The provided navigation configuration was empty and thus invalid
     EmptyNavConfig,
     /// An unknown talker ID was found in the NMEA message.
     UnknownTalkerId { expected: &'a str, found: &'a str },
+    /// The fragments of a multi-sentence message are incomplete, out of order
+    /// or do not belong to the same message.
+    InvalidFragments,
+    /// A character outside of the 6-bit ASCII armoring was found in an AIS payload.
+    InvalidAisCharacter(char),
+    /// The number of fill bits of an AIS payload is above 5 or larger than the payload.
+    InvalidFillBits(u8),
+    /// An indicator letter, e.g. of a unit or a reference, is not one of the allowed ones.
+    InvalidIndicator(char),
+    /// A latitude above 90 or a longitude above 180 degrees in magnitude,
+    /// in decimal degrees.
+    InvalidCoordinate(f64),
+    /// A field of a built sentence contains a `,` or `*`, which would
+    /// corrupt the sentence.
+    InvalidFieldCharacter(char),
+    /// The sentence has no checksum although its type
+    /// [requires](SentenceType::checksum_required) one.
+    MissingChecksum(SentenceType),
+    /// A field of the encoded data struct has a value which can't be written
+    /// in the sentence, e.g. a negative duration.
+    InvalidFieldValue(SentenceType),
+    /// The sentence has more fields than the parser supports, `max` is the
+    /// maximum number of data fields.
+    TooManyFields { sentence: SentenceType, max: usize },
+    /// The sentence was truncated, `expected` is the number of data fields
+    /// needed to complete it.
+    TooFewFields {
+        sentence: SentenceType,
+        expected: usize,
+    },
     /// The current sentences is parsable but the feature has been disabled.
     // TODO: Add sentences and data?!
     DisabledSentence,
@@ -63,6 +111,16 @@ impl<'a> fmt::Display for Error<'a> {
                 "Wrong Sentence Header (expected = '{}', found = '{}')",
                 expected, found
             ),
+            Error::WrongSentenceHeaders { expected, found } => {
+                write!(f, "Wrong Sentence Header (expected one of = ")?;
+                for (index, sentence_type) in expected.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "'{}'", sentence_type)?;
+                }
+                write!(f, ", found = '{}')", found)
+            }
             Error::UnknownGnssType(found) => write!(
                 f,
                 "Unknown GNSS type (expected one of '{:?}', found = '{}')",
@@ -72,8 +130,8 @@ impl<'a> fmt::Display for Error<'a> {
             Error::ParsingError(e) => write!(f, "Parse error: {}", e),
             Error::SentenceLength(size) => write!(
                 f,
-                "The sentence was too long to be parsed, current limit is {} characters",
-                size
+                "The sentence was too long to be parsed ({} characters), current limit is {} characters",
+                size, SENTENCE_MAX_LEN
             ),
             Error::ParameterLength {
                 max_length,
@@ -89,6 +147,11 @@ impl<'a> fmt::Display for Error<'a> {
             Error::Unknown(sentence) => {
                 write!(f, "Unknown for the crate NMEA sentence '{}'", sentence)
             }
+            Error::Proprietary(manufacturer) => write!(
+                f,
+                "Proprietary NMEA sentence of the manufacturer '{}', parse it with `parse_proprietary_sentence`",
+                manufacturer
+            ),
             Error::EmptyNavConfig => write!(
                 f,
                 "The provided navigation configuration was empty and thus invalid"
@@ -98,6 +161,41 @@ impl<'a> fmt::Display for Error<'a> {
                 "Unknown Talker ID (expected = '{}', found = '{}')",
                 expected, found
             ),
+            Error::InvalidFragments => write!(
+                f,
+                "The fragments of a multi-sentence message are incomplete or out of order"
+            ),
+            Error::InvalidAisCharacter(c) => {
+                write!(f, "Invalid character '{}' in the armored AIS payload", c)
+            }
+            Error::InvalidFillBits(fill_bits) => {
+                write!(f, "Invalid number of fill bits ({}) in AIS payload", fill_bits)
+            }
+            Error::InvalidIndicator(c) => write!(f, "Invalid indicator '{}'", c),
+            Error::InvalidCoordinate(degrees) => {
+                write!(f, "Coordinate {} degrees is out of range", degrees)
+            }
+            Error::InvalidFieldCharacter(c) => {
+                write!(f, "Invalid character '{}' in a sentence field", c)
+            }
+            Error::MissingChecksum(sentence) => {
+                write!(f, "The {} sentence requires a checksum", sentence)
+            }
+            Error::InvalidFieldValue(sentence) => write!(
+                f,
+                "A field of the {} sentence has a value which can't be encoded",
+                sentence
+            ),
+            Error::TooManyFields { sentence, max } => write!(
+                f,
+                "Too many fields in the {} sentence, at most {} are supported",
+                sentence, max
+            ),
+            Error::TooFewFields { sentence, expected } => write!(
+                f,
+                "Too few fields in the {} sentence, expected {}",
+                sentence, expected
+            ),
             Error::DisabledSentence => {
                 write!(f, "Sentence is parsable but it's feature is disabled",)
             }
@@ -108,3 +206,106 @@ impl<'a> fmt::Display for Error<'a> {
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 impl<'a> std::error::Error for Error<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use core::fmt::Write;
+
+    use heapless::String;
+
+    use super::*;
+
+    /// Formats the error into a fixed-size buffer, as a `no_std` target without
+    /// an allocator would do.
+    fn format(error: &Error) -> String<256> {
+        let mut buffer = String::new();
+        write!(buffer, "{}", error).expect("Message should fit in the buffer");
+        buffer
+    }
+
+    #[test]
+    fn test_display_without_allocation() {
+        let errors = [
+            Error::Utf8Decoding,
+            Error::ASCII,
+            Error::ChecksumMismatch {
+                calculated: 0x2E,
+                found: 0x2F,
+            },
+            Error::WrongSentenceHeader {
+                expected: SentenceType::GGA,
+                found: SentenceType::RMC,
+            },
+            Error::UnknownGnssType("XX"),
+            Error::ParsingError(nom::Err::Error(nom::error::Error::new(
+                "A,B",
+                nom::error::ErrorKind::Char,
+            ))),
+            Error::SentenceLength(120),
+            Error::ParameterLength {
+                max_length: 64,
+                parameter_length: 80,
+            },
+            Error::Unsupported(SentenceType::XTE),
+            Error::Unknown("XYZ"),
+            Error::EmptyNavConfig,
+            Error::UnknownTalkerId {
+                expected: "GP",
+                found: "XX",
+            },
+            Error::InvalidFragments,
+            Error::InvalidAisCharacter('X'),
+            Error::InvalidFillBits(6),
+            Error::InvalidIndicator('X'),
+            Error::InvalidCoordinate(91.5),
+            Error::InvalidFieldCharacter(','),
+            Error::TooManyFields {
+                sentence: SentenceType::XDR,
+                max: 32,
+            },
+            Error::TooFewFields {
+                sentence: SentenceType::GSV,
+                expected: 7,
+            },
+            Error::DisabledSentence,
+            Error::WrongSentenceHeaders {
+                expected: &[SentenceType::VDM, SentenceType::VDO],
+                found: SentenceType::GGA,
+            },
+            Error::InvalidFieldValue(SentenceType::ZTG),
+            Error::MissingChecksum(SentenceType::GGA),
+            Error::Proprietary("UBX"),
+        ];
+
+        for error in &errors {
+            assert!(!format(error).is_empty());
+        }
+
+        assert_eq!(
+            format(&errors[2]),
+            "Checksum Mismatch(calculated = 46, found = 47)"
+        );
+        assert_eq!(
+            format(&errors[3]),
+            "Wrong Sentence Header (expected = 'GGA', found = 'RMC')"
+        );
+        assert_eq!(
+            format(&errors[6]),
+            "The sentence was too long to be parsed (120 characters), current limit is 102 characters"
+        );
+        assert_eq!(format(&errors[8]), "Unsupported NMEA sentence 'XTE'");
+        assert_eq!(
+            format(&errors[21]),
+            "Wrong Sentence Header (expected one of = 'VDM', 'VDO', found = 'GGA')"
+        );
+        assert_eq!(
+            format(&errors[22]),
+            "A field of the ZTG sentence has a value which can't be encoded"
+        );
+        assert_eq!(format(&errors[23]), "The GGA sentence requires a checksum");
+        assert_eq!(
+            format(&errors[24]),
+            "Proprietary NMEA sentence of the manufacturer 'UBX', parse it with `parse_proprietary_sentence`"
+        );
+    }
+}
@@ -0,0 +1,194 @@
+//! Geoid undulation models used to convert ellipsoidal heights to heights
+//! above the mean sea level (MSL).
+//!
+//! The crate does not bundle the EGM96 coefficients or grids, the
+//! [`GeoidGrid`] interpolates an EGM96 (or any other) undulation grid
+//! provided by the application, e.g. a grid sampled from the NGA
+//! `WW15MGH.GRD` file and stored in flash.
+//!
+//! # Accuracy
+//!
+//! The bilinear interpolation reproduces the grid values exactly at the grid
+//! nodes. Between them the interpolation error is at most
+//! `h² / 8 · (max |∂²N/∂φ²| + max |∂²N/∂λ²|)`, `h` being the resolution of
+//! the grid in degrees and the second derivatives of the undulation `N` in
+//! meters per square degree. The error of a coarse grid is therefore dominated
+//! by the short wavelength features of the geoid the grid can't resolve, pick
+//! the resolution according to the accuracy needed.
+
+use crate::navigation::Position;
+
+/// A model of the geoid undulation, the height of the geoid above the
+/// WGS84 ellipsoid.
+pub trait GeoidModel {
+    /// Undulation at `position` in meters.
+    fn geoid_undulation(&self, position: &Position) -> f32;
+}
+
+/// A global grid of geoid undulations in meters, laid out like the EGM96 grids:
+/// rows go from 90° North to 90° South and each row goes from 0° to 360° East,
+/// both ends included.
+///
+/// # Examples
+///
+/// ```
+/// use nmea::{
+///     geoid::{GeoidGrid, GeoidModel},
+///     navigation::Position,
+/// };
+///
+/// // a synthetic 90 degree grid, 3 rows of 5 columns
+/// let undulations = [
+///     10.0, 10.0, 10.0, 10.0, 10.0,
+///     20.0, -10.0, 0.0, 30.0, 20.0,
+///     -30.0, -30.0, -30.0, -30.0, -30.0,
+/// ];
+/// let grid = GeoidGrid::new(&undulations, 90.0).unwrap();
+///
+/// assert_eq!(grid.geoid_undulation(&Position::new(0.0, 0.0)), 20.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeoidGrid<'a> {
+    undulations: &'a [f32],
+    resolution: f32,
+    columns: usize,
+}
+
+impl<'a> GeoidGrid<'a> {
+    /// Creates a grid with `resolution` degrees between the nodes.
+    ///
+    /// Returns `None` if 180 and 360 are not multiples of the resolution or
+    /// the number of `undulations` doesn't match it.
+    pub fn new(undulations: &'a [f32], resolution: f32) -> Option<Self> {
+        if resolution <= 0.0 {
+            return None;
+        }
+        let rows = 180.0 / resolution;
+        let columns = 360.0 / resolution;
+        if rows % 1.0 != 0.0 || columns % 1.0 != 0.0 {
+            return None;
+        }
+        let (rows, columns) = (rows as usize + 1, columns as usize + 1);
+        if undulations.len() != rows * columns {
+            return None;
+        }
+
+        Some(Self {
+            undulations,
+            resolution,
+            columns,
+        })
+    }
+
+    fn node(&self, row: usize, column: usize) -> f32 {
+        self.undulations[row * self.columns + column]
+    }
+}
+
+impl GeoidModel for GeoidGrid<'_> {
+    /// Bilinear interpolation of the 4 grid nodes around `position`.
+    fn geoid_undulation(&self, position: &Position) -> f32 {
+        let latitude = position.latitude.clamp(-90.0, 90.0) as f32;
+        let longitude = (position.longitude as f32) % 360.0;
+        let longitude = if longitude < 0.0 {
+            longitude + 360.0
+        } else {
+            longitude
+        };
+
+        let row = (90.0 - latitude) / self.resolution;
+        let column = longitude / self.resolution;
+        let (row0, column0) = (row as usize, column as usize);
+        let (row1, column1) = (
+            (row0 + 1).min(self.undulations.len() / self.columns - 1),
+            (column0 + 1).min(self.columns - 1),
+        );
+        let (row_weight, column_weight) = (row - row0 as f32, column - column0 as f32);
+
+        let north = self.node(row0, column0) * (1.0 - column_weight)
+            + self.node(row0, column1) * column_weight;
+        let south = self.node(row1, column0) * (1.0 - column_weight)
+            + self.node(row1, column1) * column_weight;
+
+        north * (1.0 - row_weight) + south * row_weight
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    /// A synthetic grid, the values are not the ones of any geoid model.
+    const UNDULATIONS: [f32; 15] = [
+        10.0, 10.0, 10.0, 10.0, 10.0, //
+        20.0, -10.0, 0.0, 30.0, 20.0, //
+        -30.0, -30.0, -30.0, -30.0, -30.0,
+    ];
+
+    #[test]
+    fn test_grid_validation() {
+        assert!(GeoidGrid::new(&UNDULATIONS, 90.0).is_some());
+        assert!(GeoidGrid::new(&UNDULATIONS, 45.0).is_none());
+        assert!(GeoidGrid::new(&UNDULATIONS, 70.0).is_none());
+        assert!(GeoidGrid::new(&UNDULATIONS, 0.0).is_none());
+        assert!(GeoidGrid::new(&UNDULATIONS[1..], 90.0).is_none());
+    }
+
+    #[test]
+    fn test_grid_interpolation() {
+        let grid = GeoidGrid::new(&UNDULATIONS, 90.0).unwrap();
+
+        // nodes
+        assert_relative_eq!(grid.geoid_undulation(&Position::new(0.0, 90.0)), -10.0);
+        assert_relative_eq!(grid.geoid_undulation(&Position::new(0.0, -90.0)), 30.0);
+        assert_relative_eq!(grid.geoid_undulation(&Position::new(90.0, 10.0)), 10.0);
+        assert_relative_eq!(grid.geoid_undulation(&Position::new(-90.0, 10.0)), -30.0);
+        // the 360 degrees column wraps around to 0
+        assert_relative_eq!(grid.geoid_undulation(&Position::new(0.0, 360.0)), 20.0);
+
+        // half way between the nodes
+        assert_relative_eq!(grid.geoid_undulation(&Position::new(0.0, 45.0)), 5.0);
+        assert_relative_eq!(
+            grid.geoid_undulation(&Position::new(45.0, 45.0)),
+            7.5,
+            epsilon = 1e-5
+        );
+    }
+
+    #[test]
+    fn test_grid_interpolation_error_bound() {
+        // a smooth undulation with known second derivatives, sampled on a 15 degree grid
+        const RESOLUTION: f64 = 15.0;
+        fn undulation(latitude: f64, longitude: f64) -> f64 {
+            30.0 * (2.0 * latitude).to_radians().sin() * longitude.to_radians().cos()
+        }
+        let mut undulations = [0.0f32; 13 * 25];
+        for row in 0..13 {
+            for column in 0..25 {
+                undulations[row * 25 + column] =
+                    undulation(90.0 - row as f64 * RESOLUTION, column as f64 * RESOLUTION) as f32;
+            }
+        }
+        let grid = GeoidGrid::new(&undulations, RESOLUTION as f32).unwrap();
+
+        // the documented bound, with the maxima of the second derivatives
+        let max_latitude_derivative = 30.0 * (2.0f64.to_radians()).powi(2);
+        let max_longitude_derivative = 30.0 * (1.0f64.to_radians()).powi(2);
+        let bound = RESOLUTION.powi(2) / 8.0 * (max_latitude_derivative + max_longitude_derivative);
+
+        let mut max_error = 0.0f64;
+        for latitude in (-89..=89).step_by(2) {
+            for longitude in (-180..180).step_by(3) {
+                let (latitude, longitude) = (f64::from(latitude), f64::from(longitude));
+                let interpolated =
+                    f64::from(grid.geoid_undulation(&Position::new(latitude, longitude)));
+                max_error = max_error.max((interpolated - undulation(latitude, longitude)).abs());
+            }
+        }
+        assert!(max_error <= bound, "{max_error} > {bound}");
+        // the bound is not far off
+        assert!(max_error > bound / 4.0, "{max_error} <= {bound} / 4");
+    }
+}
@@ -11,28 +11,71 @@
 //! NMEA Standard Sentences
 //!
 //! - AAM
+//! - ACK
 //! - ALM
+//! - ALR
 //! - APA
 //! - BOD
 //! - BWC
+//! - BWR
 //! - BWW
+//! - CUR
 //! - DBK
+//! - DBS
+//! - DSC
+//! - DSE
+//! - EPV
+//! - FSI
 //! - GBS
 //! - GGA *
+//! - GLC
 //! - GLL *
+//! - GMP
 //! - GNS *
+//! - GRS
 //! - GSA *
 //! - GST
 //! - GSV *
+//! - GTD
+//! - HBT
+//! - HDM
 //! - HDT
+//! - HSC
+//! - HTC
+//! - HTD
 //! - MDA
+//! - MHU
+//! - MMB
+//! - MTA
 //! - MTW
+//! - MWD
+//! - MWH
 //! - MWV
+//! - NRX
+//! - OSD
+//! - RMA
+//! - RMB
 //! - RMC *
+//! - RMM
+//! - RPM
+//! - SFI
+//! - STN
+//! - THS
+//! - TLL
+//! - TRF
 //! - TTM
+//! - VBW
+//! - VDM
+//! - VDO
+//! - VDR
 //! - VHW
+//! - VPW
 //! - VTG *
+//! - VWR
+//! - VWT
+//! - WCV
 //! - WNC
+//! - XDR
 //! - ZDA
 //! - ZFO
 //! - ZTG
@@ -48,10 +91,44 @@
 //!
 //! # Crate features
 //!
-//! - `default` features - `std`
-//! - `std` - enable `std`
-//! - `serde` - enable `serde` Serialize and Deserialize derives
+//! - `default` features - `std`, `chrono` and `all-sentences`
+//! - `std` - enable `std`, implies `alloc`
+//! - `chrono` - the time and date fields are `chrono` types, e.g.
+//!   `NaiveTime`, and the `time_context` module. Without it they are
+//!   [`sentences::TimeOfDay`] and [`sentences::DayMonthYear`], see the
+//!   [`sentences::Time`], [`sentences::Date`] and [`sentences::Duration`] aliases.
+//!   The feature changes the field types, code matching on them has to agree
+//!   with the feature set of the whole build.
+//! - `alloc` - enable the features that need an allocator, e.g. the `trajectory` module
+//! - `serde` - enable `serde` Serialize and Deserialize derives, the fields of
+//!   the sentence structs are serialized with camelCase keys
 //! - `defmt-03` - enable the `defmt@0.3` Format derives
+//! - `geoid` - enable the geoid models converting ellipsoidal heights to MSL
+//! - `kml` - enable the KML export of the `trajectory` tracks, implies `alloc`
+//! - `ntp` - enable the conversion of the GNSS time to NTP timestamps, implies `chrono`
+//!
+//! # `no_std`
+//!
+//! Without the default `std` feature the crate is `no_std` and doesn't need
+//! an allocator, `chrono` (if enabled) is used without its `std` and `clock`
+//! features.
+//! Available on every target:
+//!
+//! - [`parse_str()`], [`parse_bytes()`], [`parse_sentence()`] and [`parse_lenient_all()`]
+//! - [`parse_nmea_sentence()`], [`parse_nmea_sentence_checked()`], [`parse_proprietary_sentence()`] and [`sentences()`]
+//! - the sentence parsers and data structs of the [`sentences`](mod@sentences) module
+//! - the stateful [`Nmea`] parser
+//! - [`stream::SentenceBuffer`] for reassembling sentences from a byte stream
+//! - [`encode::ToNmeaSentence::write_sentence()`] writing into any `core::fmt::Write`,
+//!   e.g. a `heapless::String` or an `arrayvec::ArrayString`, and
+//!   [`encode::ToNmeaSentence::write_to_slice()`] writing into a `&mut [u8]`
+//! - [`encode::SentenceBuilder`] for building sentences of any type field by field
+//! - `time_context::TimeContext` for full timestamps of GGA, GLL and GNS
+//!   sentences, with `chrono`
+//!
+//! Only with `alloc`: `ToNmeaSentence::encode()` returning a `String` and the
+//! `trajectory` module. Only with `std`: the `reader` and `replay` modules
+//! and the `std::error::Error` implementation of [`Error`].
 //!
 //! [`Nmea::parse()`]: Nmea::parse
 //! [`Nmea::parse_for_fix()`]: Nmea::parse_for_fix
@@ -62,9 +139,35 @@
 #![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![deny(unsafe_code, rustdoc::broken_intra_doc_links)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod compact;
+pub mod encode;
 mod error;
+#[cfg(feature = "geoid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "geoid")))]
+pub mod geoid;
+pub mod navigation;
+#[cfg(feature = "ntp")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ntp")))]
+pub mod ntp;
 pub(crate) mod parse;
 mod parser;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod reader;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod replay;
+pub mod stream;
+mod tag_block;
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+pub mod time_context;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod trajectory;
 
 pub mod sentences;
 
@@ -76,6 +179,8 @@ pub use error::Error;
 #[doc(inline)]
 pub use parse::*;
 
+pub use tag_block::{TagBlock, TagGroup};
+
 #[cfg(doctest)]
 // Test the README examples
 doc_comment::doctest!("../README.md");
@@ -0,0 +1,312 @@
+//! Navigation helpers built on top of the parsed sentences.
+//!
+//! The [`NavigationSolver`] synthesizes the autopilot guidance that
+//! APA/APB/XTE sentences would report (cross-track error, bearing and
+//! distance to the destination) from a raw position fix, e.g. the one
+//! obtained from a GGA or RMC sentence.
+
+use arrayvec::ArrayString;
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use num_traits::Float;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    parse::TEXT_PARAMETER_MAX_LEN,
+    sentences::{ApaData, DistanceUnit, Reference, SteerDirection},
+};
+
+/// Mean radius of the Earth in meters, used for the great-circle calculations.
+pub const EARTH_RADIUS_METERS: f64 = 6_371_008.8;
+
+/// Number of meters in one nautical mile.
+pub const METERS_PER_NAUTICAL_MILE: f64 = 1852.0;
+
+#[doc(inline)]
+pub use crate::sentences::utils::{knots_to_mps, mps_to_knots};
+
+/// A position on the Earth's surface in decimal degrees.
+///
+/// Latitude is positive to the North and longitude is positive to the East.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Position {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+impl Position {
+    pub fn new(latitude: f64, longitude: f64) -> Self {
+        Self {
+            latitude,
+            longitude,
+        }
+    }
+
+    /// Great-circle distance to `other` in meters (haversine formula).
+    pub fn distance_to(&self, other: &Position) -> f64 {
+        self.angular_distance_to(other) * EARTH_RADIUS_METERS
+    }
+
+    /// Initial true bearing to `other` in degrees, in the range `[0, 360)`.
+    pub fn bearing_to(&self, other: &Position) -> f64 {
+        let lat1 = self.latitude.to_radians();
+        let lat2 = other.latitude.to_radians();
+        let delta_lon = (other.longitude - self.longitude).to_radians();
+
+        let y = delta_lon.sin() * lat2.cos();
+        let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * delta_lon.cos();
+
+        (y.atan2(x).to_degrees() + 360.0) % 360.0
+    }
+
+    /// Distance in meters from this position to the great-circle track going
+    /// from `start` to `end`.
+    ///
+    /// The result is positive when the position is on the right of the track
+    /// and negative when it is on the left.
+    pub fn cross_track_distance(&self, start: &Position, end: &Position) -> f64 {
+        let angular_distance = start.angular_distance_to(self);
+        let bearing_to_self = start.bearing_to(self).to_radians();
+        let bearing_to_end = start.bearing_to(end).to_radians();
+
+        (angular_distance.sin() * (bearing_to_self - bearing_to_end).sin()).asin()
+            * EARTH_RADIUS_METERS
+    }
+
+    /// Distance in meters from `start` to the point of the great-circle track
+    /// from `start` to `end` that is closest to this position.
+    pub fn along_track_distance(&self, start: &Position, end: &Position) -> f64 {
+        let angular_distance = start.angular_distance_to(self);
+        let cross_track = self.cross_track_distance(start, end) / EARTH_RADIUS_METERS;
+        let bearing_to_self = start.bearing_to(self).to_radians();
+        let bearing_to_end = start.bearing_to(end).to_radians();
+
+        let along_track = (angular_distance.cos() / cross_track.cos())
+            .clamp(-1.0, 1.0)
+            .acos();
+
+        along_track.copysign((bearing_to_end - bearing_to_self).cos()) * EARTH_RADIUS_METERS
+    }
+
+    fn angular_distance_to(&self, other: &Position) -> f64 {
+        let lat1 = self.latitude.to_radians();
+        let lat2 = other.latitude.to_radians();
+        let delta_lat = lat2 - lat1;
+        let delta_lon = (other.longitude - self.longitude).to_radians();
+
+        let a = (delta_lat / 2.0).sin().powi(2)
+            + lat1.cos() * lat2.cos() * (delta_lon / 2.0).sin().powi(2);
+
+        2.0 * a.sqrt().atan2((1.0 - a).sqrt())
+    }
+}
+
+/// Computes autopilot guidance for the leg going from an origin waypoint to
+/// a destination waypoint.
+///
+/// # Examples
+///
+/// ```
+/// use nmea::navigation::{NavigationSolver, Position};
+///
+/// let solver = NavigationSolver::new(Position::new(0.0, 0.0), Position::new(0.0, 1.0));
+/// let solution = solver.solve(&Position::new(0.1, 0.5));
+///
+/// assert!((solution.cross_track_error - 6.0).abs() < 0.01);
+/// ```
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct NavigationSolver {
+    origin: Position,
+    destination: Position,
+    arrival_circle_radius: f64,
+}
+
+impl NavigationSolver {
+    /// Default radius of the arrival circle in meters.
+    pub const DEFAULT_ARRIVAL_CIRCLE_RADIUS: f64 = 0.1 * METERS_PER_NAUTICAL_MILE;
+
+    pub fn new(origin: Position, destination: Position) -> Self {
+        Self {
+            origin,
+            destination,
+            arrival_circle_radius: Self::DEFAULT_ARRIVAL_CIRCLE_RADIUS,
+        }
+    }
+
+    /// Sets the radius of the arrival circle around the destination in meters.
+    pub fn with_arrival_circle_radius(mut self, radius: f64) -> Self {
+        self.arrival_circle_radius = radius;
+        self
+    }
+
+    pub fn origin(&self) -> Position {
+        self.origin
+    }
+
+    pub fn destination(&self) -> Position {
+        self.destination
+    }
+
+    /// Computes the guidance for the current `position`.
+    pub fn solve(&self, position: &Position) -> NavigationSolution {
+        let cross_track = position.cross_track_distance(&self.origin, &self.destination);
+        let along_track = position.along_track_distance(&self.origin, &self.destination);
+        let leg_length = self.origin.distance_to(&self.destination);
+        let distance_to_destination = position.distance_to(&self.destination);
+
+        NavigationSolution {
+            cross_track_error: cross_track.abs() / METERS_PER_NAUTICAL_MILE,
+            // the vessel right of the track has to steer left to get back on it
+            steer_direction: if cross_track > 0.0 {
+                SteerDirection::Left
+            } else {
+                SteerDirection::Right
+            },
+            bearing_origin_destination: self.origin.bearing_to(&self.destination),
+            bearing_to_destination: position.bearing_to(&self.destination),
+            distance_to_destination: distance_to_destination / METERS_PER_NAUTICAL_MILE,
+            arrival_circle_entered: distance_to_destination <= self.arrival_circle_radius,
+            perpendicular_passed: along_track >= leg_length,
+        }
+    }
+}
+
+/// Guidance computed by the [`NavigationSolver`] for a single position.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct NavigationSolution {
+    /// Magnitude of the cross-track error in nautical miles
+    pub cross_track_error: f64,
+    /// Direction to steer to get back on the track
+    pub steer_direction: SteerDirection,
+    /// True bearing from the origin to the destination in degrees
+    pub bearing_origin_destination: f64,
+    /// True bearing from the current position to the destination in degrees
+    pub bearing_to_destination: f64,
+    /// Distance from the current position to the destination in nautical miles
+    pub distance_to_destination: f64,
+    pub arrival_circle_entered: bool,
+    pub perpendicular_passed: bool,
+}
+
+impl NavigationSolution {
+    /// Builds the APA sentence data an autopilot expects for this solution.
+    pub fn to_apa(&self, waypoint_id: Option<ArrayString<TEXT_PARAMETER_MAX_LEN>>) -> ApaData {
+        ApaData {
+            status_warning: Some(true),
+            status_cycle_warning: Some(true),
+            cross_track_error_magnitude: Some(self.cross_track_error as f32),
+            steer_direction: Some(self.steer_direction),
+            cross_track_units: Some(DistanceUnit::NauticalMiles),
+            status_arrived: Some(self.arrival_circle_entered),
+            status_passed: Some(self.perpendicular_passed),
+            bearing_origin_destination: Some(self.bearing_origin_destination as f32),
+            magnetic_true: Some(Reference::True),
+            waypoint_id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::{parse::parse_nmea_sentence, sentences::parse_gga};
+
+    #[test]
+    fn test_distance_and_bearing() {
+        let origin = Position::new(0.0, 0.0);
+
+        // one degree of longitude along the equator
+        let east = Position::new(0.0, 1.0);
+        assert_relative_eq!(
+            origin.distance_to(&east),
+            EARTH_RADIUS_METERS * core::f64::consts::PI / 180.0,
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(origin.bearing_to(&east), 90.0, epsilon = 1e-9);
+        assert_relative_eq!(east.bearing_to(&origin), 270.0, epsilon = 1e-9);
+        assert_relative_eq!(origin.bearing_to(&Position::new(-1.0, 0.0)), 180.0);
+    }
+
+    #[test]
+    fn test_speed_conversions() {
+        assert_relative_eq!(knots_to_mps(1.0), 0.514_444, epsilon = 1e-6);
+        assert_relative_eq!(mps_to_knots(0.514_444), 1.0, epsilon = 1e-6);
+        assert_relative_eq!(mps_to_knots(knots_to_mps(12.5)), 12.5, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_cross_track_error_hand_calculation() {
+        let solver = NavigationSolver::new(Position::new(0.0, 0.0), Position::new(0.0, 1.0));
+
+        // 0.1 degree North of an eastbound track along the equator: the vessel
+        // is left of the track by 0.1 * 60 = 6 arc minutes, i.e. ~6 nautical miles.
+        let solution = solver.solve(&Position::new(0.1, 0.5));
+        let expected_xte = EARTH_RADIUS_METERS * 0.1_f64.to_radians() / METERS_PER_NAUTICAL_MILE;
+        assert_relative_eq!(solution.cross_track_error, expected_xte, epsilon = 1e-6);
+        assert_relative_eq!(solution.cross_track_error, 6.004, epsilon = 1e-3);
+        assert_eq!(solution.steer_direction, SteerDirection::Right);
+        assert_relative_eq!(solution.bearing_origin_destination, 90.0, epsilon = 1e-9);
+        // atan(0.1 / 0.5) = 11.31 degrees South of East
+        assert_relative_eq!(solution.bearing_to_destination, 101.31, epsilon = 1e-2);
+        assert_relative_eq!(solution.distance_to_destination, 30.61, epsilon = 1e-2);
+        assert!(!solution.arrival_circle_entered);
+        assert!(!solution.perpendicular_passed);
+
+        let solution = solver.solve(&Position::new(-0.1, 0.5));
+        assert_relative_eq!(solution.cross_track_error, expected_xte, epsilon = 1e-6);
+        assert_eq!(solution.steer_direction, SteerDirection::Left);
+    }
+
+    #[test]
+    fn test_arrival() {
+        let solver = NavigationSolver::new(Position::new(0.0, 0.0), Position::new(0.0, 1.0));
+
+        let solution = solver.solve(&Position::new(0.0, 1.0005));
+        assert!(solution.arrival_circle_entered);
+        assert!(solution.perpendicular_passed);
+
+        let solver = solver.with_arrival_circle_radius(10.0);
+        assert!(
+            !solver
+                .solve(&Position::new(0.0, 1.0005))
+                .arrival_circle_entered
+        );
+    }
+
+    #[test]
+    fn test_gga_to_apa() {
+        let s = parse_nmea_sentence(
+            "$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76",
+        )
+        .unwrap();
+        let position = parse_gga(s).unwrap().position().unwrap();
+
+        let solver = NavigationSolver::new(Position::new(53.0, -6.5), Position::new(54.0, -6.5));
+        let apa = solver
+            .solve(&position)
+            .to_apa(Some(ArrayString::from("DEST").unwrap()));
+
+        // 6°30.3372'W is West, i.e. left, of the northbound track along 6°30'W
+        assert_eq!(apa.steer_direction, Some(SteerDirection::Right));
+        assert_eq!(apa.cross_track_units, Some(DistanceUnit::NauticalMiles));
+        assert_relative_eq!(
+            apa.cross_track_error_magnitude.unwrap(),
+            0.20,
+            epsilon = 1e-2
+        );
+        assert_relative_eq!(apa.bearing_origin_destination.unwrap(), 0.0);
+        assert_eq!(apa.status_arrived, Some(false));
+        assert_eq!(apa.status_passed, Some(false));
+        assert_eq!(&apa.waypoint_id.unwrap(), "DEST");
+    }
+}
@@ -0,0 +1,155 @@
+//! Conversion of the GNSS time to NTP timestamps.
+//!
+//! Used to discipline an NTP server from the time of the RMC or ZDA sentences.
+
+use chrono::{NaiveDate, NaiveDateTime, Timelike};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Seconds between the NTP epoch (1900-01-01) and the Unix epoch (1970-01-01).
+pub const NTP_UNIX_EPOCH_OFFSET: i64 = 2_208_988_800;
+
+/// 64-bit NTP timestamp: 32-bit seconds since 1900-01-01 00:00:00 UTC and a
+/// 32-bit fraction of a second.
+///
+/// The seconds wrap around every 2^32 seconds (NTP eras), the first wrap
+/// happens on 2036-02-07 06:28:16 UTC.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nmea::ntp::NtpTimestamp;
+///
+/// let unix_epoch = NaiveDate::from_ymd_opt(1970, 1, 1)
+///     .unwrap()
+///     .and_hms_opt(0, 0, 0)
+///     .unwrap();
+/// let timestamp = NtpTimestamp::from_date_time(&unix_epoch).unwrap();
+///
+/// assert_eq!(timestamp.seconds, 2_208_988_800);
+/// assert_eq!(u64::from(timestamp), 2_208_988_800 << 32);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NtpTimestamp {
+    /// Seconds since the start of the NTP era
+    pub seconds: u32,
+    /// Fraction of a second in units of 2^-32 seconds
+    pub fraction: u32,
+}
+
+impl NtpTimestamp {
+    /// Converts a UTC date time, e.g. [`RmcData::fix_date_time`] or
+    /// [`ZdaData::utc_date_time`], to an NTP timestamp.
+    ///
+    /// Returns `None` for date times before the NTP epoch (1900-01-01).
+    /// A leap second (60th second) is reported as the last fraction of the 59th second.
+    ///
+    /// [`RmcData::fix_date_time`]: crate::sentences::RmcData::fix_date_time
+    /// [`ZdaData::utc_date_time`]: crate::sentences::ZdaData::utc_date_time
+    pub fn from_date_time(date_time: &NaiveDateTime) -> Option<Self> {
+        let ntp_epoch = NaiveDate::from_ymd_opt(1900, 1, 1)?.and_hms_opt(0, 0, 0)?;
+        let seconds = date_time.signed_duration_since(ntp_epoch).num_seconds();
+        if seconds < 0 {
+            return None;
+        }
+
+        let nanoseconds = u64::from(date_time.nanosecond().min(999_999_999));
+        Some(Self {
+            seconds: (seconds % (1 << 32)) as u32,
+            fraction: ((nanoseconds << 32) / 1_000_000_000) as u32,
+        })
+    }
+}
+
+impl From<NtpTimestamp> for u64 {
+    fn from(timestamp: NtpTimestamp) -> Self {
+        u64::from(timestamp.seconds) << 32 | u64::from(timestamp.fraction)
+    }
+}
+
+impl From<u64> for NtpTimestamp {
+    fn from(timestamp: u64) -> Self {
+        Self {
+            seconds: (timestamp >> 32) as u32,
+            fraction: timestamp as u32,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse::parse_nmea_sentence, sentences::parse_rmc};
+
+    fn date_time(year: i32, month: u32, day: u32, hour: u32, min: u32, sec: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .and_hms_opt(hour, min, sec)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_ntp_epochs() {
+        assert_eq!(
+            NtpTimestamp::from_date_time(&date_time(1900, 1, 1, 0, 0, 0)),
+            Some(NtpTimestamp {
+                seconds: 0,
+                fraction: 0
+            })
+        );
+        assert_eq!(
+            NtpTimestamp::from_date_time(&date_time(1970, 1, 1, 0, 0, 0))
+                .unwrap()
+                .seconds as i64,
+            NTP_UNIX_EPOCH_OFFSET
+        );
+        assert_eq!(
+            NtpTimestamp::from_date_time(&date_time(1899, 12, 31, 23, 59, 59)),
+            None
+        );
+        // start of NTP era 1
+        assert_eq!(
+            NtpTimestamp::from_date_time(&date_time(2036, 2, 7, 6, 28, 16)),
+            Some(NtpTimestamp {
+                seconds: 0,
+                fraction: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_ntp_fraction() {
+        let half_second = NaiveDate::from_ymd_opt(2000, 1, 1)
+            .unwrap()
+            .and_hms_milli_opt(0, 0, 0, 500)
+            .unwrap();
+        let timestamp = NtpTimestamp::from_date_time(&half_second).unwrap();
+        assert_eq!(
+            timestamp,
+            NtpTimestamp {
+                seconds: 3_155_673_600,
+                fraction: 0x8000_0000
+            }
+        );
+        assert_eq!(u64::from(timestamp), 0xBC17_C200_8000_0000);
+        assert_eq!(NtpTimestamp::from(0xBC17_C200_8000_0000), timestamp);
+    }
+
+    #[test]
+    fn test_rmc_to_ntp() {
+        let s = parse_nmea_sentence(
+            "$GPRMC,225446.33,A,4916.45,N,12311.12,W,000.5,054.7,191194,020.3,E,A*2B",
+        )
+        .unwrap();
+        let rmc = parse_rmc(s).unwrap();
+
+        // 1994-11-19T22:54:46.33Z
+        let timestamp = NtpTimestamp::from_date_time(&rmc.fix_date_time().unwrap()).unwrap();
+        assert_eq!(timestamp.seconds, 2_994_274_486);
+        assert_eq!(timestamp.fraction, 1_417_339_207);
+    }
+}
@@ -1,36 +1,38 @@
 use core::str;
 
 use nom::{
-    bytes::complete::{take, take_until},
-    character::complete::char,
-    combinator::map_res,
+    branch::alt,
+    bytes::complete::{take, take_till, take_while_m_n},
+    character::complete::{char, one_of},
+    combinator::{eof, map, map_res, value},
     sequence::preceded,
     IResult,
 };
 
+use arrayvec::ArrayString;
 use cfg_if::cfg_if;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::{sentences::*, Error, SentenceType};
+use crate::{sentences::*, tag_block::parse_tag_block, Error, SentenceType, TagBlock};
 
 /// The maximum message length parsable by the crate.
 ///
 /// From `gpsd`:
 ///
 /// > We've had reports that on the Garmin GPS-10 the device sometimes
-/// (1:1000 or so) sends garbage packets that have a valid checksum
-/// but are like 2 successive NMEA packets merged together in one
-/// with some fields lost. Usually these are much longer than the
-/// legal limit for NMEA, so we can cope by just tossing out overlong
-/// packets.  This may be a generic bug of all Garmin chipsets.
-/// NMEA 3.01, Section 5.3 says the max sentence length shall be
-/// 82 chars, including the leading $ and terminating \r\n.
+/// > (1:1000 or so) sends garbage packets that have a valid checksum
+/// > but are like 2 successive NMEA packets merged together in one
+/// > with some fields lost. Usually these are much longer than the
+/// > legal limit for NMEA, so we can cope by just tossing out overlong
+/// > packets.  This may be a generic bug of all Garmin chipsets.
+/// > NMEA 3.01, Section 5.3 says the max sentence length shall be
+/// > 82 chars, including the leading $ and terminating \r\n.
 ///
 /// > Some receivers (TN-200, GSW 2.3.2) emit oversized sentences.
-/// The Trimble BX-960 receiver emits a 91-character GGA message.
-/// The current hog champion is the Skytraq S2525F8 which emits
-/// a 100-character PSTI message.
+/// > The Trimble BX-960 receiver emits a 91-character GGA message.
+/// > The current hog champion is the Skytraq S2525F8 which emits
+/// > a 100-character PSTI message.
 pub const SENTENCE_MAX_LEN: usize = 102;
 
 /// Maximum length of a single waypoint id data in sentence
@@ -39,30 +41,159 @@ pub const TEXT_PARAMETER_MAX_LEN: usize = 64;
 /// A known and parsable Nmea sentence type.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct NmeaSentence<'a> {
     pub talker_id: &'a str,
     pub message_id: SentenceType,
     pub data: &'a str,
-    pub checksum: u8,
+    /// The `*hh` checksum, `None` if the sentence has none.
+    pub checksum: Option<u8>,
+    /// The TAG block preceding the sentence, `None` if there is none.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub tag_block: Option<TagBlock<'a>>,
 }
 
 impl<'a> NmeaSentence<'a> {
     pub fn calc_checksum(&self) -> u8 {
-        checksum(
+        xor_checksum(
             self.talker_id
                 .as_bytes()
                 .iter()
                 .chain(self.message_id.as_str().as_bytes())
-                .chain(&[b','])
+                .chain(b",")
                 .chain(self.data.as_bytes()),
         )
     }
+
+    /// Returns whether the stored [`NmeaSentence::checksum`] matches the
+    /// checksum calculated over the sentence, `false` if there is none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nmea::parse_nmea_sentence;
+    ///
+    /// let sentence = parse_nmea_sentence("$GPHDT,274.07,T*03").unwrap();
+    /// assert!(sentence.rechecksum());
+    ///
+    /// let corrupted = parse_nmea_sentence("$GPHDT,274.08,T*03").unwrap();
+    /// assert!(!corrupted.rechecksum());
+    /// ```
+    pub fn rechecksum(&self) -> bool {
+        self.checksum == Some(self.calc_checksum())
+    }
+
+    /// Returns the typed [`TalkerId`], the raw one stays available as
+    /// [`NmeaSentence::talker_id`].
+    ///
+    /// Fails only for a `talker_id` which isn't two ASCII characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nmea::{parse_nmea_sentence, sentences::TalkerId};
+    ///
+    /// let sentence = parse_nmea_sentence("$GLGSV,1,1,00*65").unwrap();
+    /// assert_eq!(sentence.talker(), Ok(TalkerId::Glonass));
+    /// ```
+    pub fn talker(&self) -> Result<TalkerId, Error<'static>> {
+        self.talker_id.parse()
+    }
+}
+
+/// A proprietary `$P` sentence of a manufacturer, e.g. `$PUBX` of u-blox.
+///
+/// The vendor data isn't parsed by the crate, route it to your own parser
+/// based on the [`ProprietarySentence::manufacturer`] mnemonic.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProprietarySentence<'a> {
+    /// The manufacturer mnemonic following the `P`, e.g. `UBX` or `GRM`
+    #[cfg_attr(feature = "defmt-03", defmt(Display2Format))]
+    pub manufacturer: ArrayString<3>,
+    /// Everything between the manufacturer mnemonic and the checksum, e.g.
+    /// `,00,...` for `$PUBX,00,...` and `Z,2282,f,3` for `$PGRMZ,2282,f,3`.
+    pub data: &'a str,
+    /// The `*hh` checksum, `None` if the sentence has none.
+    pub checksum: Option<u8>,
 }
 
-pub(crate) fn checksum<'a, I: Iterator<Item = &'a u8>>(bytes: I) -> u8 {
+impl<'a> ProprietarySentence<'a> {
+    pub fn calc_checksum(&self) -> u8 {
+        xor_checksum(
+            b"P".iter()
+                .chain(self.manufacturer.as_bytes())
+                .chain(self.data.as_bytes()),
+        )
+    }
+
+    /// Returns whether the stored [`ProprietarySentence::checksum`] matches
+    /// the checksum calculated over the sentence, `false` if there is none.
+    pub fn rechecksum(&self) -> bool {
+        self.checksum == Some(self.calc_checksum())
+    }
+}
+
+pub(crate) fn xor_checksum<'a, I: Iterator<Item = &'a u8>>(bytes: I) -> u8 {
     bytes.fold(0, |c, x| c ^ *x)
 }
 
+/// Calculates the checksum of a raw sentence, i.e. the XOR of all the bytes
+/// between the leading `$` or `!` and the `*`.
+///
+/// Both are optional, so `body` can be a whole sentence as well as just the
+/// part covered by the checksum.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nmea::checksum("$GPHDT,274.07,T*03"), 0x03);
+/// assert_eq!(nmea::checksum("GPHDT,274.07,T"), 0x03);
+/// ```
+pub fn checksum(body: &str) -> u8 {
+    let body = body.strip_prefix(['$', '!']).unwrap_or(body);
+    let body = match body.find('*') {
+        Some(end) => &body[..end],
+        None => body,
+    };
+    xor_checksum(body.as_bytes().iter())
+}
+
+/// Returns whether the trailing `*hh` checksum of a raw sentence matches the
+/// [`checksum`] calculated over it.
+///
+/// A trailing `\r\n` and a leading TAG block are ignored, a sentence without
+/// a checksum isn't valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nmea::verify("$GPHDT,274.07,T*03"));
+/// assert!(!nmea::verify("$GPHDT,274.08,T*03"));
+/// assert!(!nmea::verify("$GPHDT,274.07,T"));
+/// ```
+pub fn verify(line: &str) -> bool {
+    let line = line.trim_end_matches(['\r', '\n']);
+    let line = match line.strip_prefix('\\') {
+        Some(tagged) => match tagged.split_once('\\') {
+            Some((_tag_block, line)) => line,
+            None => return false,
+        },
+        None => line,
+    };
+    if !line.starts_with(['$', '!']) {
+        return false;
+    }
+    let Some((body, found)) = line.rsplit_once('*') else {
+        return false;
+    };
+
+    found.len() == 2
+        && found.chars().all(|c| c.is_ascii_hexdigit())
+        && u8::from_str_radix(found, 16) == Ok(checksum(body))
+}
+
 fn parse_hex(data: &str) -> Result<u8, &'static str> {
     u8::from_str_radix(data, 16).map_err(|_| "Failed to parse checksum as hex number")
 }
@@ -77,12 +208,14 @@ fn parse_sentence_type(i: &str) -> IResult<&str, SentenceType> {
     })(i)
 }
 
-fn do_parse_nmea_sentence(i: &str) -> IResult<&str, NmeaSentence> {
-    let (i, talker_id) = preceded(char('$'), take(2usize))(i)?;
+fn do_parse_nmea_sentence(i: &str) -> IResult<&str, NmeaSentence<'_>> {
+    // `!` starts the encapsulation sentences, e.g. the AIS VDM/VDO ones
+    let (i, talker_id) = preceded(one_of("$!"), take(2usize))(i)?;
     let (i, message_id) = parse_sentence_type(i)?;
     let (i, _) = char(',')(i)?;
-    let (i, data) = take_until("*")(i)?;
-    let (i, checksum) = parse_checksum(i)?;
+    let (i, data) = take_till(|c| c == '*')(i)?;
+    // some receivers omit the checksum, a malformed one is still an error
+    let (i, checksum) = alt((map(parse_checksum, Some), value(None, eof)))(i)?;
 
     Ok((
         i,
@@ -91,15 +224,175 @@ fn do_parse_nmea_sentence(i: &str) -> IResult<&str, NmeaSentence> {
             message_id,
             data,
             checksum,
+            tag_block: None,
+        },
+    ))
+}
+
+/// Splits a NMEA 0183 sentence into its talker id, type, data and checksum
+/// without verifying the checksum.
+///
+/// A sentence without the `*hh` checksum is accepted with
+/// [`NmeaSentence::checksum`] set to `None`.
+///
+/// A leading TAG block, e.g. `\s:r003669945,c:1241544035*79\`, is stripped
+/// and returned in [`NmeaSentence::tag_block`]. Unlike the one of the
+/// sentence, its checksum is always verified.
+///
+/// Prefer [`parse_nmea_sentence_checked`] unless you compare
+/// [`NmeaSentence::checksum`] yourself.
+///
+/// # Errors
+///
+/// Proprietary `$P` sentences unknown to the crate, e.g. `$PUBX`, are
+/// reported as [`Error::Proprietary`], split them with
+/// [`parse_proprietary_sentence`] instead.
+///
+/// # Examples
+///
+/// ```
+/// use nmea::{parse_nmea_sentence, parse_proprietary_sentence, Error};
+///
+/// let line = "$PUBX,40,GLL,1,0,0,0,0,0*5D";
+/// assert_eq!(parse_nmea_sentence(line), Err(Error::Proprietary("UBX")));
+/// assert!(parse_proprietary_sentence(line).unwrap().rechecksum());
+/// ```
+pub fn parse_nmea_sentence(sentence: &str) -> core::result::Result<NmeaSentence<'_>, Error<'_>> {
+    let (tag_block, sentence) = parse_tag_block(sentence)?;
+    if sentence.len() > SENTENCE_MAX_LEN {
+        return Err(Error::SentenceLength(sentence.len()));
+    }
+    match do_parse_nmea_sentence(sentence) {
+        Ok((_, nmea_sentence)) => Ok(NmeaSentence {
+            tag_block,
+            ..nmea_sentence
+        }),
+        // the mnemonic following `$P` was validated by the proprietary parser
+        Err(_) if do_parse_proprietary_sentence(sentence).is_ok() => {
+            Err(Error::Proprietary(&sentence[2..5]))
+        }
+        Err(error) => Err(error.into()),
+    }
+}
+
+/// Splits a multi-line log, e.g. a capture held in memory, into sentences.
+///
+/// Blank lines and comments starting with `#` are skipped, the other lines
+/// are parsed with [`parse_nmea_sentence_checked`]. A malformed line yields
+/// an `Err` item and the iteration continues with the next line.
+///
+/// # Examples
+///
+/// ```
+/// let log = "# captured at sea\n\
+///            $GPHDT,274.07,T*03\r\n\
+///            \n\
+///            $GPHDT,274.08,T*03\n\
+///            $GPZTG,145832.12,042359.17,WPT*24\n";
+///
+/// let sentences: Vec<_> = nmea::sentences(log).collect();
+/// assert_eq!(sentences.len(), 3);
+/// assert!(sentences[0].is_ok());
+/// assert!(sentences[1].is_err());
+/// assert!(sentences[2].is_ok());
+/// ```
+pub fn sentences(input: &str) -> impl Iterator<Item = Result<NmeaSentence<'_>, Error<'_>>> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(parse_nmea_sentence_checked)
+}
+
+fn do_parse_proprietary_sentence(i: &str) -> IResult<&str, ProprietarySentence<'_>> {
+    let (i, _) = char('$')(i)?;
+    let (i, _) = char('P')(i)?;
+    let (i, manufacturer) = map_res(
+        take_while_m_n(3, 3, |c: char| c.is_ascii_alphanumeric()),
+        ArrayString::from,
+    )(i)?;
+    let (i, data) = take_till(|c| c == '*')(i)?;
+    let (i, checksum) = alt((map(parse_checksum, Some), value(None, eof)))(i)?;
+
+    Ok((
+        i,
+        ProprietarySentence {
+            manufacturer,
+            data,
+            checksum,
         },
     ))
 }
 
-pub fn parse_nmea_sentence(sentence: &str) -> core::result::Result<NmeaSentence, Error<'_>> {
+/// Splits a proprietary `$P` sentence into its manufacturer mnemonic, data
+/// and checksum without verifying the checksum.
+///
+/// [`parse_nmea_sentence`] only handles the proprietary sentences which are
+/// known to the crate, e.g. `$PGRMZ`, and reports the other ones as
+/// [`Error::Proprietary`], this one accepts any of them.
+///
+/// # Examples
+///
+/// ```
+/// use nmea::parse_proprietary_sentence;
+///
+/// let sentence = parse_proprietary_sentence("$PUBX,40,GLL,1,0,0,0,0,0*5D").unwrap();
+/// assert_eq!(sentence.manufacturer.as_str(), "UBX");
+/// assert_eq!(sentence.data, ",40,GLL,1,0,0,0,0,0");
+/// assert!(sentence.rechecksum());
+/// ```
+pub fn parse_proprietary_sentence(
+    sentence: &str,
+) -> core::result::Result<ProprietarySentence<'_>, Error<'_>> {
     if sentence.len() > SENTENCE_MAX_LEN {
         Err(Error::SentenceLength(sentence.len()))
     } else {
-        Ok(do_parse_nmea_sentence(sentence)?.1)
+        Ok(do_parse_proprietary_sentence(sentence)?.1)
+    }
+}
+
+/// Splits a NMEA 0183 sentence into its talker id, type, data and checksum
+/// and verifies the checksum.
+///
+/// This is the recommended entry point for handling the raw sentences, use
+/// [`parse_nmea_sentence`] only for the ones which legitimately carry no
+/// valid checksum.
+///
+/// # Errors
+///
+/// Apart from the errors of [`parse_nmea_sentence`], [`Error::ChecksumMismatch`]
+/// when the checksum of the sentence is wrong and [`Error::MissingChecksum`] when
+/// it's missing from a sentence type which [requires](SentenceType::checksum_required) one.
+///
+/// # Examples
+///
+/// ```
+/// use nmea::{parse_nmea_sentence_checked, Error};
+///
+/// let sentence = parse_nmea_sentence_checked("$GPHDT,274.07,T*03").unwrap();
+/// assert_eq!(sentence.data, "274.07,T");
+///
+/// assert_eq!(
+///     parse_nmea_sentence_checked("$GPHDT,274.07,T*00"),
+///     Err(Error::ChecksumMismatch {
+///         calculated: 0x03,
+///         found: 0x00
+///     })
+/// );
+/// ```
+pub fn parse_nmea_sentence_checked(
+    sentence: &str,
+) -> core::result::Result<NmeaSentence<'_>, Error<'_>> {
+    let nmea_sentence = parse_nmea_sentence(sentence)?;
+    match nmea_sentence.checksum {
+        Some(found) if found != nmea_sentence.calc_checksum() => Err(Error::ChecksumMismatch {
+            calculated: nmea_sentence.calc_checksum(),
+            found,
+        }),
+        None if nmea_sentence.message_id.checksum_required() => {
+            Err(Error::MissingChecksum(nmea_sentence.message_id))
+        }
+        _ => Ok(nmea_sentence),
     }
 }
 
@@ -108,29 +401,72 @@ pub fn parse_nmea_sentence(sentence: &str) -> core::result::Result<NmeaSentence,
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 pub enum ParseResult {
     AAM(AamData),
+    ACK(AckData),
     ALM(AlmData),
+    ALR(AlrData),
     APA(ApaData),
     BOD(BodData),
     BWC(BwcData),
+    BWR(BwrData),
     BWW(BwwData),
+    CUR(CurData),
     DBK(DbkData),
+    DBS(DbsData),
+    DSC(DscData),
+    DSE(DseData),
+    EPV(EpvData),
+    FSI(FsiData),
     GBS(GbsData),
     GGA(GgaData),
+    GLC(GlcData),
     GLL(GllData),
+    GMP(GmpData),
     GNS(GnsData),
+    GRS(GrsData),
     GSA(GsaData),
     GST(GstData),
     GSV(GsvData),
+    GTD(GtdData),
+    HBT(HbtData),
+    HDM(HdmData),
     HDT(HdtData),
+    HSC(HscData),
+    HTC(HtcData),
+    HTD(HtdData),
     MDA(MdaData),
+    MHU(MhuData),
+    MMB(MmbData),
+    MTA(MtaData),
     MTW(MtwData),
+    MWD(MwdData),
+    MWH(MwhData),
     MWV(MwvData),
+    NRX(NrxData),
+    OSD(OsdData),
+    RMA(RmaData),
+    RMB(RmbData),
     RMC(RmcData),
+    RMM(RmmData),
+    RPM(RpmData),
+    SFI(SfiData),
+    STN(StnData),
+    THS(ThsData),
+    TLL(TllData),
+    TRF(TrfData),
     TTM(TtmData),
     TXT(TxtData),
+    VBW(VbwData),
+    VDM(VdmData),
+    VDO(VdmData),
+    VDR(VdrData),
     VHW(VhwData),
+    VPW(VpwData),
     VTG(VtgData),
+    VWR(VwrData),
+    VWT(VwtData),
+    WCV(WcvData),
     WNC(WncData),
+    XDR(XdrData),
     ZDA(ZdaData),
     ZFO(ZfoData),
     ZTG(ZtgData),
@@ -139,6 +475,34 @@ pub enum ParseResult {
     Unsupported(SentenceType),
 }
 
+impl ParseResult {
+    /// Turns a [`ParseResult::Unsupported`] into an [`Error::Unsupported`],
+    /// for callers which treat unsupported sentences as errors.
+    ///
+    /// A sentence passed to the wrong `parse_*` function is still reported as
+    /// [`Error::WrongSentenceHeader`] by that function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nmea::{parse_sentence, Error, SentenceType};
+    ///
+    /// let parsed = parse_sentence("$GPDTM,W84,,0.0,N,0.0,E,0.0,W84*6F").unwrap();
+    /// assert_eq!(parsed.supported(), Err(Error::Unsupported(SentenceType::DTM)));
+    ///
+    /// # #[cfg(feature = "HDT")] {
+    /// let parsed = parse_sentence("$GPHDT,274.07,T*03").unwrap();
+    /// assert!(parsed.supported().is_ok());
+    /// # }
+    /// ```
+    pub fn supported(self) -> Result<Self, Error<'static>> {
+        match self {
+            ParseResult::Unsupported(sentence_type) => Err(Error::Unsupported(sentence_type)),
+            parse_result => Ok(parse_result),
+        }
+    }
+}
+
 impl From<&ParseResult> for SentenceType {
     fn from(parse_result: &ParseResult) -> Self {
         match parse_result {
@@ -168,6 +532,49 @@ impl From<&ParseResult> for SentenceType {
             ParseResult::WNC(_) => SentenceType::WNC,
             ParseResult::ZFO(_) => SentenceType::ZFO,
             ParseResult::ZTG(_) => SentenceType::ZTG,
+            ParseResult::VDM(_) => SentenceType::VDM,
+            ParseResult::VDO(_) => SentenceType::VDO,
+            ParseResult::TLL(_) => SentenceType::TLL,
+            ParseResult::RMB(_) => SentenceType::RMB,
+            ParseResult::XDR(_) => SentenceType::XDR,
+            ParseResult::MWD(_) => SentenceType::MWD,
+            ParseResult::VWR(_) => SentenceType::VWR,
+            ParseResult::VBW(_) => SentenceType::VBW,
+            ParseResult::GRS(_) => SentenceType::GRS,
+            ParseResult::THS(_) => SentenceType::THS,
+            ParseResult::RPM(_) => SentenceType::RPM,
+            ParseResult::ALR(_) => SentenceType::ALR,
+            ParseResult::ACK(_) => SentenceType::ACK,
+            ParseResult::HSC(_) => SentenceType::HSC,
+            ParseResult::OSD(_) => SentenceType::OSD,
+            ParseResult::RMA(_) => SentenceType::RMA,
+            ParseResult::BWR(_) => SentenceType::BWR,
+            ParseResult::WCV(_) => SentenceType::WCV,
+            ParseResult::MTA(_) => SentenceType::MTA,
+            ParseResult::MMB(_) => SentenceType::MMB,
+            ParseResult::MHU(_) => SentenceType::MHU,
+            ParseResult::MWH(_) => SentenceType::MWH,
+            ParseResult::CUR(_) => SentenceType::CUR,
+            ParseResult::GLC(_) => SentenceType::GLC,
+            ParseResult::DBS(_) => SentenceType::DBS,
+            ParseResult::VWT(_) => SentenceType::VWT,
+            ParseResult::HDM(_) => SentenceType::HDM,
+            ParseResult::GTD(_) => SentenceType::GTD,
+            ParseResult::RMM(_) => SentenceType::RMM,
+            ParseResult::DSC(_) => SentenceType::DSC,
+            ParseResult::DSE(_) => SentenceType::DSE,
+            ParseResult::HBT(_) => SentenceType::HBT,
+            ParseResult::VDR(_) => SentenceType::VDR,
+            ParseResult::VPW(_) => SentenceType::VPW,
+            ParseResult::STN(_) => SentenceType::STN,
+            ParseResult::GMP(_) => SentenceType::GMP,
+            ParseResult::FSI(_) => SentenceType::FSI,
+            ParseResult::NRX(_) => SentenceType::NRX,
+            ParseResult::SFI(_) => SentenceType::SFI,
+            ParseResult::TRF(_) => SentenceType::TRF,
+            ParseResult::EPV(_) => SentenceType::EPV,
+            ParseResult::HTC(_) => SentenceType::HTC,
+            ParseResult::HTD(_) => SentenceType::HTD,
             ParseResult::PGRMZ(_) => SentenceType::RMZ,
             ParseResult::ZDA(_) => SentenceType::ZDA,
             ParseResult::Unsupported(sentence_type) => *sentence_type,
@@ -175,13 +582,50 @@ impl From<&ParseResult> for SentenceType {
     }
 }
 
+/// A parsed NMEA 0183 sentence, one variant per supported [`SentenceType`].
+///
+/// Alias of [`ParseResult`], returned by [`parse_sentence`].
+pub type ParsedSentence = ParseResult;
+
+/// Parse any NMEA 0183 sentence, dispatching on its [`SentenceType`].
+///
+/// Well-formed sentences of a type this crate can't parse (or whose feature
+/// is disabled) are returned as [`ParsedSentence::Unsupported`] instead of an
+/// error, so an arbitrary NMEA stream can be fed through this one call.
+/// Proprietary `$P` sentences unknown to the crate are reported as
+/// [`Error::Proprietary`], parse them with [`parse_proprietary_sentence`].
+///
+/// This is the same as [`parse_str`], except for the sentences of disabled
+/// features which [`parse_str`] reports as [`Error::DisabledSentence`].
+///
+/// # Examples
+///
+/// ```
+/// use nmea::{parse_sentence, ParsedSentence, SentenceType};
+///
+/// let parsed = parse_sentence("$GPDTM,W84,,0.0,N,0.0,E,0.0,W84*6F").unwrap();
+/// assert_eq!(parsed, ParsedSentence::Unsupported(SentenceType::DTM));
+/// ```
+pub fn parse_sentence(input: &str) -> Result<ParsedSentence, Error<'_>> {
+    if !input.is_ascii() {
+        return Err(Error::ASCII);
+    }
+
+    let nmea_sentence = parse_nmea_sentence_checked(input)?;
+    let sentence_type = nmea_sentence.message_id;
+    match parse_sentence_data(nmea_sentence) {
+        Err(Error::DisabledSentence) => Ok(ParsedSentence::Unsupported(sentence_type)),
+        result => result,
+    }
+}
+
 /// Parse a NMEA 0183 sentence from bytes and extract data from it.
 ///
 /// # Errors
 ///
 /// Apart from errors returned by the message parsing itself, it will return
 /// [`Error::Utf8Decoding`] when the bytes are not a valid UTF-8 string.
-pub fn parse_bytes(sentence_input: &[u8]) -> Result<ParseResult, Error> {
+pub fn parse_bytes(sentence_input: &[u8]) -> Result<ParseResult, Error<'_>> {
     let string = core::str::from_utf8(sentence_input).map_err(|_err| Error::Utf8Decoding)?;
 
     parse_str(string)
@@ -191,278 +635,491 @@ pub fn parse_bytes(sentence_input: &[u8]) -> Result<ParseResult, Error> {
 ///
 /// Should not contain `\r\n` ending.
 ///
+/// The checksum may be omitted for the sentence types which don't
+/// [require](SentenceType::checksum_required) one.
+///
 /// # Errors
 ///
 /// - [`Error::ASCII`] when string contains non-ASCII characters.
-pub fn parse_str(sentence_input: &str) -> Result<ParseResult, Error> {
+pub fn parse_str(sentence_input: &str) -> Result<ParseResult, Error<'_>> {
     if !sentence_input.is_ascii() {
         return Err(Error::ASCII);
     }
 
-    let nmea_sentence = parse_nmea_sentence(sentence_input)?;
-    let calculated_checksum = nmea_sentence.calc_checksum();
-
-    if nmea_sentence.checksum == calculated_checksum {
-        // Ordered alphabetically
-        match nmea_sentence.message_id {
-            SentenceType::AAM => {
-                cfg_if! {
-                    if #[cfg(feature = "AAM")] {
-                        parse_aam(nmea_sentence).map(ParseResult::AAM)
-                    } else {
-                        return Err(Error::DisabledSentence);
-                    }
+    parse_sentence_data(parse_nmea_sentence_checked(sentence_input)?)
+}
+
+/// Dispatches the sentence to the parser of its [`SentenceType`],
+/// the checksum is not verified.
+fn parse_sentence_data(nmea_sentence: NmeaSentence) -> Result<ParseResult, Error> {
+    // Ordered alphabetically
+    match nmea_sentence.message_id {
+        SentenceType::AAM => {
+            cfg_if! {
+                if #[cfg(feature = "AAM")] {
+                    parse_aam(nmea_sentence).map(ParseResult::AAM)
+                } else {
+                    return Err(Error::DisabledSentence);
                 }
             }
-            SentenceType::ALM => {
-                cfg_if! {
-                    if #[cfg(feature = "ALM")] {
-                        parse_alm(nmea_sentence).map(ParseResult::ALM)
-                    } else {
-                        return Err(Error::DisabledSentence);
-                    }
+        }
+        SentenceType::ALM => {
+            cfg_if! {
+                if #[cfg(feature = "ALM")] {
+                    parse_alm(nmea_sentence).map(ParseResult::ALM)
+                } else {
+                    return Err(Error::DisabledSentence);
                 }
             }
-            SentenceType::APA => {
-                cfg_if! {
-                    if #[cfg(feature = "APA")] {
-                        parse_apa(nmea_sentence).map(ParseResult::APA)
-                    } else {
-                        return Err(Error::DisabledSentence);
-                    }
+        }
+        SentenceType::APA => {
+            cfg_if! {
+                if #[cfg(feature = "APA")] {
+                    parse_apa(nmea_sentence).map(ParseResult::APA)
+                } else {
+                    return Err(Error::DisabledSentence);
                 }
             }
-            SentenceType::BOD => {
-                cfg_if! {
-                    if #[cfg(feature = "BOD")] {
-                        parse_bod(nmea_sentence).map(ParseResult::BOD)
-                    } else {
-                        return Err(Error::DisabledSentence);
-                    }
+        }
+        SentenceType::BOD => {
+            cfg_if! {
+                if #[cfg(feature = "BOD")] {
+                    parse_bod(nmea_sentence).map(ParseResult::BOD)
+                } else {
+                    return Err(Error::DisabledSentence);
                 }
             }
-            SentenceType::BWC => {
-                cfg_if! {
-                    if #[cfg(feature = "BWC")] {
-                        parse_bwc(nmea_sentence).map(ParseResult::BWC)
-                    } else {
-                        return Err(Error::DisabledSentence);
-                    }
+        }
+        SentenceType::BWC => {
+            cfg_if! {
+                if #[cfg(feature = "BWC")] {
+                    parse_bwc(nmea_sentence).map(ParseResult::BWC)
+                } else {
+                    return Err(Error::DisabledSentence);
                 }
             }
-            SentenceType::BWW => {
-                cfg_if! {
-                    if #[cfg(feature = "BWW")] {
-                        parse_bww(nmea_sentence).map(ParseResult::BWW)
-                    } else {
-                        return Err(Error::DisabledSentence);
-                    }
+        }
+        SentenceType::BWW => {
+            cfg_if! {
+                if #[cfg(feature = "BWW")] {
+                    parse_bww(nmea_sentence).map(ParseResult::BWW)
+                } else {
+                    return Err(Error::DisabledSentence);
                 }
             }
-            SentenceType::DBK => {
-                cfg_if! {
-                    if #[cfg(feature = "DBK")] {
-                        parse_dbk(nmea_sentence).map(Into::into)
-                    } else {
-                        return Err(Error::DisabledSentence);
-                    }
+        }
+        SentenceType::DBK => {
+            cfg_if! {
+                if #[cfg(feature = "DBK")] {
+                    parse_dbk(nmea_sentence).map(Into::into)
+                } else {
+                    return Err(Error::DisabledSentence);
                 }
             }
-            SentenceType::GBS => {
-                cfg_if! {
-                    if #[cfg(feature = "GBS")] {
-                        parse_gbs(nmea_sentence).map(ParseResult::GBS)
-                    } else {
-                        return Err(Error::DisabledSentence);
-                    }
+        }
+        SentenceType::GBS => {
+            cfg_if! {
+                if #[cfg(feature = "GBS")] {
+                    parse_gbs(nmea_sentence).map(ParseResult::GBS)
+                } else {
+                    return Err(Error::DisabledSentence);
                 }
             }
-            SentenceType::GGA => {
-                cfg_if! {
-                    if #[cfg(feature = "GGA")] {
-                        parse_gga(nmea_sentence).map(ParseResult::GGA)
-                    } else {
-                        return Err(Error::DisabledSentence);
-                    }
+        }
+        SentenceType::GGA => {
+            cfg_if! {
+                if #[cfg(feature = "GGA")] {
+                    parse_gga(nmea_sentence).map(ParseResult::GGA)
+                } else {
+                    return Err(Error::DisabledSentence);
                 }
             }
-            SentenceType::GLL => {
-                cfg_if! {
-                    if #[cfg(feature = "GLL")] {
-                        parse_gll(nmea_sentence).map(ParseResult::GLL)
-                    } else {
-                        return Err(Error::DisabledSentence);
-                    }
+        }
+        SentenceType::GLL => {
+            cfg_if! {
+                if #[cfg(feature = "GLL")] {
+                    parse_gll(nmea_sentence).map(ParseResult::GLL)
+                } else {
+                    return Err(Error::DisabledSentence);
                 }
             }
-            SentenceType::GNS => {
-                cfg_if! {
-                    if #[cfg(feature = "GNS")] {
-                        parse_gns(nmea_sentence).map(ParseResult::GNS)
-                    } else {
-                        return Err(Error::DisabledSentence);
-                    }
+        }
+        SentenceType::GNS => {
+            cfg_if! {
+                if #[cfg(feature = "GNS")] {
+                    parse_gns(nmea_sentence).map(ParseResult::GNS)
+                } else {
+                    return Err(Error::DisabledSentence);
                 }
             }
-            SentenceType::GSA => {
-                cfg_if! {
-                    if #[cfg(feature = "GSA")] {
-                        parse_gsa(nmea_sentence).map(ParseResult::GSA)
-                    } else {
-                        return Err(Error::DisabledSentence);
-                    }
+        }
+        SentenceType::GSA => {
+            cfg_if! {
+                if #[cfg(feature = "GSA")] {
+                    parse_gsa(nmea_sentence).map(ParseResult::GSA)
+                } else {
+                    return Err(Error::DisabledSentence);
                 }
             }
-            SentenceType::GST => {
-                cfg_if! {
-                    if #[cfg(feature = "GST")] {
-                        parse_gst(nmea_sentence).map(ParseResult::GST)
-                    } else {
-                        return Err(Error::DisabledSentence);
-                    }
+        }
+        SentenceType::GST => {
+            cfg_if! {
+                if #[cfg(feature = "GST")] {
+                    parse_gst(nmea_sentence).map(ParseResult::GST)
+                } else {
+                    return Err(Error::DisabledSentence);
                 }
             }
-            SentenceType::GSV => {
-                cfg_if! {
-                    if #[cfg(feature = "GSV")] {
-                        parse_gsv(nmea_sentence).map(ParseResult::GSV)
-                    } else {
-                        return Err(Error::DisabledSentence);
-                    }
+        }
+        SentenceType::GSV => {
+            cfg_if! {
+                if #[cfg(feature = "GSV")] {
+                    parse_gsv(nmea_sentence).map(ParseResult::GSV)
+                } else {
+                    return Err(Error::DisabledSentence);
                 }
             }
-            SentenceType::HDT => {
-                cfg_if! {
-                    if #[cfg(feature = "HDT")] {
-                        parse_hdt(nmea_sentence).map(ParseResult::HDT)
-                    } else {
-                        return Err(Error::DisabledSentence);
-                    }
+        }
+        SentenceType::HDT => {
+            cfg_if! {
+                if #[cfg(feature = "HDT")] {
+                    parse_hdt(nmea_sentence).map(ParseResult::HDT)
+                } else {
+                    return Err(Error::DisabledSentence);
                 }
             }
-            SentenceType::MDA => {
-                cfg_if! {
-                    if #[cfg(feature = "MDA")] {
-                        parse_mda(nmea_sentence).map(ParseResult::MDA)
-                    } else {
-                        return Err(Error::DisabledSentence);
-                    }
+        }
+        SentenceType::MDA => {
+            cfg_if! {
+                if #[cfg(feature = "MDA")] {
+                    parse_mda(nmea_sentence).map(ParseResult::MDA)
+                } else {
+                    return Err(Error::DisabledSentence);
                 }
             }
-            SentenceType::MTW => {
-                cfg_if! {
-                    if #[cfg(feature = "MTW")] {
-                        parse_mtw(nmea_sentence).map(ParseResult::MTW)
-                    } else {
-                        return Err(Error::DisabledSentence);
-                    }
+        }
+        SentenceType::MTW => {
+            cfg_if! {
+                if #[cfg(feature = "MTW")] {
+                    parse_mtw(nmea_sentence).map(ParseResult::MTW)
+                } else {
+                    return Err(Error::DisabledSentence);
                 }
             }
-            SentenceType::MWV => {
-                cfg_if! {
-                    if #[cfg(feature = "MWV")] {
-                        parse_mwv(nmea_sentence).map(ParseResult::MWV)
-                    } else {
-                        return Err(Error::DisabledSentence);
-                    }
+        }
+        SentenceType::MWV => {
+            cfg_if! {
+                if #[cfg(feature = "MWV")] {
+                    parse_mwv(nmea_sentence).map(ParseResult::MWV)
+                } else {
+                    return Err(Error::DisabledSentence);
+                }
+            }
+        }
+        SentenceType::RMC => {
+            cfg_if! {
+                if #[cfg(feature = "RMC")] {
+                    parse_rmc(nmea_sentence).map(ParseResult::RMC)
+                } else {
+                    return Err(Error::DisabledSentence);
                 }
             }
-            SentenceType::RMC => {
-                cfg_if! {
-                    if #[cfg(feature = "RMC")] {
-                        parse_rmc(nmea_sentence).map(ParseResult::RMC)
-                    } else {
-                        return Err(Error::DisabledSentence);
-                    }
+        }
+        SentenceType::RMZ => {
+            cfg_if! {
+                if #[cfg(feature = "RMZ")] {
+                    parse_pgrmz(nmea_sentence).map(ParseResult::PGRMZ)
+                } else {
+                    return Err(Error::DisabledSentence);
+                }
+            }
+        }
+        SentenceType::TLL => {
+            cfg_if! {
+                if #[cfg(feature = "TLL")] {
+                    parse_tll(nmea_sentence).map(ParseResult::TLL)
+                } else {
+                    return Err(Error::DisabledSentence);
+                }
+            }
+        }
+        SentenceType::TTM => {
+            cfg_if! {
+                if #[cfg(feature = "TTM")] {
+                    parse_ttm(nmea_sentence).map(ParseResult::TTM)
+                } else {
+                    return Err(Error::DisabledSentence);
                 }
             }
-            SentenceType::RMZ => {
-                cfg_if! {
-                    if #[cfg(feature = "RMZ")] {
-                        parse_pgrmz(nmea_sentence).map(ParseResult::PGRMZ)
-                    } else {
-                        return Err(Error::DisabledSentence);
-                    }
+        }
+        SentenceType::TXT => {
+            cfg_if! {
+                if #[cfg(feature = "TXT")] {
+                    parse_txt(nmea_sentence).map(ParseResult::TXT)
+                } else {
+                    return Err(Error::DisabledSentence);
                 }
             }
-            SentenceType::TTM => {
-                cfg_if! {
-                    if #[cfg(feature = "TTM")] {
-                        parse_ttm(nmea_sentence).map(ParseResult::TTM)
-                    } else {
-                        return Err(Error::DisabledSentence);
-                    }
+        }
+        SentenceType::VDM => {
+            cfg_if! {
+                if #[cfg(feature = "VDM")] {
+                    parse_vdm(nmea_sentence).map(ParseResult::VDM)
+                } else {
+                    return Err(Error::DisabledSentence);
                 }
             }
-            SentenceType::TXT => {
-                cfg_if! {
-                    if #[cfg(feature = "TXT")] {
-                        parse_txt(nmea_sentence).map(ParseResult::TXT)
-                    } else {
-                        return Err(Error::DisabledSentence);
-                    }
+        }
+        SentenceType::VDO => {
+            cfg_if! {
+                if #[cfg(feature = "VDO")] {
+                    parse_vdm(nmea_sentence).map(ParseResult::VDO)
+                } else {
+                    return Err(Error::DisabledSentence);
                 }
             }
-            SentenceType::VHW => {
-                cfg_if! {
-                    if #[cfg(feature = "VHW")] {
-                        parse_vhw(nmea_sentence).map(ParseResult::VHW)
-                    } else {
-                        return Err(Error::DisabledSentence);
-                    }
+        }
+        SentenceType::VHW => {
+            cfg_if! {
+                if #[cfg(feature = "VHW")] {
+                    parse_vhw(nmea_sentence).map(ParseResult::VHW)
+                } else {
+                    return Err(Error::DisabledSentence);
                 }
             }
-            SentenceType::VTG => {
-                cfg_if! {
-                    if #[cfg(feature = "VTG")] {
-                        parse_vtg(nmea_sentence).map(ParseResult::VTG)
-                    } else {
-                        return Err(Error::DisabledSentence);
-                    }
+        }
+        SentenceType::VTG => {
+            cfg_if! {
+                if #[cfg(feature = "VTG")] {
+                    parse_vtg(nmea_sentence).map(ParseResult::VTG)
+                } else {
+                    return Err(Error::DisabledSentence);
                 }
             }
-            SentenceType::WNC => {
-                cfg_if! {
-                    if #[cfg(feature = "WNC")] {
-                        parse_wnc(nmea_sentence).map(ParseResult::WNC)
-                    } else {
-                        return Err(Error::DisabledSentence);
-                    }
+        }
+        SentenceType::WNC => {
+            cfg_if! {
+                if #[cfg(feature = "WNC")] {
+                    parse_wnc(nmea_sentence).map(ParseResult::WNC)
+                } else {
+                    return Err(Error::DisabledSentence);
                 }
             }
-            SentenceType::ZDA => {
-                cfg_if! {
-                    if #[cfg(feature = "ZDA")] {
-                        parse_zda(nmea_sentence).map(ParseResult::ZDA)
-                    } else {
-                        return Err(Error::DisabledSentence);
-                    }
+        }
+        SentenceType::ZDA => {
+            cfg_if! {
+                if #[cfg(feature = "ZDA")] {
+                    parse_zda(nmea_sentence).map(ParseResult::ZDA)
+                } else {
+                    return Err(Error::DisabledSentence);
                 }
             }
-            SentenceType::ZFO => {
-                cfg_if! {
-                    if #[cfg(feature = "ZFO")] {
-                        parse_zfo(nmea_sentence).map(ParseResult::ZFO)
-                    } else {
-                        return Err(Error::DisabledSentence);
-                    }
+        }
+        SentenceType::ZFO => {
+            cfg_if! {
+                if #[cfg(feature = "ZFO")] {
+                    parse_zfo(nmea_sentence).map(ParseResult::ZFO)
+                } else {
+                    return Err(Error::DisabledSentence);
                 }
             }
-            SentenceType::ZTG => {
-                cfg_if! {
-                    if #[cfg(feature = "ZTG")] {
-                        parse_ztg(nmea_sentence).map(ParseResult::ZTG)
-                    } else {
-                        return Err(Error::DisabledSentence);
-                    }
+        }
+        SentenceType::ZTG => {
+            cfg_if! {
+                if #[cfg(feature = "ZTG")] {
+                    parse_ztg(nmea_sentence).map(ParseResult::ZTG)
+                } else {
+                    return Err(Error::DisabledSentence);
                 }
             }
-            sentence_type => Ok(ParseResult::Unsupported(sentence_type)),
         }
-    } else {
-        Err(Error::ChecksumMismatch {
-            calculated: calculated_checksum,
-            found: nmea_sentence.checksum,
+        sentence_type => Ok(ParseResult::Unsupported(sentence_type)),
+    }
+}
+
+/// Best-effort parsing of a single line which never returns an error.
+///
+/// Unlike [`parse_str`] it:
+/// - ignores surrounding whitespace and the `\r\n` ending,
+/// - ignores a missing, malformed or wrong checksum,
+/// - blanks the fields the sentence parser fails on, so malformed optional
+///   fields result in `None` fields instead of an error.
+///
+/// Returns `None` when the line is not recognizable as a sentence, when a
+/// required field is malformed or when the sentence is disabled.
+///
+/// # Examples
+///
+/// ```
+/// use nmea::{parse_lenient_all, ParseResult};
+///
+/// // corrupt checksum and a malformed altitude
+/// let line = "$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,6x.7,M,55.2,M,,*00";
+/// # #[cfg(feature = "GGA")]
+/// match parse_lenient_all(line) {
+///     Some(ParseResult::GGA(gga)) => {
+///         assert_eq!(gga.fix_satellites, Some(8));
+///         assert_eq!(gga.altitude, None);
+///     }
+///     _ => panic!("GGA should be parsed"),
+/// }
+/// ```
+pub fn parse_lenient_all(line: &str) -> Option<ParseResult> {
+    let line = line.trim();
+    if !line.is_ascii() || line.len() > SENTENCE_MAX_LEN {
+        return None;
+    }
+    let (_, sentence) = do_parse_lenient_nmea_sentence(line).ok()?;
+
+    let mut blanked = [false; SENTENCE_MAX_LEN];
+    let mut data = blank_fields(sentence.data, &blanked);
+    loop {
+        let attempt = NmeaSentence {
+            data: &data,
+            ..sentence
+        };
+        let failed_field = match parse_sentence_data(attempt) {
+            Ok(result) => return Some(result),
+            Err(Error::ParsingError(nom::Err::Error(error) | nom::Err::Failure(error))) => {
+                field_containing(&data, error.input)?
+            }
+            Err(
+                error @ (Error::InvalidIndicator(_)
+                | Error::ParameterLength { .. }
+                | Error::InvalidCoordinate(_)),
+            ) => find_failed_field(sentence, &blanked, &error)?,
+            Err(_) => return None,
+        };
+        // blanking the field didn't help, e.g. it's a required one
+        if blanked[failed_field] {
+            return None;
+        }
+        blanked[failed_field] = true;
+        data = blank_fields(sentence.data, &blanked);
+    }
+}
+
+/// Returns the index of the field of `data` which `input`, the remaining input
+/// of a failed parser, points into.
+///
+/// The remaining input can be a slice of a single field (sub-parsers like
+/// `map_parser` parse the field on its own) so its position is taken from the
+/// pointer instead of its length.
+fn field_containing(data: &str, input: &str) -> Option<usize> {
+    let offset = (input.as_ptr() as usize).checked_sub(data.as_ptr() as usize)?;
+    Some(data.get(..offset)?.matches(',').count())
+}
+
+/// Finds the field causing an `error` which doesn't tell its position, e.g. a
+/// [`Error::ParameterLength`] of a field converted after parsing: the first
+/// field whose blanking resolves the error.
+fn find_failed_field(
+    sentence: NmeaSentence,
+    blanked: &[bool; SENTENCE_MAX_LEN],
+    error: &Error,
+) -> Option<usize> {
+    let may_cause = |field: &str| match *error {
+        Error::InvalidIndicator(indicator) => field.contains(indicator),
+        Error::ParameterLength {
+            parameter_length, ..
+        } => field.len() >= parameter_length,
+        _ => true,
+    };
+
+    sentence
+        .data
+        .split(',')
+        .enumerate()
+        .filter(|&(index, field)| !blanked[index] && !field.is_empty() && may_cause(field))
+        .map(|(index, _)| index)
+        .find(|&index| {
+            let mut trial = *blanked;
+            trial[index] = true;
+            let data = blank_fields(sentence.data, &trial);
+            let attempt = NmeaSentence {
+                data: &data,
+                ..sentence
+            };
+            !matches!(parse_sentence_data(attempt), Err(other) if other == *error)
         })
+}
+
+/// Copies the comma separated `data` replacing the `blanked` fields with empty ones.
+fn blank_fields(data: &str, blanked: &[bool]) -> ArrayString<SENTENCE_MAX_LEN> {
+    let mut blanked_data = ArrayString::new();
+    for (index, field) in data.split(',').enumerate() {
+        if index > 0 {
+            blanked_data.push(',');
+        }
+        if !blanked[index] {
+            blanked_data.push_str(field);
+        }
+    }
+    blanked_data
+}
+
+/// Parses the envelope of a sentence without requiring a valid checksum.
+fn do_parse_lenient_nmea_sentence(i: &str) -> IResult<&str, NmeaSentence<'_>> {
+    let (i, talker_id) = preceded(one_of("$!"), take(2usize))(i)?;
+    let (i, message_id) = parse_sentence_type(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, data) = take_till(|c| c == '*')(i)?;
+    let checksum = parse_checksum(i).ok().map(|(_, checksum)| checksum);
+
+    Ok((
+        "",
+        NmeaSentence {
+            talker_id,
+            message_id,
+            data,
+            checksum,
+            tag_block: None,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sentence_unsupported() {
+        assert_eq!(
+            parse_sentence("$GPDTM,W84,,0.0,N,0.0,E,0.0,W84*6F"),
+            Ok(ParsedSentence::Unsupported(SentenceType::DTM))
+        );
+    }
+
+    #[test]
+    fn test_field_containing_sub_parser_input() {
+        use nom::{bytes::complete::take_until, combinator::map_parser};
+
+        use crate::sentences::faa_mode::parse_faa_modes;
+
+        let data = "1.5,AZ,3";
+        let field = data.get(4..).unwrap();
+        let Err(nom::Err::Failure(error)) = map_parser(take_until(","), parse_faa_modes)(field)
+        else {
+            panic!("the mode field should fail");
+        };
+        // the sub-parser fails on the last byte of its slice of the field
+        assert_eq!(error.input, "Z");
+        assert_eq!(field_containing(data, error.input), Some(1));
+        assert_eq!(field_containing(data, &data[data.len()..]), Some(2));
+        // not a slice of the data
+        assert_eq!(field_containing(field, data), None);
+    }
+
+    #[test]
+    #[cfg(not(feature = "HDT"))]
+    fn test_parse_sentence_disabled() {
+        assert_eq!(
+            parse_sentence("$GPHDT,274.07,T*03"),
+            Ok(ParsedSentence::Unsupported(SentenceType::HDT))
+        );
+        assert_eq!(
+            parse_str("$GPHDT,274.07,T*03"),
+            Err(Error::DisabledSentence)
+        );
     }
 }
@@ -1,8 +1,7 @@
 //! The [`Nmea`] parser.
 
-use core::{fmt, mem, ops::BitOr};
+use core::{cmp::Ordering, fmt, mem, ops::BitOr};
 
-use chrono::{NaiveDate, NaiveTime};
 use heapless::{Deque, Vec};
 
 use crate::{
@@ -32,16 +31,15 @@ use serde::{de::Visitor, ser::SerializeSeq, Deserialize, Serialize};
 /// nmea.parse(gga).unwrap();
 /// println!("{}", nmea);
 /// # }
-
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[derive(Debug, Clone, Default)]
 pub struct Nmea {
     #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
-    pub fix_time: Option<NaiveTime>,
+    pub fix_time: Option<Time>,
     #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
-    pub fix_date: Option<NaiveDate>,
+    pub fix_date: Option<Date>,
     pub fix_type: Option<FixType>,
     pub latitude: Option<f64>,
     pub longitude: Option<f64>,
@@ -59,7 +57,7 @@ pub struct Nmea {
     satellites_scan: [SatsPack; GnssType::COUNT],
     required_sentences_for_nav: SentenceMask,
     #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
-    last_fix_time: Option<NaiveTime>,
+    last_fix_time: Option<Time>,
     last_txt: Option<TxtData>,
     sentences_for_this_time: SentenceMask,
 }
@@ -95,7 +93,7 @@ impl<'a> Nmea {
     }
 
     /// Returns fix type
-    pub fn fix_timestamp(&self) -> Option<NaiveTime> {
+    pub fn fix_timestamp(&self) -> Option<Time> {
         self.fix_time
     }
 
@@ -156,6 +154,83 @@ impl<'a> Nmea {
         ret
     }
 
+    /// Returns the average signal-to-noise ratio (dB-Hz) of the tracked
+    /// satellites, ignoring satellites without an SNR.
+    ///
+    /// Returns `None` if no satellite reports an SNR.
+    pub fn average_snr(&self) -> Option<f32> {
+        let snrs = self.snrs();
+        if snrs.is_empty() {
+            return None;
+        }
+
+        Some(snrs.iter().sum::<f32>() / snrs.len() as f32)
+    }
+
+    /// Returns the median signal-to-noise ratio (dB-Hz) of the tracked
+    /// satellites, ignoring satellites without an SNR.
+    ///
+    /// Returns `None` if no satellite reports an SNR.
+    pub fn median_snr(&self) -> Option<f32> {
+        let mut snrs = self.snrs();
+        if snrs.is_empty() {
+            return None;
+        }
+
+        snrs.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal));
+        let middle = snrs.len() / 2;
+        if snrs.len() % 2 == 0 {
+            Some((snrs[middle - 1] + snrs[middle]) / 2.0)
+        } else {
+            Some(snrs[middle])
+        }
+    }
+
+    /// Returns the number of tracked satellites with an SNR of at least `snr` dB-Hz.
+    pub fn satellites_above(&self, snr: u8) -> usize {
+        self.snrs()
+            .iter()
+            .filter(|&&sat_snr| sat_snr >= f32::from(snr))
+            .count()
+    }
+
+    /// Returns a 0–1 score of how well the tracked satellites with an
+    /// elevation of at least `min_elevation` degrees cover the sky in azimuth.
+    ///
+    /// The score is `1 - largest_gap / 360`, where `largest_gap` is the widest
+    /// azimuth sector without any satellite, so satellites clustered in one
+    /// direction score low and satellites spread around the horizon score high.
+    ///
+    /// Returns `None` if no satellite above `min_elevation` reports an azimuth.
+    pub fn geometry_spread(&self, min_elevation: f32) -> Option<f32> {
+        let mut azimuths: Vec<f32, 58> = self
+            .satellites()
+            .iter()
+            .filter(|sat| sat.elevation().map_or(false, |elev| elev >= min_elevation))
+            .filter_map(|sat| sat.azimuth())
+            .map(|azimuth| azimuth % 360.)
+            .collect();
+        if azimuths.is_empty() {
+            return None;
+        }
+
+        azimuths.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal));
+        let wrap_gap = 360. - azimuths[azimuths.len() - 1] + azimuths[0];
+        let largest_gap = azimuths
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .fold(wrap_gap, f32::max);
+
+        Some(1. - largest_gap / 360.)
+    }
+
+    fn snrs(&self) -> Vec<f32, 58> {
+        self.satellites()
+            .iter()
+            .filter_map(|sat| sat.snr())
+            .collect()
+    }
+
     fn merge_gga_data(&mut self, gga_data: GgaData) {
         self.fix_time = gga_data.fix_time;
         self.latitude = gga_data.latitude;
@@ -388,7 +463,50 @@ impl<'a> Nmea {
             | ParseResult::ZDA(_)
             | ParseResult::ZFO(_)
             | ParseResult::WNC(_)
-            | ParseResult::ZTG(_) => return Ok(FixType::Invalid),
+            | ParseResult::ZTG(_)
+            | ParseResult::VDM(_)
+            | ParseResult::VDO(_)
+            | ParseResult::TLL(_)
+            | ParseResult::RMB(_)
+            | ParseResult::XDR(_)
+            | ParseResult::MWD(_)
+            | ParseResult::VWR(_)
+            | ParseResult::VBW(_)
+            | ParseResult::GRS(_)
+            | ParseResult::THS(_)
+            | ParseResult::RPM(_)
+            | ParseResult::ALR(_)
+            | ParseResult::ACK(_)
+            | ParseResult::HSC(_)
+            | ParseResult::OSD(_)
+            | ParseResult::RMA(_)
+            | ParseResult::BWR(_)
+            | ParseResult::WCV(_)
+            | ParseResult::MTA(_)
+            | ParseResult::MMB(_)
+            | ParseResult::MHU(_)
+            | ParseResult::MWH(_)
+            | ParseResult::CUR(_)
+            | ParseResult::GLC(_)
+            | ParseResult::DBS(_)
+            | ParseResult::VWT(_)
+            | ParseResult::HDM(_)
+            | ParseResult::GTD(_)
+            | ParseResult::RMM(_)
+            | ParseResult::DSC(_)
+            | ParseResult::DSE(_)
+            | ParseResult::HBT(_)
+            | ParseResult::VDR(_)
+            | ParseResult::VPW(_)
+            | ParseResult::STN(_)
+            | ParseResult::GMP(_)
+            | ParseResult::FSI(_)
+            | ParseResult::NRX(_)
+            | ParseResult::SFI(_)
+            | ParseResult::TRF(_)
+            | ParseResult::EPV(_)
+            | ParseResult::HTC(_)
+            | ParseResult::HTD(_) => return Ok(FixType::Invalid),
 
             ParseResult::Unsupported(_) => {
                 return Ok(FixType::Invalid);
@@ -411,7 +529,7 @@ impl<'a> Nmea {
         self.last_txt.as_ref()
     }
 
-    fn update_fix_time(&mut self, fix_time: Option<NaiveTime>) -> bool {
+    fn update_fix_time(&mut self, fix_time: Option<Time>) -> bool {
         match (self.last_fix_time, fix_time) {
             (Some(ref last_fix_time), Some(ref new_fix_time)) => {
                 if *last_fix_time != *new_fix_time {
@@ -507,6 +625,7 @@ mod serde_deq {
 }
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[derive(Clone, PartialEq)]
 /// Satellite information
@@ -539,6 +658,11 @@ impl Satellite {
     pub fn snr(&self) -> Option<f32> {
         self.snr
     }
+    /// The constellation derived from the PRN, see [`utils::constellation_from_prn`].
+    #[inline]
+    pub fn constellation(&self) -> Option<GnssSystem> {
+        utils::constellation_from_prn(self.prn)
+    }
 }
 
 impl fmt::Display for Satellite {
@@ -572,6 +696,22 @@ macro_rules! count_tts {
 }
 pub(crate) use count_tts;
 
+/// The error of parsing a [`SentenceType`] from an unknown mnemonic with
+/// [`str::parse`], [`SentenceType::try_from`] returns the borrowing
+/// [`Error::Unknown`] instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownSentenceType;
+
+impl fmt::Display for UnknownSentenceType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Unknown NMEA sentence type")
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for UnknownSentenceType {}
+
 macro_rules! define_sentence_type_enum {
     (
         $(#[$outer:meta])*
@@ -615,16 +755,16 @@ macro_rules! define_sentence_type_enum {
             }
         }
 
-        // impl core::str::FromStr for $Name {
-        //     type Err = crate::Error;
+        impl core::str::FromStr for $Name {
+            type Err = UnknownSentenceType;
 
-        //     fn from_str(s: &'a str) -> Result<Self, Self::Err> {
-        //         match s {
-        //             $(stringify!($Variant) => Ok($Name::$Variant),)*
-        //             _ => Err(crate::Error::Unknown(s)),
-        //         }
-        //     }
-        // }
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $(stringify!($Variant) => Ok($Name::$Variant),)*
+                    _ => Err(UnknownSentenceType),
+                }
+            }
+        }
 
         impl core::fmt::Display for $Name {
             fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> fmt::Result {
@@ -641,13 +781,18 @@ define_sentence_type_enum! {
     ///
     /// ### General
     ///
+    /// - [`SentenceType::EPV`]
+    /// - [`SentenceType::HBT`]
     /// - [`SentenceType::OSD`]
+    /// - [`SentenceType::STN`]
     ///
     /// ### Autopilot:
     ///
     /// - [`SentenceType::APA`]
     /// - [`SentenceType::APB`]
     /// - [`SentenceType::ASD`]
+    /// - [`SentenceType::HTC`]
+    /// - [`SentenceType::HTD`]
     ///
     /// ### Decca
     ///
@@ -664,7 +809,10 @@ define_sentence_type_enum! {
     ///
     /// ### Radio
     ///
+    /// - [`SentenceType::DSC`]
+    /// - [`SentenceType::DSE`]
     /// - [`SentenceType::FSI`]
+    /// - [`SentenceType::NRX`]
     /// - [`SentenceType::SFI`]
     /// - [`SentenceType::TLL`]
     ///
@@ -717,6 +865,8 @@ define_sentence_type_enum! {
     ///
     /// - [`SentenceType::GLL`]
     /// - [`SentenceType::DTM`]
+    /// - [`SentenceType::RMM`]
+    /// - [`SentenceType::GMP`]
     ///
     /// ### Radar
     ///
@@ -735,7 +885,7 @@ define_sentence_type_enum! {
     /// ### Transit
     ///
     /// - [`SentenceType::GXA`]
-    /// - `SentenceType::RTF` (missing?!)
+    /// - [`SentenceType::TRF`]
     ///
     /// ### Waypoints and tacks
     ///
@@ -760,6 +910,7 @@ define_sentence_type_enum! {
     /// - [`SentenceType::MWV`]
     /// - [`SentenceType::VPW`]
     /// - [`SentenceType::VWR`]
+    /// - [`SentenceType::VWT`]
     ///
     /// ### Date and Time
     ///
@@ -866,7 +1017,17 @@ define_sentence_type_enum! {
         ///
         /// Type: `Course`
         DPT,
+        /// DSC - Digital Selective Calling Information
+        ///
+        /// <https://gpsd.gitlab.io/gpsd/NMEA.html#_dsc_digital_selective_calling_information>
+        ///
+        /// Type: `Radio`
         DSC,
+        /// DSE - Expanded Digital Selective Calling
+        ///
+        /// <https://gpsd.gitlab.io/gpsd/NMEA.html#_dse_extended_digital_selective_calling>
+        ///
+        /// Type: `Radio`
         DSE,
         DSI,
         /// Type: `Radar`
@@ -877,13 +1038,17 @@ define_sentence_type_enum! {
         ///
         /// Type: `Position`
         DTM,
+        /// EPV - Command or Report Equipment Property Value
+        ///
+        /// Type: `General`
+        EPV,
         /// FSI - Frequency Set Information
         ///
         /// <https://gpsd.gitlab.io/gpsd/NMEA.html#_fsi_frequency_set_information>
         ///
         /// Type: `Radio`
         FSI,
-        /// GBS - GPS Satellite Fault Detection
+        /// GBS - GNSS Satellite Fault Detection
         ///
         /// <https://gpsd.gitlab.io/gpsd/NMEA.html#_gbs_gps_satellite_fault_detection>
         ///
@@ -907,6 +1072,9 @@ define_sentence_type_enum! {
         ///
         /// Type: `Position`
         GLL,
+        /// GMP - GNSS Map Projection Fix Data
+        ///
+        /// Type: `Position`
         GMP,
         /// GNS - Fix data
         ///
@@ -914,7 +1082,7 @@ define_sentence_type_enum! {
         ///
         /// Type: `GPS`
         GNS,
-        /// GRS - GPS Range Residuals
+        /// GRS - GNSS Range Residuals
         ///
         /// <https://gpsd.gitlab.io/gpsd/NMEA.html#_grs_gps_range_residuals>
         GRS,
@@ -946,6 +1114,10 @@ define_sentence_type_enum! {
         ///
         /// Type: `Transit`
         GXA,
+        /// HBT - Heartbeat Supervision
+        ///
+        /// Type: `General`
+        HBT,
         /// HDG - Heading - Deviation & Variation
         ///
         /// <https://gpsd.gitlab.io/gpsd/NMEA.html#_hdg_heading_deviation_variation>
@@ -981,7 +1153,13 @@ define_sentence_type_enum! {
         ///
         /// <https://gpsd.gitlab.io/gpsd/NMEA.html#_hwbias_unknown>
         HWBIAS,
+        /// HTC - Heading/Track Control Command
+        ///
+        /// Type: `Autopilot`
         HTC,
+        /// HTD - Heading/Track Control Data
+        ///
+        /// Type: `Autopilot`
         HTD,
         /// ITS - Trawl Door Spread 2 Distance
         ///
@@ -1002,7 +1180,19 @@ define_sentence_type_enum! {
         ///
         /// <https://gpsd.gitlab.io/gpsd/NMEA.html#_mda_meteorological_composite>
         MDA,
+        /// MHU - Humidity
+        ///
+        /// <https://gpsd.gitlab.io/gpsd/NMEA.html#_mhu_humidity>
+        ///
+        /// Type: `Weather`
+        MHU,
         MLA,
+        /// MMB - Barometer
+        ///
+        /// <https://gpsd.gitlab.io/gpsd/NMEA.html#_mmb_barometer>
+        ///
+        /// Type: `Weather`
+        MMB,
         /// MSK - Control for a Beacon Receiver
         ///
         /// <https://gpsd.gitlab.io/gpsd/NMEA.html#_msk_control_for_a_beacon_receiver>
@@ -1014,18 +1204,34 @@ define_sentence_type_enum! {
         /// <https://gpsd.gitlab.io/gpsd/NMEA.html#_mss_beacon_receiver_status>
         MSS,
         MWD,
+        /// MTA - Air Temperature
+        ///
+        /// <https://gpsd.gitlab.io/gpsd/NMEA.html#_mta_air_temperature>
+        ///
+        /// Type: `Temperature`
+        MTA,
         /// MTW - Mean Temperature of Water
         ///
         /// <https://gpsd.gitlab.io/gpsd/NMEA.html#_mtw_mean_temperature_of_water>
         ///
         /// Type: `Temperature`
         MTW,
+        /// MWH - Wave Height
+        ///
+        /// <https://gpsd.gitlab.io/gpsd/NMEA.html#_mwh_wave_height>
+        ///
+        /// Type: `Weather`
+        MWH,
         /// MWV - Wind Speed and Angle
         ///
         /// <https://gpsd.gitlab.io/gpsd/NMEA.html#_mwv_wind_speed_and_angle>
         ///
         /// Type: `Wind`
         MWV,
+        /// NRX - NAVTEX Received Message
+        ///
+        /// Type: `Radio`
+        NRX,
         /// OLN - Omega Lane Numbers
         ///
         /// <https://gpsd.gitlab.io/gpsd/NMEA.html#_oln_omega_lane_numbers>
@@ -1065,6 +1271,10 @@ define_sentence_type_enum! {
         ///
         /// Type: `Navigation`
         RMC,
+        /// RMM - Map Datum
+        ///
+        /// Type: `Position`
+        RMM,
         /// PGRMZ - Garmin Altitude
         ///
         /// <https://gpsd.gitlab.io/gpsd/NMEA.html#_pgrmz_garmin_altitude>
@@ -1111,6 +1321,8 @@ define_sentence_type_enum! {
         /// STN - Multiple Data ID
         ///
         /// <https://gpsd.gitlab.io/gpsd/NMEA.html#_stn_multiple_data_id>
+        ///
+        /// Type: `General`
         STN,
         /// TDS - Trawl Door Spread Distance
         ///
@@ -1120,6 +1332,10 @@ define_sentence_type_enum! {
         ///
         /// <https://gpsd.gitlab.io/gpsd/NMEA.html#_tfi_trawl_filling_indicator>
         TFI,
+        /// THS - True Heading and Status
+        ///
+        /// <https://gpsd.gitlab.io/gpsd/NMEA.html#_ths_true_heading_and_status>
+        THS,
         /// TLB - Target Label
         ///
         /// <https://gpsd.gitlab.io/gpsd/NMEA.html#_tlb_target_label>
@@ -1144,6 +1360,8 @@ define_sentence_type_enum! {
         /// TRF - TRANSIT Fix Data
         ///
         /// <https://gpsd.gitlab.io/gpsd/NMEA.html#_trf_transit_fix_data>
+        ///
+        /// Type: `Transit`
         TRF,
         /// TTM - Tracked Target Message
         ///
@@ -1198,6 +1416,12 @@ define_sentence_type_enum! {
         ///
         /// Type: `Wind`
         VWR,
+        /// VWT - True Wind Speed and Angle
+        ///
+        /// <https://gpsd.gitlab.io/gpsd/NMEA.html#_vwt_true_wind_speed_and_angle>
+        ///
+        /// Type: `Wind`
+        VWT,
         /// WCV - Waypoint Closure Velocity
         ///
         /// <https://gpsd.gitlab.io/gpsd/NMEA.html#_wcv_waypoint_closure_velocity>
@@ -1256,6 +1480,102 @@ define_sentence_type_enum! {
     }
 }
 
+impl SentenceType {
+    /// Returns the name of the data struct the sentence type is parsed into,
+    /// e.g. `"ApaData"` for [`SentenceType::APA`].
+    ///
+    /// Returns an empty string for the sentence types which are not parsed by
+    /// the crate, they are returned as [`ParseResult::Unsupported`].
+    pub fn data_struct_name(&self) -> &'static str {
+        match self {
+            SentenceType::AAM => "AamData",
+            SentenceType::ACK => "AckData",
+            SentenceType::ALM => "AlmData",
+            SentenceType::ALR => "AlrData",
+            SentenceType::APA => "ApaData",
+            SentenceType::BOD => "BodData",
+            SentenceType::BWC => "BwcData",
+            SentenceType::BWR => "BwrData",
+            SentenceType::BWW => "BwwData",
+            SentenceType::CUR => "CurData",
+            SentenceType::DBK => "DbkData",
+            SentenceType::DBS => "DbsData",
+            SentenceType::DSC => "DscData",
+            SentenceType::DSE => "DseData",
+            SentenceType::EPV => "EpvData",
+            SentenceType::FSI => "FsiData",
+            SentenceType::GBS => "GbsData",
+            SentenceType::GGA => "GgaData",
+            SentenceType::GLC => "GlcData",
+            SentenceType::GLL => "GllData",
+            SentenceType::GMP => "GmpData",
+            SentenceType::GNS => "GnsData",
+            SentenceType::GRS => "GrsData",
+            SentenceType::GSA => "GsaData",
+            SentenceType::GST => "GstData",
+            SentenceType::GSV => "GsvData",
+            SentenceType::GTD => "GtdData",
+            SentenceType::HBT => "HbtData",
+            SentenceType::HDM => "HdmData",
+            SentenceType::HDT => "HdtData",
+            SentenceType::HSC => "HscData",
+            SentenceType::HTC => "HtcData",
+            SentenceType::HTD => "HtdData",
+            SentenceType::MDA => "MdaData",
+            SentenceType::MHU => "MhuData",
+            SentenceType::MMB => "MmbData",
+            SentenceType::MTA => "MtaData",
+            SentenceType::MTW => "MtwData",
+            SentenceType::MWD => "MwdData",
+            SentenceType::MWH => "MwhData",
+            SentenceType::MWV => "MwvData",
+            SentenceType::NRX => "NrxData",
+            SentenceType::OSD => "OsdData",
+            SentenceType::RMA => "RmaData",
+            SentenceType::RMB => "RmbData",
+            SentenceType::RMC => "RmcData",
+            SentenceType::RMM => "RmmData",
+            SentenceType::RMZ => "PgrmzData",
+            SentenceType::RPM => "RpmData",
+            SentenceType::SFI => "SfiData",
+            SentenceType::STN => "StnData",
+            SentenceType::THS => "ThsData",
+            SentenceType::TLL => "TllData",
+            SentenceType::TRF => "TrfData",
+            SentenceType::TTM => "TtmData",
+            SentenceType::TXT => "TxtData",
+            SentenceType::VBW => "VbwData",
+            SentenceType::VDM => "VdmData",
+            SentenceType::VDO => "VdmData",
+            SentenceType::VDR => "VdrData",
+            SentenceType::VHW => "VhwData",
+            SentenceType::VPW => "VpwData",
+            SentenceType::VTG => "VtgData",
+            SentenceType::VWR => "VwrData",
+            SentenceType::VWT => "VwtData",
+            SentenceType::WCV => "WcvData",
+            SentenceType::WNC => "WncData",
+            SentenceType::XDR => "XdrData",
+            SentenceType::ZDA => "ZdaData",
+            SentenceType::ZFO => "ZfoData",
+            SentenceType::ZTG => "ZtgData",
+            _ => "",
+        }
+    }
+
+    /// Whether the sentence type must be sent with a checksum.
+    ///
+    /// Heading and rate of turn sensors are commonly sent at high rates
+    /// without the `*hh` checksum, [`parse_str`] accepts these sentence
+    /// types without one. All the other sentence types require a checksum.
+    pub fn checksum_required(&self) -> bool {
+        !matches!(
+            self,
+            SentenceType::HDG | SentenceType::HDM | SentenceType::HDT | SentenceType::ROT
+        )
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
@@ -1277,6 +1597,21 @@ impl SentenceMask {
     }
 }
 
+/// Sentence types are ordered alphabetically by their name, e.g.
+/// `SentenceType::GGA < SentenceType::GLL`, independent of the order of the
+/// variants.
+impl Ord for SentenceType {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl PartialOrd for SentenceType {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl BitOr for SentenceType {
     type Output = SentenceMask;
     fn bitor(self, rhs: Self) -> Self::Output {
@@ -1301,7 +1636,11 @@ mod tests {
 
     use quickcheck::{QuickCheck, TestResult};
 
-    use crate::{parse::checksum, sentences::FixType, Error, Nmea, SentenceType};
+    use crate::{
+        parse::{checksum, verify},
+        sentences::FixType,
+        Error, Nmea, SentenceType, UnknownSentenceType,
+    };
 
     #[cfg(feature = "GGA")]
     fn check_parsing_lat_lon_in_gga(lat: f64, lon: f64) -> TestResult {
@@ -1326,7 +1665,7 @@ mod tests {
             lat_dir = if lat.is_sign_positive() { 'N' } else { 'S' },
             lon_dir = if lon.is_sign_positive() { 'E' } else { 'W' },
         );
-        let cs = checksum(s.as_bytes()[1..s.len() - 1].iter());
+        let cs = checksum(&s);
         s.push_str(&format!("{:02X}", cs));
         nmea.parse(&s).unwrap();
 
@@ -1355,13 +1694,43 @@ mod tests {
     fn test_checksum() {
         let valid = "$GNGSA,A,1,,,,,,,,,,,,,99.99,99.99,99.99*2E";
         let invalid = "$GNZDA,165118.00,13,05,2016,00,00*71";
-        assert_eq!(checksum(valid[1..valid.len() - 3].as_bytes().iter()), 0x2E);
-        assert_ne!(
-            checksum(invalid[1..invalid.len() - 3].as_bytes().iter()),
-            0x71
+        assert_eq!(checksum(valid), 0x2E);
+        assert_ne!(checksum(invalid), 0x71);
+    }
+
+    #[test]
+    fn test_checksum_raw_body() {
+        let apa = "$GPAPA,A,A,0.10,R,N,V,V,011,M,DEST,011,M*42";
+        let ztg = "$GPZTG,145832.12,042359.17,WPT*24";
+
+        assert_eq!(checksum(apa), 0x42);
+        assert_eq!(checksum("GPAPA,A,A,0.10,R,N,V,V,011,M,DEST,011,M"), 0x42);
+        assert_eq!(checksum(ztg), 0x24);
+        assert_eq!(checksum("GPZTG,145832.12,042359.17,WPT"), 0x24);
+        assert_eq!(
+            checksum("!AIVDM,1,1,,A,13aEOK?P00PD2wVMdLDRhgvL289?,0*26"),
+            0x26
         );
     }
 
+    #[test]
+    fn test_verify() {
+        assert!(verify("$GPAPA,A,A,0.10,R,N,V,V,011,M,DEST,011,M*42"));
+        assert!(verify("$GPZTG,145832.12,042359.17,WPT*24\r\n"));
+        assert!(verify("$GPZTG,145832.12,042359.17,WPT*24"));
+        assert!(verify(
+            "\\s:2573535,c:1671620143*0B\\!AIVDM,1,1,,A,13aEOK?P00PD2wVMdLDRhgvL289?,0*26"
+        ));
+
+        // wrong, missing or malformed checksum
+        assert!(!verify("$GPZTG,145832.12,042359.17,WPT*25"));
+        assert!(!verify("$GPZTG,145832.12,042359.17,WPT"));
+        assert!(!verify("$GPZTG,145832.12,042359.17,WPT*2"));
+        assert!(!verify("$GPZTG,145832.12,042359.17,WPT*+4"));
+        assert!(!verify("GPZTG,145832.12,042359.17,WPT*24"));
+        assert!(!verify("\\s:2573535$GPZTG,145832.12,042359.17,WPT*24"));
+    }
+
     #[test]
     fn test_message_type() {
         assert_eq!(SentenceType::try_from("GGA"), Ok(SentenceType::GGA));
@@ -1383,6 +1752,19 @@ mod tests {
             .quickcheck(check_parsing_lat_lon_in_gga as fn(f64, f64) -> TestResult);
     }
 
+    #[test]
+    fn test_sentence_type_from_str() {
+        assert_eq!(format!("{}", SentenceType::APA), "APA");
+        for sentence_type in SentenceType::TYPES {
+            assert_eq!(
+                sentence_type.to_string().parse::<SentenceType>(),
+                Ok(sentence_type)
+            );
+        }
+        assert_eq!("XXX".parse::<SentenceType>(), Err(UnknownSentenceType));
+        assert_eq!("apa".parse::<SentenceType>(), Err(UnknownSentenceType));
+    }
+
     #[test]
     fn test_sentence_type_enum() {
         // So we don't trip over the max value of u128 when shifting it with
@@ -1391,4 +1773,73 @@ mod tests {
             assert!((sentence_type as u32) < 127);
         }
     }
+
+    #[test]
+    fn test_sentence_type_ord_and_hash() {
+        use std::collections::{BTreeSet, HashMap};
+
+        // ROO is declared before RLM and LRI before LR1, but they are sorted
+        // alphabetically
+        assert!((SentenceType::ROO as u32) < (SentenceType::RLM as u32));
+        assert!(SentenceType::RLM < SentenceType::ROO);
+        assert!((SentenceType::LRI as u32) < (SentenceType::LR1 as u32));
+        assert!(SentenceType::LR1 < SentenceType::LRI);
+        assert!(SentenceType::GGA < SentenceType::GLL);
+
+        let handled: BTreeSet<_> = [SentenceType::RMC, SentenceType::GGA, SentenceType::GGA]
+            .into_iter()
+            .collect();
+        assert_eq!(
+            handled.into_iter().collect::<Vec<_>>(),
+            [SentenceType::GGA, SentenceType::RMC]
+        );
+
+        let mut counts = HashMap::new();
+        for sentence_type in [SentenceType::GSV, SentenceType::GSV, SentenceType::GSA] {
+            *counts.entry(sentence_type).or_insert(0) += 1;
+        }
+        assert_eq!(counts[&SentenceType::GSV], 2);
+
+        let mut sorted = SentenceType::TYPES;
+        sorted.sort();
+        assert!(sorted.windows(2).all(|w| w[0].as_str() < w[1].as_str()));
+    }
+
+    #[test]
+    fn test_sentence_type_data_struct_name() {
+        assert_eq!(SentenceType::APA.data_struct_name(), "ApaData");
+        assert_eq!(SentenceType::GGA.data_struct_name(), "GgaData");
+        assert_eq!(SentenceType::RMZ.data_struct_name(), "PgrmzData");
+        assert_eq!(SentenceType::ZTG.data_struct_name(), "ZtgData");
+        assert_eq!(SentenceType::XTE.data_struct_name(), "");
+    }
+
+    #[test]
+    fn test_sentence_type_checksum_required() {
+        assert!(!SentenceType::HDT.checksum_required());
+        assert!(!SentenceType::ROT.checksum_required());
+        assert!(SentenceType::GGA.checksum_required());
+        assert!(SentenceType::RMC.checksum_required());
+
+        #[cfg(feature = "HDT")]
+        assert!(matches!(
+            crate::parse_str("$HCHDT,274.07,T"),
+            Ok(crate::ParseResult::HDT(_))
+        ));
+        assert_eq!(
+            crate::parse_str("$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,"),
+            Err(Error::MissingChecksum(SentenceType::GGA))
+        );
+        assert_eq!(
+            crate::parse_nmea_sentence_checked(
+                "$GPRMC,092750.000,A,5321.6802,N,00630.3372,W,0.02,31.66,280511,,,A"
+            ),
+            Err(Error::MissingChecksum(SentenceType::RMC))
+        );
+        // a present checksum is still verified
+        assert!(matches!(
+            crate::parse_str("$HCHDT,274.07,T*00"),
+            Err(Error::ChecksumMismatch { .. })
+        ));
+    }
 }
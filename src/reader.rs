@@ -0,0 +1,253 @@
+//! Reading NMEA sentences from a [`BufRead`] source, e.g. a file or a serial port.
+
+use std::{
+    fmt,
+    io::{self, BufRead},
+};
+
+use crate::{parse_sentence, stream::SentenceBuffer, Error, ParsedSentence, SENTENCE_MAX_LEN};
+
+/// Error of the [`NmeaReader`].
+#[derive(Debug)]
+pub enum ReadError {
+    /// Reading from the source failed.
+    Io(io::Error),
+    /// The line is not a sentence which can be parsed, it's either longer than
+    /// [`SENTENCE_MAX_LEN`] ([`Error::SentenceLength`] with its length) or not
+    /// valid UTF-8 ([`Error::Utf8Decoding`]).
+    Line(Error<'static>),
+    /// The sentence could not be parsed.
+    Parse(ParseError),
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io(err) => write!(f, "I/O error: {}", err),
+            ReadError::Line(err) => write!(f, "Invalid line: {}", err),
+            ReadError::Parse(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReadError::Io(err) => Some(err),
+            ReadError::Line(err) => Some(err),
+            ReadError::Parse(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for ReadError {
+    fn from(err: io::Error) -> Self {
+        ReadError::Io(err)
+    }
+}
+
+/// A sentence which failed to parse.
+///
+/// The crate [`Error`] borrows the sentence it failed on, so the owned
+/// sentence is kept instead and [`ParseError::error`] returns the error
+/// borrowing from it.
+///
+/// # Examples
+///
+/// ```
+/// use nmea::{reader::{NmeaReader, ReadError}, Error};
+///
+/// let log = "$GPHDT,274.07,T*00\r\n";
+///
+/// match NmeaReader::new(log.as_bytes()).next() {
+///     Some(Err(ReadError::Parse(err))) => {
+///         assert_eq!(err.sentence(), "$GPHDT,274.07,T*00");
+///         assert!(matches!(err.error(), Error::ChecksumMismatch { .. }));
+///     }
+///     other => panic!("unexpected {:?}", other),
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    sentence: String,
+}
+
+impl ParseError {
+    /// Returns the sentence without its terminator.
+    pub fn sentence(&self) -> &str {
+        &self.sentence
+    }
+
+    /// Returns the error the sentence failed to parse with.
+    pub fn error(&self) -> Error<'_> {
+        // parsing is deterministic, this is the error the reader got
+        match parse_sentence(&self.sentence) {
+            Err(err) => err,
+            Ok(_) => unreachable!("the sentence failed to parse when it was read"),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Failed to parse '{}': {}", self.sentence, self.error())
+    }
+}
+
+/// Iterator parsing the sentences of a [`BufRead`] source.
+///
+/// The sentences are framed with a [`SentenceBuffer`]: both `\n` and `\r\n`
+/// line endings are handled, bytes outside of sentences (e.g. empty lines) are
+/// skipped and lines longer than [`SENTENCE_MAX_LEN`] are rejected without
+/// buffering them. A last sentence without a line ending is parsed at the end
+/// of the source.
+///
+/// # Examples
+///
+/// ```
+/// use nmea::{reader::NmeaReader, ParsedSentence};
+///
+/// let log = "$GPHDT,274.07,T*03\r\n\r\n$GPHDT,274.08,T*0C\n";
+///
+/// for sentence in NmeaReader::new(log.as_bytes()) {
+///     assert!(matches!(sentence, Ok(ParsedSentence::HDT(_))));
+/// }
+/// ```
+#[derive(Debug)]
+pub struct NmeaReader<R> {
+    reader: R,
+    buffer: SentenceBuffer<SENTENCE_MAX_LEN>,
+}
+
+impl<R: BufRead> NmeaReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buffer: SentenceBuffer::new(),
+        }
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Reads up to the end of the next line, returning it if it's a sentence.
+    fn next_line(
+        &mut self,
+    ) -> io::Result<Option<Result<heapless::String<SENTENCE_MAX_LEN>, Error<'static>>>> {
+        loop {
+            let chunk = match self.reader.fill_buf() {
+                Ok(chunk) => chunk,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            };
+            if chunk.is_empty() {
+                // terminate a last sentence without a line ending
+                return Ok(self.buffer.feed(b'\n'));
+            }
+
+            let mut line = None;
+            let mut consumed = 0;
+            for &byte in chunk {
+                consumed += 1;
+                line = self.buffer.feed(byte);
+                if line.is_some() {
+                    break;
+                }
+            }
+            self.reader.consume(consumed);
+            if line.is_some() {
+                return Ok(line);
+            }
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for NmeaReader<R> {
+    type Item = Result<ParsedSentence, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = match self.next_line() {
+            Ok(line) => line?,
+            Err(err) => return Some(Err(err.into())),
+        };
+        let sentence = match line {
+            Ok(sentence) => sentence,
+            Err(err) => return Some(Err(ReadError::Line(err))),
+        };
+
+        Some(parse_sentence(&sentence).map_err(|_| {
+            ReadError::Parse(ParseError {
+                sentence: sentence.as_str().into(),
+            })
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_endings_and_empty_lines() {
+        let log = "\n$GPHDT,274.07,T*03\r\n\r\n\n$GPVTG,071.9,T,061.7,M,000.48,N,0000.88,K,A*10\n$GPHDT,274.07,T*03";
+        let sentences: Vec<_> = NmeaReader::new(log.as_bytes())
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(sentences.len(), 3);
+        assert!(matches!(sentences[0], ParsedSentence::HDT(_)));
+        assert!(matches!(sentences[1], ParsedSentence::VTG(_)));
+        assert!(matches!(sentences[2], ParsedSentence::HDT(_)));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let log = "$GPHDT,274.07,T*00\r\n$GPHDT,274.07,T*03\r\n";
+        let mut reader = NmeaReader::new(log.as_bytes());
+
+        match reader.next() {
+            Some(Err(ReadError::Parse(err))) => {
+                assert_eq!(err.sentence(), "$GPHDT,274.07,T*00");
+                assert_eq!(
+                    err.error(),
+                    Error::ChecksumMismatch {
+                        calculated: 0x03,
+                        found: 0x00
+                    }
+                );
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+        assert!(matches!(reader.next(), Some(Ok(ParsedSentence::HDT(_)))));
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_unsupported_sentence() {
+        let log = "$GPDTM,W84,,0.0,N,0.0,E,0.0,W84*6F\n";
+        let mut reader = NmeaReader::new(log.as_bytes());
+
+        assert!(matches!(
+            reader.next(),
+            Some(Ok(ParsedSentence::Unsupported(crate::SentenceType::DTM)))
+        ));
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_line_too_long() {
+        let mut log = "$GPHDT,".to_string();
+        log.push_str(&"1".repeat(4000));
+        log.push_str("\n$GPHDT,274.07,T*03\n");
+        let mut reader = NmeaReader::new(io::BufReader::with_capacity(64, log.as_bytes()));
+
+        assert!(matches!(
+            reader.next(),
+            Some(Err(ReadError::Line(Error::SentenceLength(4007))))
+        ));
+        assert!(matches!(reader.next(), Some(Ok(ParsedSentence::HDT(_)))));
+        assert!(reader.next().is_none());
+    }
+}
@@ -0,0 +1,200 @@
+//! Real-time replay of recorded NMEA logs.
+//!
+//! The [`Replayer`] yields the sentences of a log after sleeping for the time
+//! elapsed between their own timestamps, which makes it possible to test the
+//! consumers of a receiver without the receiver.
+
+use core::time::Duration;
+
+use crate::{parse_str, sentences::TimeOfDay, ParseResult};
+
+const MILLISECONDS_PER_DAY: i64 = 24 * 60 * 60 * 1000;
+
+/// The clock the [`Replayer`] sleeps with.
+pub trait Clock {
+    fn sleep(&mut self, duration: Duration);
+}
+
+/// [`Clock`] sleeping with [`std::thread::sleep`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn sleep(&mut self, duration: Duration) {
+        std::thread::sleep(duration)
+    }
+}
+
+/// Replays sentences rate-limited by their timestamps.
+///
+/// The delays are derived from the time of the RMC, GGA and ZDA sentences.
+/// Sentences without a timestamp (or which can't be parsed) are yielded
+/// immediately.
+///
+/// # Examples
+///
+/// ```no_run
+/// use nmea::replay::Replayer;
+///
+/// let log = [
+///     "$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76",
+///     "$GPGGA,092751.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*77",
+/// ];
+///
+/// // replay twice as fast as recorded
+/// for sentence in Replayer::new(log).with_speed(2.0) {
+///     println!("{}", sentence);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Replayer<I, C = SystemClock> {
+    sentences: I,
+    clock: C,
+    speed: f64,
+    last_time: Option<TimeOfDay>,
+}
+
+impl<I> Replayer<I, SystemClock> {
+    pub fn new<T: IntoIterator<IntoIter = I>>(sentences: T) -> Self {
+        Self {
+            sentences: sentences.into_iter(),
+            clock: SystemClock,
+            speed: 1.0,
+            last_time: None,
+        }
+    }
+}
+
+impl<I, C> Replayer<I, C> {
+    /// Sets the replay speed multiplier, e.g. `2.0` replays twice as fast as recorded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `speed` is not finite and positive.
+    pub fn with_speed(mut self, speed: f64) -> Self {
+        assert!(
+            speed.is_finite() && speed > 0.0,
+            "replay speed must be finite and positive, got {}",
+            speed
+        );
+        self.speed = speed;
+        self
+    }
+
+    /// Replaces the clock used to sleep between the sentences.
+    pub fn with_clock<T: Clock>(self, clock: T) -> Replayer<I, T> {
+        Replayer {
+            sentences: self.sentences,
+            clock,
+            speed: self.speed,
+            last_time: self.last_time,
+        }
+    }
+}
+
+impl<I, C> Iterator for Replayer<I, C>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+    C: Clock,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let sentence = self.sentences.next()?;
+
+        if let Some(time) = sentence_time(sentence.as_ref()) {
+            if let Some(last_time) = self.last_time {
+                // the timestamps wrap around at midnight
+                let delta = (i64::from(time.milliseconds()) - i64::from(last_time.milliseconds()))
+                    .rem_euclid(MILLISECONDS_PER_DAY);
+                if delta != 0 {
+                    let delta = Duration::from_millis(delta as u64);
+                    self.clock.sleep(delta.div_f64(self.speed));
+                }
+            }
+            self.last_time = Some(time);
+        }
+
+        Some(sentence)
+    }
+}
+
+// `Time` already is a `TimeOfDay` without the `chrono` feature
+#[allow(clippy::useless_conversion)]
+fn sentence_time(sentence: &str) -> Option<TimeOfDay> {
+    let time = match parse_str(sentence.trim_end()).ok()? {
+        ParseResult::GGA(gga) => gga.fix_time,
+        ParseResult::RMC(rmc) => rmc.fix_time,
+        ParseResult::ZDA(zda) => zda.utc_time,
+        _ => None,
+    };
+    time.map(TimeOfDay::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    struct MockClock {
+        sleeps: Vec<Duration>,
+    }
+
+    impl Clock for &mut MockClock {
+        fn sleep(&mut self, duration: Duration) {
+            self.sleeps.push(duration);
+        }
+    }
+
+    const LOG: [&str; 6] = [
+        "$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76",
+        "$GPRMC,092750.000,A,5321.6802,N,00630.3372,W,0.02,31.66,280511,,,A*43",
+        "$GPGSA,A,3,10,07,05,02,29,04,08,13,,,,,1.72,1.03,1.38*0A",
+        "$GPGGA,092751.500,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*72",
+        "$GPZDA,092753.00,28,05,2011,00,00*61",
+        "invalid sentence",
+    ];
+
+    #[test]
+    fn test_replay_delays() {
+        let mut clock = MockClock::default();
+        let replayed: Vec<_> = Replayer::new(LOG).with_clock(&mut clock).collect();
+
+        assert_eq!(replayed, LOG);
+        assert_eq!(
+            clock.sleeps,
+            [Duration::from_millis(1500), Duration::from_millis(1500)]
+        );
+    }
+
+    #[test]
+    fn test_replay_speed_and_midnight() {
+        let mut clock = MockClock::default();
+        let log = [
+            "$GPZDA,235959.00,28,05,2011,00,00*6A",
+            "$GPZDA,000001.00,29,05,2011,00,00*6B",
+        ];
+        let replayed = Replayer::new(log)
+            .with_speed(4.0)
+            .with_clock(&mut clock)
+            .count();
+
+        assert_eq!(replayed, 2);
+        assert_eq!(clock.sleeps, [Duration::from_millis(500)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "replay speed must be finite and positive")]
+    fn test_replay_zero_speed() {
+        let _ = Replayer::new(LOG).with_speed(0.0);
+    }
+
+    #[test]
+    fn test_replay_invalid_speeds() {
+        for speed in [-1.0, f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let result = std::panic::catch_unwind(|| Replayer::new(LOG).with_speed(speed));
+            assert!(result.is_err(), "speed {} was accepted", speed);
+        }
+    }
+}
@@ -2,16 +2,17 @@ use crate::parse::TEXT_PARAMETER_MAX_LEN;
 
 use arrayvec::ArrayString;
 use nom::{
-    bytes::complete::is_not,
-    character::complete::{char, one_of},
-    combinator::opt,
-    number::complete::float,
+    bytes::complete::is_not, character::complete::char, combinator::opt, number::complete::float,
 };
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::{parse::NmeaSentence, sentences::utils::array_string, Error, SentenceType};
+use crate::{
+    parse::NmeaSentence,
+    sentences::utils::{array_string, parse_valid_status},
+    Error, SentenceType,
+};
 
 /// AAM - Waypoint Arrival Alarm
 ///
@@ -33,20 +34,39 @@ use crate::{parse::NmeaSentence, sentences::utils::array_string, Error, Sentence
 /// Example: $GPAAM,A,A,0.10,N,WPTNME*43
 /// WPTNME is the waypoint name.
 /// ```
+///
+/// The waypoint ID is bounded by `MAX_LEN` characters, [`TEXT_PARAMETER_MAX_LEN`] by default.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[derive(Debug, PartialEq)]
-pub struct AamData {
-    pub arrival_circle_entered: Option<bool>,
-    pub perpendicular_passed: Option<bool>,
-    pub arrival_circle_radius: Option<f32>,
-    pub radius_units: Option<char>,
+pub struct AamData<const MAX_LEN: usize = TEXT_PARAMETER_MAX_LEN> {
+    pub arrival_circle_entered: bool,
+    pub perpendicular_passed: bool,
+    pub circle_radius: Option<f32>,
+    /// Units of the radius, `N` for nautical miles, the only unit of AAM,
+    /// also when the field is empty
+    pub radius_units: char,
     #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
-    pub waypoint_id: Option<ArrayString<TEXT_PARAMETER_MAX_LEN>>,
+    pub waypoint_id: Option<ArrayString<MAX_LEN>>,
 }
 
 /// Parse AAM message
+///
+/// The waypoint ID is bounded by [`TEXT_PARAMETER_MAX_LEN`], use
+/// [`parse_aam_with_max_len`] for a different bound.
 pub fn parse_aam(sentence: NmeaSentence) -> Result<AamData, Error> {
+    parse_aam_with_max_len(sentence)
+}
+
+/// Parse AAM message with the waypoint ID bounded by `MAX_LEN` characters.
+///
+/// # Errors
+///
+/// [`Error::ParameterLength`] with `max_length` set to `MAX_LEN` if the waypoint ID is longer.
+pub fn parse_aam_with_max_len<const MAX_LEN: usize>(
+    sentence: NmeaSentence,
+) -> Result<AamData<MAX_LEN>, Error> {
     if sentence.message_id != SentenceType::AAM {
         Err(Error::WrongSentenceHeader {
             expected: SentenceType::AAM,
@@ -57,24 +77,14 @@ pub fn parse_aam(sentence: NmeaSentence) -> Result<AamData, Error> {
     }
 }
 
-fn do_parse_aam(i: &str) -> Result<AamData, Error> {
-    let (i, arrival_circle_entered) = one_of("AV")(i)?;
-    let arrival_circle_entered = match arrival_circle_entered {
-        'A' => Some(true),
-        'V' => Some(false),
-        _ => unreachable!(),
-    };
+fn do_parse_aam<const MAX_LEN: usize>(i: &str) -> Result<AamData<MAX_LEN>, Error<'_>> {
+    let (i, arrival_circle_entered) = parse_valid_status(i)?;
     let (i, _) = char(',')(i)?;
 
-    let (i, perpendicular_passed) = one_of("AV")(i)?;
-    let perpendicular_passed = match perpendicular_passed {
-        'A' => Some(true),
-        'V' => Some(false),
-        _ => unreachable!(),
-    };
+    let (i, perpendicular_passed) = parse_valid_status(i)?;
     let (i, _) = char(',')(i)?;
 
-    let (i, arrival_circle_radius) = opt(float)(i)?;
+    let (i, circle_radius) = opt(float)(i)?;
     let (i, _) = char(',')(i)?;
 
     let (i, radius_units) = opt(char('N'))(i)?;
@@ -85,11 +95,9 @@ fn do_parse_aam(i: &str) -> Result<AamData, Error> {
     Ok(AamData {
         arrival_circle_entered,
         perpendicular_passed,
-        arrival_circle_radius,
-        radius_units,
-        waypoint_id: waypoint_id
-            .map(array_string::<TEXT_PARAMETER_MAX_LEN>)
-            .transpose()?,
+        circle_radius,
+        radius_units: radius_units.unwrap_or('N'),
+        waypoint_id: waypoint_id.map(array_string::<MAX_LEN>).transpose()?,
     })
 }
 
@@ -106,14 +114,15 @@ mod tests {
             talker_id: "GP",
             message_id: SentenceType::AAM,
             data: "A,V,0.10,N,WPTNME",
-            checksum: 0x0,
+            checksum: Some(0x0),
+            tag_block: None,
         })
         .unwrap();
 
-        assert!(data.arrival_circle_entered.unwrap());
-        assert!(!data.perpendicular_passed.unwrap());
-        assert_relative_eq!(data.arrival_circle_radius.unwrap(), 0.10);
-        assert_eq!(data.radius_units.unwrap(), 'N');
+        assert!(data.arrival_circle_entered);
+        assert!(!data.perpendicular_passed);
+        assert_relative_eq!(data.circle_radius.unwrap(), 0.10);
+        assert_eq!(data.radius_units, 'N');
         assert_eq!(&data.waypoint_id.unwrap(), "WPTNME");
     }
 
@@ -124,7 +133,8 @@ mod tests {
             talker_id: "GP",
             message_id: SentenceType::AAM,
             data: "G,V,0.10,N,WPTNME",
-            checksum: 0x0,
+            checksum: Some(0x0),
+            tag_block: None,
         })
         .unwrap();
     }
@@ -136,7 +146,8 @@ mod tests {
             talker_id: "GP",
             message_id: SentenceType::AAM,
             data: "V,X,0.10,N,WPTNME",
-            checksum: 0x0,
+            checksum: Some(0x0),
+            tag_block: None,
         })
         .unwrap();
     }
@@ -148,7 +159,8 @@ mod tests {
             talker_id: "GP",
             message_id: SentenceType::AAM,
             data: "V,A,0.10,P,WPTNME",
-            checksum: 0x0,
+            checksum: Some(0x0),
+            tag_block: None,
         })
         .unwrap();
     }
@@ -156,24 +168,37 @@ mod tests {
     #[test]
     fn parse_aam_full_sentence() {
         let sentence = parse_nmea_sentence("$GPAAM,A,A,0.10,N,WPTNME*32").unwrap();
-        assert_eq!(sentence.checksum, 0x32);
+        assert_eq!(sentence.checksum, Some(0x32));
         assert_eq!(sentence.calc_checksum(), 0x32);
 
         let data = parse_aam(sentence).unwrap();
-        assert!(data.arrival_circle_entered.unwrap());
-        assert!(data.perpendicular_passed.unwrap());
-        assert_relative_eq!(data.arrival_circle_radius.unwrap(), 0.10);
-        assert_eq!(data.radius_units.unwrap(), 'N');
+        assert!(data.arrival_circle_entered);
+        assert!(data.perpendicular_passed);
+        assert_relative_eq!(data.circle_radius.unwrap(), 0.10);
+        assert_eq!(data.radius_units, 'N');
         assert_eq!(&data.waypoint_id.unwrap(), "WPTNME");
     }
 
+    #[test]
+    fn parse_aam_without_radius() {
+        let sentence = parse_nmea_sentence("$GPAAM,V,V,,,WPTNME*63").unwrap();
+        assert_eq!(sentence.checksum, Some(sentence.calc_checksum()));
+
+        let data = parse_aam(sentence).unwrap();
+        assert!(!data.arrival_circle_entered);
+        assert!(!data.perpendicular_passed);
+        assert_eq!(data.circle_radius, None);
+        assert_eq!(data.radius_units, 'N');
+    }
+
     #[test]
     fn parse_aam_with_wrong_message_id() {
         let error = parse_aam(NmeaSentence {
             talker_id: "GP",
             message_id: SentenceType::ABK,
             data: "A,V,0.10,N,WPTNME",
-            checksum: 0x43,
+            checksum: Some(0x43),
+            tag_block: None,
         })
         .unwrap_err();
 
@@ -0,0 +1,97 @@
+use nom::{
+    combinator::{eof, opt},
+    IResult,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::utils::number;
+use crate::{Error, NmeaSentence, SentenceType};
+
+/// ACK - Acknowledge Alarm
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_ack_alarm_acknowledgement>
+///
+/// ```text
+///        1   2
+///        |   |
+/// $--ACK,xxx*hh<CR><LF>
+/// ```
+/// 1. Unique alarm number (identifier) at the alarm source
+/// 2. Checksum
+///
+/// Example:
+/// ```text
+/// $IIACK,001*54
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AckData {
+    /// Unique alarm number at the alarm source
+    pub alarm_id: Option<u32>,
+}
+
+/// # Parse ACK message
+///
+/// See: <https://gpsd.gitlab.io/gpsd/NMEA.html#_ack_alarm_acknowledgement>
+pub fn parse_ack(sentence: NmeaSentence) -> Result<AckData, Error> {
+    if sentence.message_id != SentenceType::ACK {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::ACK,
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_ack(sentence.data)?.1)
+    }
+}
+
+fn do_parse_ack(i: &str) -> IResult<&str, AckData> {
+    let (i, alarm_id) = opt(number::<u32>)(i)?;
+    let (i, _) = eof(i)?;
+
+    Ok((i, AckData { alarm_id }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_ack() {
+        let s = parse_nmea_sentence("$IIACK,001*54").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(parse_ack(s), Ok(AckData { alarm_id: Some(1) }));
+    }
+
+    #[test]
+    fn test_parse_ack_empty() {
+        let s = parse_nmea_sentence("$IIACK,*65").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(parse_ack(s), Ok(AckData { alarm_id: None }));
+
+        let s = parse_nmea_sentence("$IIACK,00A*24").unwrap();
+        assert!(parse_ack(s).is_err());
+    }
+
+    #[test]
+    fn test_wrong_sentence() {
+        let invalid_alr_sentence = NmeaSentence {
+            message_id: SentenceType::ALR,
+            data: "001",
+            talker_id: "II",
+            checksum: None,
+            tag_block: None,
+        };
+        assert_eq!(
+            Err(Error::WrongSentenceHeader {
+                expected: SentenceType::ACK,
+                found: SentenceType::ALR
+            }),
+            parse_ack(invalid_alr_sentence)
+        );
+    }
+}
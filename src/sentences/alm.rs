@@ -1,19 +1,11 @@
-use crate::sentences::utils::parse_number_in_range;
-use nom::{
-    character::{
-        complete::{char, hex_digit0},
-        streaming::hex_digit1,
-    },
-    combinator::{map_res, opt},
-    IResult,
-};
+use nom::{character::complete::char, combinator::opt, IResult};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use crate::{Error, NmeaSentence, SentenceType};
 
-use super::utils::number;
+use super::utils::{hex_number, number, parse_number_in_range};
 
 /// ALM - GPS Almanac Data
 ///
@@ -32,8 +24,10 @@ use super::utils::number;
 ///  4. GPS Week Number (range 0 to 2^13 - 1), where:
 ///     - 0 is the week of the GPS Week Number epoch on January 6th 1980;
 ///     - 8191 is the week that precedes the next rollover on January 6th 2137;
+///
 ///     Note: the legacy representation started at the same epoch, but
-///           the number is 10-bit wide only, with a rollover every 19.7 years.
+///     the number is 10-bit wide only, with a rollover every 19.7 years.
+///  5. SV health, bits 17-24 of each almanac page
 ///  6. Eccentricity
 ///  7. Almanac Reference Time
 ///  8. Inclination Angle
@@ -48,6 +42,7 @@ use super::utils::number;
 ///  
 ///  Fields 5 through 15 are dumped as raw hex.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct AlmData {
@@ -78,6 +73,9 @@ impl AlmData {
     }
 }
 
+/// # Parse ALM message
+///
+/// See: <https://gpsd.gitlab.io/gpsd/NMEA.html#_alm_gps_almanac_data>
 pub fn parse_alm(sentence: NmeaSentence) -> Result<AlmData, Error> {
     if sentence.message_id != SentenceType::ALM {
         Err(Error::WrongSentenceHeader {
@@ -107,41 +105,40 @@ fn do_parse_alm(i: &str) -> IResult<&str, AlmData> {
     let (i, _) = char(',')(i)?;
 
     //  5. SV health, bits 17-24 of each almanac page
-    let (i, sv_health) = opt(map_res(hex_digit1, |s| u8::from_str_radix(s, 16)))(i)?;
+    let (i, sv_health) = opt(hex_number::<u8>)(i)?;
     let (i, _) = char(',')(i)?;
 
     //  6. Eccentricity
-    let (i, eccentricity) = opt(map_res(hex_digit1, |s| u16::from_str_radix(s, 16)))(i)?;
+    let (i, eccentricity) = opt(hex_number::<u16>)(i)?;
     let (i, _) = char(',')(i)?;
 
     //  7. Almanac Reference Time
-    let (i, almanac_reference_time) = opt(map_res(hex_digit1, |s| u8::from_str_radix(s, 16)))(i)?;
+    let (i, almanac_reference_time) = opt(hex_number::<u8>)(i)?;
     let (i, _) = char(',')(i)?;
 
     //  8. Inclination Angle
-    let (i, inclination_angle) = opt(map_res(hex_digit1, |s| u16::from_str_radix(s, 16)))(i)?;
+    let (i, inclination_angle) = opt(hex_number::<u16>)(i)?;
     let (i, _) = char(',')(i)?;
     //  9. Rate of Right Ascension
-    let (i, rate_of_right_ascension) = opt(map_res(hex_digit1, |s| u16::from_str_radix(s, 16)))(i)?;
+    let (i, rate_of_right_ascension) = opt(hex_number::<u16>)(i)?;
     let (i, _) = char(',')(i)?;
     // 10. Root of semi-major axis
-    let (i, root_of_semi_major_axis) = opt(map_res(hex_digit1, |s| u32::from_str_radix(s, 16)))(i)?;
+    let (i, root_of_semi_major_axis) = opt(hex_number::<u32>)(i)?;
     let (i, _) = char(',')(i)?;
     // 11. Argument of perigee
-    let (i, argument_of_perigee) = opt(map_res(hex_digit1, |s| u32::from_str_radix(s, 16)))(i)?;
+    let (i, argument_of_perigee) = opt(hex_number::<u32>)(i)?;
     let (i, _) = char(',')(i)?;
     // 12. Longitude of ascension node
-    let (i, longitude_of_ascension_node) =
-        opt(map_res(hex_digit1, |s| u32::from_str_radix(s, 16)))(i)?;
+    let (i, longitude_of_ascension_node) = opt(hex_number::<u32>)(i)?;
     let (i, _) = char(',')(i)?;
     // 13. Mean anomaly
-    let (i, mean_anomaly) = opt(map_res(hex_digit1, |s| u32::from_str_radix(s, 16)))(i)?;
+    let (i, mean_anomaly) = opt(hex_number::<u32>)(i)?;
     let (i, _) = char(',')(i)?;
     // 14. F0 Clock Parameter
-    let (i, f0_clock_parameter) = opt(map_res(hex_digit0, |s| u16::from_str_radix(s, 16)))(i)?;
+    let (i, f0_clock_parameter) = opt(hex_number::<u16>)(i)?;
     let (i, _) = char(',')(i)?;
     // 15. F1 Clock Parameter
-    let (i, f1_clock_parameter) = opt(map_res(hex_digit0, |s| u16::from_str_radix(s, 16)))(i)?;
+    let (i, f1_clock_parameter) = opt(hex_number::<u16>)(i)?;
 
     Ok((
         i,
@@ -167,7 +164,7 @@ fn do_parse_alm(i: &str) -> IResult<&str, AlmData> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{parse_nmea_sentence, sentences::parse_alm};
+    use crate::{parse_nmea_sentence, sentences::parse_alm, Error};
 
     #[test]
     fn test() {
@@ -191,8 +188,8 @@ mod tests {
             "$GPALM,31,1,02,1617,00,50F6,0F,FD98,FD39,A10CF3,81389B,423632,BD913C,148,001*3C";
 
         let sentence = parse_nmea_sentence(sentence_string).unwrap();
-        assert_eq!(sentence.checksum, sentence.calc_checksum());
-        assert_eq!(sentence.checksum, 0x3C);
+        assert_eq!(sentence.checksum, Some(sentence.calc_checksum()));
+        assert_eq!(sentence.checksum, Some(0x3C));
 
         let data = parse_alm(sentence).unwrap();
         assert_eq!(
@@ -223,4 +220,33 @@ mod tests {
         assert_eq!(f0_clock_parameter, data.f0_clock_parameter.unwrap());
         assert_eq!(f1_clock_parameter, data.f1_clock_parameter.unwrap());
     }
+
+    #[test]
+    fn test_parse_alm_invalid_hex() {
+        // `G` in the eccentricity
+        let sentence = parse_nmea_sentence(
+            "$GPALM,31,1,02,1617,00,50G6,0F,FD98,FD39,A10CF3,81389B,423632,BD913C,148,001*3D",
+        )
+        .unwrap();
+        assert!(matches!(
+            parse_alm(sentence),
+            Err(Error::ParsingError(nom::Err::Error(_)))
+        ));
+
+        // the SV health doesn't fit into 8 bits
+        let sentence = parse_nmea_sentence(
+            "$GPALM,31,1,02,1617,100,50F6,0F,FD98,FD39,A10CF3,81389B,423632,BD913C,148,001*0D",
+        )
+        .unwrap();
+        assert!(parse_alm(sentence).is_err());
+
+        // empty F0/F1 clock parameters at the end of the sentence
+        let sentence = parse_nmea_sentence(
+            "$GPALM,31,1,02,1617,00,50F6,0F,FD98,FD39,A10CF3,81389B,423632,BD913C,,*30",
+        )
+        .unwrap();
+        let data = parse_alm(sentence).unwrap();
+        assert_eq!(data.f0_clock_parameter, None);
+        assert_eq!(data.f1_clock_parameter, None);
+    }
 }
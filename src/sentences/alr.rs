@@ -0,0 +1,181 @@
+use arrayvec::ArrayString;
+use nom::{
+    bytes::complete::{is_not, take_while_m_n},
+    character::complete::char,
+    combinator::{map_res, opt},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    parse::{NmeaSentence, TEXT_PARAMETER_MAX_LEN},
+    sentences::utils::{array_string, parse_hms, parse_valid_status, Time},
+    Error, SentenceType,
+};
+
+/// ALR - Set Alarm State
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_alr_set_alarm_state>
+///
+/// ```text
+///        1         2   3 4 5    6
+///        |         |   | | |    |
+/// $--ALR,hhmmss.ss,xxx,A,A,c--c*hh<CR><LF>
+/// ```
+///
+/// 1. Time of the alarm condition change, UTC
+/// 2. Unique alarm number at the alarm source
+/// 3. Alarm condition, A = threshold exceeded, V = not exceeded
+/// 4. Alarm's acknowledge state, A = acknowledged, V = unacknowledged
+/// 5. Alarm's description text
+/// 6. Checksum
+///
+/// Example:
+/// ```text
+/// $IIALR,020000,001,A,V,Bilge pump alarm1*48
+/// ```
+///
+/// The description is bounded by `MAX_LEN` characters, [`TEXT_PARAMETER_MAX_LEN`] by default.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AlrData<const MAX_LEN: usize = TEXT_PARAMETER_MAX_LEN> {
+    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
+    pub time: Option<Time>,
+    /// Unique alarm number at the alarm source
+    pub alarm_id: u16,
+    /// `true` if the alarm threshold is exceeded
+    pub condition: bool,
+    /// `true` if the alarm is acknowledged
+    pub acknowledged: bool,
+    #[cfg_attr(feature = "defmt-03", defmt(Display2Format))]
+    pub description: ArrayString<MAX_LEN>,
+}
+
+fn do_parse_alr<const MAX_LEN: usize>(i: &str) -> Result<AlrData<MAX_LEN>, Error<'_>> {
+    // 1. Time of the alarm condition change
+    let (i, time) = opt(parse_hms)(i)?;
+    let (i, _) = char(',')(i)?;
+
+    // 2. Unique alarm number
+    let (i, alarm_id) = map_res(
+        take_while_m_n(1, 3, |c: char| c.is_ascii_digit()),
+        str::parse,
+    )(i)?;
+    let (i, _) = char(',')(i)?;
+
+    // 3. Alarm condition
+    let (i, condition) = parse_valid_status(i)?;
+    let (i, _) = char(',')(i)?;
+
+    // 4. Alarm's acknowledge state
+    let (i, acknowledged) = parse_valid_status(i)?;
+    let (i, _) = char(',')(i)?;
+
+    // 5. Alarm's description text
+    let (_i, description) = opt(is_not("*"))(i)?;
+
+    Ok(AlrData {
+        time,
+        alarm_id,
+        condition,
+        acknowledged,
+        description: array_string::<MAX_LEN>(description.unwrap_or_default())?,
+    })
+}
+
+/// # Parse ALR message
+///
+/// See: <https://gpsd.gitlab.io/gpsd/NMEA.html#_alr_set_alarm_state>
+///
+/// The description is bounded by [`TEXT_PARAMETER_MAX_LEN`], use
+/// [`parse_alr_with_max_len`] for a different bound.
+pub fn parse_alr(sentence: NmeaSentence) -> Result<AlrData, Error> {
+    parse_alr_with_max_len(sentence)
+}
+
+/// Parse ALR message with the description bounded by `MAX_LEN` characters.
+///
+/// # Errors
+///
+/// [`Error::ParameterLength`] with `max_length` set to `MAX_LEN` if the description is longer.
+pub fn parse_alr_with_max_len<const MAX_LEN: usize>(
+    sentence: NmeaSentence,
+) -> Result<AlrData<MAX_LEN>, Error> {
+    if sentence.message_id != SentenceType::ALR {
+        return Err(Error::WrongSentenceHeader {
+            expected: SentenceType::ALR,
+            found: sentence.message_id,
+        });
+    }
+
+    do_parse_alr(sentence.data)
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod tests {
+    use chrono::NaiveTime;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_alr() {
+        let s = parse_nmea_sentence("$IIALR,020000,001,A,V,Bilge pump alarm1*48").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+
+        assert_eq!(
+            parse_alr(s),
+            Ok(AlrData {
+                time: NaiveTime::from_hms_opt(2, 0, 0),
+                alarm_id: 1,
+                condition: true,
+                acknowledged: false,
+                description: ArrayString::from("Bilge pump alarm1").unwrap(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_alr_empty_fields() {
+        let s = parse_nmea_sentence("$IIALR,,002,V,A,*56").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_alr(s).unwrap();
+
+        assert_eq!(data.time, None);
+        assert_eq!(data.alarm_id, 2);
+        assert!(!data.condition);
+        assert!(data.acknowledged);
+        assert!(data.description.is_empty());
+    }
+
+    #[test]
+    fn test_parse_alr_invalid() {
+        // invalid alarm condition
+        let s = parse_nmea_sentence("$IIALR,020000,001,X,V,Bilge pump alarm1*51").unwrap();
+        assert!(parse_alr(s).is_err());
+
+        // missing alarm number
+        let s = parse_nmea_sentence("$IIALR,020000,,A,V,Bilge pump alarm1*79").unwrap();
+        assert!(parse_alr(s).is_err());
+
+        // description longer than `TEXT_PARAMETER_MAX_LEN`
+        let data = "020000,001,A,V,".to_owned() + &"X".repeat(TEXT_PARAMETER_MAX_LEN + 1);
+        let s = NmeaSentence {
+            talker_id: "II",
+            message_id: SentenceType::ALR,
+            data: &data,
+            checksum: None,
+            tag_block: None,
+        };
+        assert_eq!(
+            parse_alr(s),
+            Err(Error::ParameterLength {
+                max_length: TEXT_PARAMETER_MAX_LEN,
+                parameter_length: TEXT_PARAMETER_MAX_LEN + 1
+            })
+        );
+    }
+}
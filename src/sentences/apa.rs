@@ -1,17 +1,28 @@
+use core::fmt::{self, Write};
+
 use crate::parse::TEXT_PARAMETER_MAX_LEN;
 
 use arrayvec::ArrayString;
 use nom::{
     bytes::complete::is_not,
-    character::complete::{char, one_of},
-    combinator::opt,
+    character::complete::{anychar, char},
+    combinator::{map_res, opt},
     number::complete::float,
 };
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::{parse::NmeaSentence, sentences::utils::array_string, Error, SentenceType};
+use crate::{
+    compact::Compact,
+    encode::{write_opt, write_status, ToNmeaSentence},
+    parse::NmeaSentence,
+    sentences::{
+        utils::{array_string, parse_valid_status},
+        DistanceUnit, Reference, SteerDirection,
+    },
+    Error, SentenceType,
+};
 
 ///  APA - Autopilot Sentence "A"
 ///  This sentence is sent by some GPS receivers to allow them to be used to control an autopilot unit
@@ -39,50 +50,131 @@ use crate::{parse::NmeaSentence, sentences::utils::array_string, Error, Sentence
 ///
 /// Example: `$GPAPA,A,A,0.10,R,N,V,V,011,M,DEST,011,M*82`
 /// Where the last "M" is the waypoint name
-
+///
+/// [`Display`](fmt::Display) and, with `defmt-03`, `defmt::Format` write the
+/// fields in the order of the sentence and the absent ones
+/// [compactly](crate::compact) as `-`.
+///
+/// The waypoint ID is bounded by `MAX_LEN` characters, [`TEXT_PARAMETER_MAX_LEN`] by default.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[derive(Debug, PartialEq, Clone)]
-pub struct ApaData {
+pub struct ApaData<const MAX_LEN: usize = TEXT_PARAMETER_MAX_LEN> {
     pub status_warning: Option<bool>,
     pub status_cycle_warning: Option<bool>,
     pub cross_track_error_magnitude: Option<f32>,
     pub steer_direction: Option<SteerDirection>,
-    pub cross_track_units: Option<CrossTrackUnits>,
+    pub cross_track_units: Option<DistanceUnit>,
     pub status_arrived: Option<bool>,
     pub status_passed: Option<bool>,
     pub bearing_origin_destination: Option<f32>,
-    pub magnetic_true: Option<MagneticTrue>,
-    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
-    pub waypoint_id: Option<ArrayString<TEXT_PARAMETER_MAX_LEN>>,
+    pub magnetic_true: Option<Reference>,
+    pub waypoint_id: Option<ArrayString<MAX_LEN>>,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
-pub enum SteerDirection {
-    Left,
-    Right,
+impl<const MAX_LEN: usize> fmt::Display for ApaData<MAX_LEN> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "APA {} {} {} {:?} {:?} {} {} {} {:?} {}",
+            Compact(&self.status_warning),
+            Compact(&self.status_cycle_warning),
+            Compact(&self.cross_track_error_magnitude),
+            Compact(&self.steer_direction),
+            Compact(&self.cross_track_units),
+            Compact(&self.status_arrived),
+            Compact(&self.status_passed),
+            Compact(&self.bearing_origin_destination),
+            Compact(&self.magnetic_true),
+            Compact(&self.waypoint_id),
+        )
+    }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
-pub enum CrossTrackUnits {
-    Nautical,
-    Kilometers,
+#[cfg(feature = "defmt-03")]
+impl<const MAX_LEN: usize> defmt::Format for ApaData<MAX_LEN> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(
+            f,
+            "APA {} {} {} {} {} {} {} {} {} {}",
+            Compact(&self.status_warning),
+            Compact(&self.status_cycle_warning),
+            Compact(&self.cross_track_error_magnitude),
+            Compact(&self.steer_direction),
+            Compact(&self.cross_track_units),
+            Compact(&self.status_arrived),
+            Compact(&self.status_passed),
+            Compact(&self.bearing_origin_destination),
+            Compact(&self.magnetic_true),
+            Compact(&self.waypoint_id.as_ref().map(defmt::Display2Format)),
+        )
+    }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
-pub enum MagneticTrue {
-    Magnetic,
-    True,
+impl<const MAX_LEN: usize> ApaData<MAX_LEN> {
+    /// Returns the cross track error magnitude in meters.
+    pub fn cross_track_error_meters(&self) -> Option<f32> {
+        Some(
+            self.cross_track_units?
+                .to_meters(self.cross_track_error_magnitude?),
+        )
+    }
+}
+
+impl<const MAX_LEN: usize> ToNmeaSentence for ApaData<MAX_LEN> {
+    fn sentence_type(&self) -> SentenceType {
+        SentenceType::APA
+    }
+
+    fn write_data<W: Write>(&self, f: &mut W) -> fmt::Result {
+        write_status(f, self.status_warning)?;
+        f.write_char(',')?;
+        write_status(f, self.status_cycle_warning)?;
+        f.write_char(',')?;
+        if let Some(magnitude) = self.cross_track_error_magnitude {
+            write!(f, "{:.2}", magnitude)?;
+        }
+        f.write_char(',')?;
+        if let Some(direction) = self.steer_direction {
+            f.write_char(direction.into())?;
+        }
+        f.write_char(',')?;
+        if let Some(unit) = self.cross_track_units {
+            f.write_char(unit.into())?;
+        }
+        f.write_char(',')?;
+        write_status(f, self.status_arrived)?;
+        f.write_char(',')?;
+        write_status(f, self.status_passed)?;
+        f.write_char(',')?;
+        if let Some(bearing) = self.bearing_origin_destination {
+            write!(f, "{:03.0}", bearing)?;
+        }
+        f.write_char(',')?;
+        if let Some(reference) = self.magnetic_true {
+            f.write_char(reference.into())?;
+        }
+        f.write_char(',')?;
+        write_opt(f, &self.waypoint_id)
+    }
 }
 
 /// Parse APA message
+///
+/// The waypoint ID is bounded by [`TEXT_PARAMETER_MAX_LEN`], use
+/// [`parse_apa_with_max_len`] for a different bound.
 pub fn parse_apa(sentence: NmeaSentence) -> Result<ApaData, Error> {
+    parse_apa_with_max_len(sentence)
+}
+
+/// Parse APA message with the waypoint ID bounded by `MAX_LEN` characters.
+///
+/// # Errors
+///
+/// [`Error::ParameterLength`] with `max_length` set to `MAX_LEN` if the waypoint ID is longer.
+pub fn parse_apa_with_max_len<const MAX_LEN: usize>(
+    sentence: NmeaSentence,
+) -> Result<ApaData<MAX_LEN>, Error> {
     if sentence.message_id != SentenceType::APA {
         Err(Error::WrongSentenceHeader {
             expected: SentenceType::APA,
@@ -93,84 +185,59 @@ pub fn parse_apa(sentence: NmeaSentence) -> Result<ApaData, Error> {
     }
 }
 
-fn do_parse_apa(i: &str) -> Result<ApaData, Error> {
-    let (i, status_warning) = one_of("AV")(i)?;
-    let status_warning = match status_warning {
-        'A' => Some(true),
-        'V' => Some(false),
-        _ => unreachable!(),
-    };
+/// Number of fields of an APA sentence, the waypoint ID is the last one.
+const APA_FIELDS: usize = 10;
+
+fn do_parse_apa<const MAX_LEN: usize>(i: &str) -> Result<ApaData<MAX_LEN>, Error<'_>> {
+    // A truncated sentence fails upfront instead of somewhere in the middle
+    // of the field parsers.
+    if i.split(',').count() < APA_FIELDS {
+        return Err(Error::TooFewFields {
+            sentence: SentenceType::APA,
+            expected: APA_FIELDS,
+        });
+    }
+
+    let (i, status_warning) = parse_valid_status(i)?;
     let (i, _) = char(',')(i)?;
 
-    let (i, status_cycle_warning) = one_of("AV")(i)?;
-    let status_cycle_warning = match status_cycle_warning {
-        'A' => Some(true),
-        'V' => Some(false),
-        _ => unreachable!(),
-    };
+    let (i, status_cycle_warning) = parse_valid_status(i)?;
     let (i, _) = char(',')(i)?;
 
     let (i, cross_track_error_magnitude) = opt(float)(i)?;
     let (i, _) = char(',')(i)?;
 
-    let (i, steer_direction) = one_of("LR")(i)?;
-    let steer_direction = match steer_direction {
-        'L' => Some(SteerDirection::Left),
-        'R' => Some(SteerDirection::Right),
-        _ => unreachable!(),
-    };
+    let (i, steer_direction) = map_res(anychar, SteerDirection::try_from)(i)?;
     let (i, _) = char(',')(i)?;
 
-    let (i, cross_track_units) = one_of("NK")(i)?;
-    let cross_track_units = match cross_track_units {
-        'N' => Some(CrossTrackUnits::Nautical),
-        'K' => Some(CrossTrackUnits::Kilometers),
-        _ => unreachable!(),
-    };
+    let (i, cross_track_units) = map_res(anychar, DistanceUnit::try_from)(i)?;
     let (i, _) = char(',')(i)?;
 
-    let (i, status_arrived) = one_of("AV")(i)?;
-    let status_arrived = match status_arrived {
-        'A' => Some(true),
-        'V' => Some(false),
-        _ => unreachable!(),
-    };
+    let (i, status_arrived) = parse_valid_status(i)?;
     let (i, _) = char(',')(i)?;
 
-    let (i, status_passed) = one_of("AV")(i)?;
-    let status_passed = match status_passed {
-        'A' => Some(true),
-        'V' => Some(false),
-        _ => unreachable!(),
-    };
+    let (i, status_passed) = parse_valid_status(i)?;
     let (i, _) = char(',')(i)?;
 
     let (i, bearing_origin_destination) = opt(float)(i)?;
     let (i, _) = char(',')(i)?;
 
-    let (i, magnetic_true) = one_of("MT")(i)?;
-    let magnetic_true = match magnetic_true {
-        'M' => Some(MagneticTrue::Magnetic),
-        'T' => Some(MagneticTrue::True),
-        _ => unreachable!(),
-    };
+    let (i, magnetic_true) = map_res(anychar, Reference::try_from)(i)?;
     let (i, _) = char(',')(i)?;
 
     let (_i, waypoint_id) = opt(is_not("*"))(i)?;
 
     Ok(ApaData {
-        status_warning,
-        status_cycle_warning,
+        status_warning: Some(status_warning),
+        status_cycle_warning: Some(status_cycle_warning),
         cross_track_error_magnitude,
-        steer_direction,
-        cross_track_units,
-        status_arrived,
-        status_passed,
+        steer_direction: Some(steer_direction),
+        cross_track_units: Some(cross_track_units),
+        status_arrived: Some(status_arrived),
+        status_passed: Some(status_passed),
         bearing_origin_destination,
-        magnetic_true,
-        waypoint_id: waypoint_id
-            .map(array_string::<TEXT_PARAMETER_MAX_LEN>)
-            .transpose()?,
+        magnetic_true: Some(magnetic_true),
+        waypoint_id: waypoint_id.map(array_string::<MAX_LEN>).transpose()?,
     })
 }
 
@@ -187,7 +254,8 @@ mod tests {
             talker_id: "GP",
             message_id: SentenceType::APA,
             data: "A,A,0.10,R,N,V,V,011,M,DEST,011,M*42",
-            checksum: 0x3E,
+            checksum: Some(0x3E),
+            tag_block: None,
         })
         .unwrap();
 
@@ -195,18 +263,25 @@ mod tests {
         assert!(data.status_cycle_warning.unwrap());
         assert_relative_eq!(data.cross_track_error_magnitude.unwrap(), 0.10);
         assert_eq!(data.steer_direction.unwrap(), SteerDirection::Right);
-        assert_eq!(data.cross_track_units.unwrap(), CrossTrackUnits::Nautical);
+        assert_eq!(data.cross_track_units.unwrap(), DistanceUnit::NauticalMiles);
         assert!(!data.status_arrived.unwrap());
         assert!(!data.status_passed.unwrap());
         assert_relative_eq!(data.bearing_origin_destination.unwrap(), 11.0);
-        assert_eq!(data.magnetic_true.unwrap(), MagneticTrue::Magnetic);
+        assert_eq!(data.magnetic_true.unwrap(), Reference::Magnetic);
         assert_eq!(&data.waypoint_id.unwrap(), "DEST,011,M");
     }
 
+    #[test]
+    fn apa_cross_track_error_meters() {
+        let sentence = parse_nmea_sentence("$GPAPA,A,A,0.10,R,N,V,V,011,M,DEST,011,M*42").unwrap();
+        let data = parse_apa(sentence).unwrap();
+        assert_relative_eq!(data.cross_track_error_meters().unwrap(), 185.2);
+    }
+
     #[test]
     fn parse_apa_full_sentence() {
         let sentence = parse_nmea_sentence("$GPAPA,A,A,0.10,R,N,V,V,011,M,DEST,011,M*42").unwrap();
-        assert_eq!(sentence.checksum, 0x42);
+        assert_eq!(sentence.checksum, Some(0x42));
         assert_eq!(sentence.calc_checksum(), 0x42);
 
         let data = parse_apa(sentence).unwrap();
@@ -214,14 +289,89 @@ mod tests {
         assert!(data.status_cycle_warning.unwrap());
         assert_relative_eq!(data.cross_track_error_magnitude.unwrap(), 0.10);
         assert_eq!(data.steer_direction.unwrap(), SteerDirection::Right);
-        assert_eq!(data.cross_track_units.unwrap(), CrossTrackUnits::Nautical);
+        assert_eq!(data.cross_track_units.unwrap(), DistanceUnit::NauticalMiles);
         assert!(!data.status_arrived.unwrap());
         assert!(!data.status_passed.unwrap());
         assert_relative_eq!(data.bearing_origin_destination.unwrap(), 11.0);
-        assert_eq!(data.magnetic_true.unwrap(), MagneticTrue::Magnetic);
+        assert_eq!(data.magnetic_true.unwrap(), Reference::Magnetic);
         assert_eq!(&data.waypoint_id.unwrap(), "DEST,011,M");
     }
 
+    #[test]
+    fn encode_apa_round_trip() {
+        let line = "$GPAPA,A,A,0.10,R,N,V,V,011,M,DEST,011,M*42";
+        let data = parse_apa(parse_nmea_sentence(line).unwrap()).unwrap();
+
+        let mut encoded = heapless::String::<82>::new();
+        data.write_sentence("GP", &mut encoded).unwrap();
+        assert_eq!(encoded, line);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_apa_shape() {
+        let line = "$GPAPA,A,V,0.5,L,N,V,A,011,M,DEST*15";
+        let data = parse_apa(parse_nmea_sentence(line).unwrap()).unwrap();
+
+        let serialized = serde_json::to_string(&data).unwrap();
+        assert_eq!(
+            serialized,
+            concat!(
+                r#"{"statusWarning":true,"statusCycleWarning":false,"#,
+                r#""crossTrackErrorMagnitude":0.5,"steerDirection":"Left","#,
+                r#""crossTrackUnits":"NauticalMiles","statusArrived":false,"#,
+                r#""statusPassed":true,"bearingOriginDestination":11.0,"#,
+                r#""magneticTrue":"Magnetic","waypointId":"DEST"}"#
+            )
+        );
+
+        let deserialized: ApaData = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(data, deserialized);
+    }
+
+    #[test]
+    fn parse_apa_truncated() {
+        let data = "A,A,0.10,R,N,V,V,011,M,DEST";
+        for len in 0..data.len() {
+            let truncated = &data[..len];
+            let result = parse_apa(NmeaSentence {
+                talker_id: "GP",
+                message_id: SentenceType::APA,
+                data: truncated,
+                checksum: None,
+                tag_block: None,
+            });
+            if truncated.split(',').count() < APA_FIELDS {
+                assert_eq!(
+                    result,
+                    Err(Error::TooFewFields {
+                        sentence: SentenceType::APA,
+                        expected: APA_FIELDS,
+                    }),
+                    "{truncated}"
+                );
+            } else {
+                // only the waypoint ID is truncated
+                assert_eq!(
+                    result.unwrap().waypoint_id.as_deref(),
+                    Some(&data[23..len]).filter(|id| !id.is_empty()),
+                    "{truncated}"
+                );
+            }
+        }
+
+        // the trailing waypoint ID may be empty
+        let data = parse_apa(NmeaSentence {
+            talker_id: "GP",
+            message_id: SentenceType::APA,
+            data: "A,A,0.10,R,N,V,V,011,M,",
+            checksum: None,
+            tag_block: None,
+        })
+        .unwrap();
+        assert_eq!(data.waypoint_id, None);
+    }
+
     #[test]
     #[should_panic]
     fn parse_apa_with_invalid_status_warning_value() {
@@ -229,7 +379,8 @@ mod tests {
             talker_id: "GP",
             message_id: SentenceType::APA,
             data: "G,A,0.10,R,N,V,V,011,M,DEST,011,M*4",
-            checksum: 0x0,
+            checksum: Some(0x0),
+            tag_block: None,
         })
         .unwrap();
     }
@@ -241,7 +392,8 @@ mod tests {
             talker_id: "GP",
             message_id: SentenceType::APA,
             data: "A,A,0.10,R,N,V,V,011,X,DEST,011,M*4",
-            checksum: 0x0,
+            checksum: Some(0x0),
+            tag_block: None,
         })
         .unwrap();
     }
@@ -253,7 +405,8 @@ mod tests {
             talker_id: "GP",
             message_id: SentenceType::APA,
             data: "A,A,0.10,R,C,V,V,011,M,DEST,011,M*4",
-            checksum: 0x0,
+            checksum: Some(0x0),
+            tag_block: None,
         })
         .unwrap();
     }
@@ -264,7 +417,8 @@ mod tests {
             talker_id: "GP",
             message_id: SentenceType::ABK,
             data: "A,A,0.10,R,N,V,V,011,M,DEST,011,M*42",
-            checksum: 0x43,
+            checksum: Some(0x43),
+            tag_block: None,
         })
         .unwrap_err();
 
@@ -21,16 +21,29 @@ use serde::{Deserialize, Serialize};
 ///        |   | |   | |    |    |
 /// $--BOD,x.x,T,x.x,M,c--c,c--c*hh<CR><LF>
 /// ```
+///
+/// 1. Bearing Degrees, True
+/// 2. T = True
+/// 3. Bearing Degrees, Magnetic
+/// 4. M = Magnetic
+/// 5. Destination Waypoint ID
+/// 6. Origin Waypoint ID
+/// 7. Checksum
+///
+/// The waypoint IDs are bounded by `MAX_LEN` characters, [`TEXT_PARAMETER_MAX_LEN`] by default.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct BodData {
+pub struct BodData<const MAX_LEN: usize = TEXT_PARAMETER_MAX_LEN> {
     pub bearing_true: Option<f32>,
     pub bearing_magnetic: Option<f32>,
+    /// Destination waypoint ID
     #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
-    pub to_waypoint: Option<ArrayString<TEXT_PARAMETER_MAX_LEN>>,
+    pub destination_waypoint_id: Option<ArrayString<MAX_LEN>>,
+    /// Origin waypoint ID, only present when a route is active
     #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
-    pub from_waypoint: Option<ArrayString<TEXT_PARAMETER_MAX_LEN>>,
+    pub origin_waypoint_id: Option<ArrayString<MAX_LEN>>,
 }
 
 /// BOD - Bearing - Waypoint to Waypoint
@@ -40,7 +53,7 @@ pub struct BodData {
 ///        |   | |   | |    |    |
 /// $--BOD,x.x,T,x.x,M,c--c,c--c*hh<CR><LF>
 /// ```
-fn do_parse_bod(i: &str) -> Result<BodData, Error> {
+fn do_parse_bod<const MAX_LEN: usize>(i: &str) -> Result<BodData<MAX_LEN>, Error<'_>> {
     // 1. Bearing Degrees, True
     let (i, bearing_true) = opt(map_parser(take_until(","), float))(i)?;
     let (i, _) = char(',')(i)?;
@@ -58,21 +71,21 @@ fn do_parse_bod(i: &str) -> Result<BodData, Error> {
     let (i, _) = char(',')(i)?;
 
     // 5. Destination Waypoint
-    let (i, to_waypoint) = opt(is_not(",*"))(i)?;
+    let (i, destination_waypoint_id) = opt(is_not(",*"))(i)?;
 
     // 6. origin Waypoint
-    let from_waypoint = opt(preceded(char(','), is_not("*")))(i)?.1;
+    let origin_waypoint_id = opt(preceded(char(','), is_not("*")))(i)?.1;
 
     // 7. Checksum
 
     Ok(BodData {
         bearing_true,
         bearing_magnetic,
-        to_waypoint: to_waypoint
-            .map(array_string::<TEXT_PARAMETER_MAX_LEN>)
+        destination_waypoint_id: destination_waypoint_id
+            .map(array_string::<MAX_LEN>)
             .transpose()?,
-        from_waypoint: from_waypoint
-            .map(array_string::<TEXT_PARAMETER_MAX_LEN>)
+        origin_waypoint_id: origin_waypoint_id
+            .map(array_string::<MAX_LEN>)
             .transpose()?,
     })
 }
@@ -80,7 +93,21 @@ fn do_parse_bod(i: &str) -> Result<BodData, Error> {
 /// # Parse BOD message
 ///
 /// See: <https://gpsd.gitlab.io/gpsd/NMEA.html#_bod_bearing_waypoint_to_waypoint>
+///
+/// The waypoint IDs are bounded by [`TEXT_PARAMETER_MAX_LEN`], use
+/// [`parse_bod_with_max_len`] for a different bound.
 pub fn parse_bod(sentence: NmeaSentence) -> Result<BodData, Error> {
+    parse_bod_with_max_len(sentence)
+}
+
+/// Parse BOD message with the waypoint IDs bounded by `MAX_LEN` characters.
+///
+/// # Errors
+///
+/// [`Error::ParameterLength`] with `max_length` set to `MAX_LEN` if one of them is longer.
+pub fn parse_bod_with_max_len<const MAX_LEN: usize>(
+    sentence: NmeaSentence,
+) -> Result<BodData<MAX_LEN>, Error> {
     if sentence.message_id != SentenceType::BOD {
         Err(Error::WrongSentenceHeader {
             expected: SentenceType::BOD,
@@ -101,27 +128,27 @@ mod tests {
     #[test]
     fn parse_bod_with_route_active_example_full() {
         let sentence = parse_nmea_sentence("$GPBOD,097.0,T,103.2,M,POINTB,POINTA*4A").unwrap();
-        assert_eq!(sentence.checksum, sentence.calc_checksum());
-        assert_eq!(sentence.checksum, 0x4A);
+        assert_eq!(sentence.checksum, Some(sentence.calc_checksum()));
+        assert_eq!(sentence.checksum, Some(0x4A));
 
         let data = parse_bod(sentence).unwrap();
         assert_relative_eq!(data.bearing_true.unwrap(), 97.0);
         assert_relative_eq!(data.bearing_magnetic.unwrap(), 103.2);
-        assert_eq!(data.to_waypoint.as_deref(), Some("POINTB"));
-        assert_eq!(data.from_waypoint.as_deref(), Some("POINTA"));
+        assert_eq!(data.destination_waypoint_id.as_deref(), Some("POINTB"));
+        assert_eq!(data.origin_waypoint_id.as_deref(), Some("POINTA"));
     }
 
     #[test]
     fn parse_bod_with_route_active_missing_destination_waypoint_example_full() {
         let sentence = parse_nmea_sentence("$GPBOD,097.0,T,103.2,M,,POINTA*44").unwrap();
-        assert_eq!(sentence.checksum, sentence.calc_checksum());
-        assert_eq!(sentence.checksum, 0x44);
+        assert_eq!(sentence.checksum, Some(sentence.calc_checksum()));
+        assert_eq!(sentence.checksum, Some(0x44));
 
         let data = parse_bod(sentence).unwrap();
         assert_relative_eq!(data.bearing_true.unwrap(), 97.0);
         assert_relative_eq!(data.bearing_magnetic.unwrap(), 103.2);
-        assert!(data.to_waypoint.is_none());
-        assert_eq!(data.from_waypoint.as_deref(), Some("POINTA"));
+        assert!(data.destination_waypoint_id.is_none());
+        assert_eq!(data.origin_waypoint_id.as_deref(), Some("POINTA"));
     }
 
     #[test]
@@ -129,39 +156,62 @@ mod tests {
         // this is equivalent to the "no route active" test, except here there is a comma with an empty field
         // before the checksum. This is just to make sure parsing is resilient to missing data.
         let sentence = parse_nmea_sentence("$GPBOD,097.0,T,103.2,M,POINTB,*47").unwrap();
-        assert_eq!(sentence.checksum, sentence.calc_checksum());
-        assert_eq!(sentence.checksum, 0x47);
+        assert_eq!(sentence.checksum, Some(sentence.calc_checksum()));
+        assert_eq!(sentence.checksum, Some(0x47));
 
         let data = parse_bod(sentence).unwrap();
         assert_relative_eq!(data.bearing_true.unwrap(), 97.0);
         assert_relative_eq!(data.bearing_magnetic.unwrap(), 103.2);
-        assert_eq!(data.to_waypoint.as_deref(), Some("POINTB"));
-        assert!(data.from_waypoint.is_none());
+        assert_eq!(data.destination_waypoint_id.as_deref(), Some("POINTB"));
+        assert!(data.origin_waypoint_id.is_none());
     }
 
     #[test]
     fn parse_bod_no_route_active_example_full() {
         let sentence = parse_nmea_sentence("$GPBOD,099.3,T,105.6,M,POINTB*64").unwrap();
-        assert_eq!(sentence.checksum, sentence.calc_checksum());
-        assert_eq!(sentence.checksum, 0x64);
+        assert_eq!(sentence.checksum, Some(sentence.calc_checksum()));
+        assert_eq!(sentence.checksum, Some(0x64));
         let data = parse_bod(sentence).unwrap();
 
         assert_relative_eq!(data.bearing_true.unwrap(), 99.3);
         assert_relative_eq!(data.bearing_magnetic.unwrap(), 105.6);
-        assert_eq!(data.to_waypoint.as_deref(), Some("POINTB"));
-        assert!(data.from_waypoint.is_none());
+        assert_eq!(data.destination_waypoint_id.as_deref(), Some("POINTB"));
+        assert!(data.origin_waypoint_id.is_none());
     }
 
     #[test]
     fn parse_bod_no_route_active_no_bearing_example_full() {
         let sentence = parse_nmea_sentence("$GPBOD,,T,105.6,M,POINTB*49").unwrap();
-        assert_eq!(sentence.checksum, sentence.calc_checksum());
-        assert_eq!(sentence.checksum, 0x49);
+        assert_eq!(sentence.checksum, Some(sentence.calc_checksum()));
+        assert_eq!(sentence.checksum, Some(0x49));
         let data = parse_bod(sentence).unwrap();
 
         assert!(data.bearing_true.is_none());
         assert_relative_eq!(data.bearing_magnetic.unwrap(), 105.6);
-        assert_eq!(data.to_waypoint.as_deref(), Some("POINTB"));
-        assert!(data.from_waypoint.is_none());
+        assert_eq!(data.destination_waypoint_id.as_deref(), Some("POINTB"));
+        assert!(data.origin_waypoint_id.is_none());
+    }
+
+    #[test]
+    fn parse_bod_with_too_long_waypoint_ids() {
+        let long_id = "P".repeat(TEXT_PARAMETER_MAX_LEN + 1);
+
+        let data = format!("097.0,T,103.2,M,{},POINTA", long_id);
+        assert_eq!(
+            do_parse_bod::<TEXT_PARAMETER_MAX_LEN>(&data),
+            Err(Error::ParameterLength {
+                max_length: TEXT_PARAMETER_MAX_LEN,
+                parameter_length: TEXT_PARAMETER_MAX_LEN + 1,
+            })
+        );
+
+        let data = format!("097.0,T,103.2,M,POINTB,{}", long_id);
+        assert_eq!(
+            do_parse_bod::<TEXT_PARAMETER_MAX_LEN>(&data),
+            Err(Error::ParameterLength {
+                max_length: TEXT_PARAMETER_MAX_LEN,
+                parameter_length: TEXT_PARAMETER_MAX_LEN + 1,
+            })
+        );
     }
 }
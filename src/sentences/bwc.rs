@@ -1,5 +1,4 @@
 use arrayvec::ArrayString;
-use chrono::NaiveTime;
 use nom::{
     bytes::complete::is_not, character::complete::char, combinator::opt, number::complete::float,
 };
@@ -9,7 +8,10 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     parse::{NmeaSentence, TEXT_PARAMETER_MAX_LEN},
-    sentences::utils::{parse_hms, parse_lat_lon},
+    sentences::{
+        utils::{array_string, check_lat_lon, parse_hms, parse_lat_lon, Time},
+        Latitude, Longitude,
+    },
     Error, SentenceType,
 };
 
@@ -23,19 +25,34 @@ use crate::{
 ///         |         |       | |        | |   | |   | |   | |    |   |
 /// $--BWC,hhmmss.ss,llll.ll,a,yyyyy.yy,a,x.x,T,x.x,M,x.x,N,c--c,m,*hh<CR><LF>
 /// ```
+///
+/// The waypoint ID is bounded by `MAX_LEN` characters, [`TEXT_PARAMETER_MAX_LEN`] by default.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[derive(Debug, PartialEq)]
-pub struct BwcData {
+pub struct BwcData<const MAX_LEN: usize = TEXT_PARAMETER_MAX_LEN> {
     #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
-    pub fix_time: Option<NaiveTime>,
+    pub fix_time: Option<Time>,
     pub latitude: Option<f64>,
     pub longitude: Option<f64>,
     pub true_bearing: Option<f32>,
     pub magnetic_bearing: Option<f32>,
     pub distance: Option<f32>,
     #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
-    pub waypoint_id: Option<ArrayString<TEXT_PARAMETER_MAX_LEN>>,
+    pub waypoint_id: Option<ArrayString<MAX_LEN>>,
+}
+
+impl<const MAX_LEN: usize> BwcData<MAX_LEN> {
+    /// The latitude of the waypoint as a [`Latitude`].
+    pub fn latitude(&self) -> Option<Latitude> {
+        self.latitude.map(Latitude::new)
+    }
+
+    /// The longitude of the waypoint as a [`Longitude`].
+    pub fn longitude(&self) -> Option<Longitude> {
+        self.longitude.map(Longitude::new)
+    }
 }
 
 /// BWC - Bearing & Distance to Waypoint - Great Circle
@@ -45,7 +62,9 @@ pub struct BwcData {
 ///         |         |       | |        | |   | |   | |   | |    |   |
 /// $--BWC,hhmmss.ss,llll.ll,a,yyyyy.yy,a,x.x,T,x.x,M,x.x,N,c--c,m,*hh<CR><LF>
 /// ```
-fn do_parse_bwc(i: &str) -> Result<BwcData, Error> {
+///
+/// BWR shares the exact same layout and is parsed by this function too.
+pub(crate) fn do_parse_bwc<const MAX_LEN: usize>(i: &str) -> Result<BwcData<MAX_LEN>, Error<'_>> {
     // 1. UTC Time or observation
     let (i, fix_time) = opt(parse_hms)(i)?;
     let (i, _) = char(',')(i)?;
@@ -83,30 +102,40 @@ fn do_parse_bwc(i: &str) -> Result<BwcData, Error> {
 
     // 13. FAA mode indicator (NMEA 2.3 and later, optional)
 
-    let waypoint_id = if let Some(waypoint_id) = waypoint_id {
-        Some(
-            ArrayString::from(waypoint_id)
-                .map_err(|_e| Error::SentenceLength(waypoint_id.len()))?,
-        )
-    } else {
-        None
-    };
+    let waypoint_id = waypoint_id.map(array_string::<MAX_LEN>).transpose()?;
 
-    Ok(BwcData {
+    let data = BwcData {
         fix_time,
-        latitude: lat_lon.map(|v| v.0),
-        longitude: lat_lon.map(|v| v.1),
+        latitude: lat_lon.map(|(latitude, _)| latitude.degrees()),
+        longitude: lat_lon.map(|(_, longitude)| longitude.degrees()),
         true_bearing,
         magnetic_bearing,
         distance,
         waypoint_id,
-    })
+    };
+    check_lat_lon(data.latitude, data.longitude)?;
+
+    Ok(data)
 }
 
 /// # Parse BWC message
 ///
 /// See: <https://gpsd.gitlab.io/gpsd/NMEA.html#_bwc_bearing_distance_to_waypoint_great_circle>
+///
+/// The waypoint ID is bounded by [`TEXT_PARAMETER_MAX_LEN`], use
+/// [`parse_bwc_with_max_len`] for a different bound.
 pub fn parse_bwc(sentence: NmeaSentence) -> Result<BwcData, Error> {
+    parse_bwc_with_max_len(sentence)
+}
+
+/// Parse BWC message with the waypoint ID bounded by `MAX_LEN` characters.
+///
+/// # Errors
+///
+/// [`Error::ParameterLength`] with `max_length` set to `MAX_LEN` if the waypoint ID is longer.
+pub fn parse_bwc_with_max_len<const MAX_LEN: usize>(
+    sentence: NmeaSentence,
+) -> Result<BwcData<MAX_LEN>, Error> {
     if sentence.message_id != SentenceType::BWC {
         Err(Error::WrongSentenceHeader {
             expected: SentenceType::BWC,
@@ -117,10 +146,12 @@ pub fn parse_bwc(sentence: NmeaSentence) -> Result<BwcData, Error> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "chrono"))]
 mod tests {
     use approx::assert_relative_eq;
 
+    use chrono::NaiveTime;
+
     use super::*;
     use crate::parse::parse_nmea_sentence;
 
@@ -130,8 +161,8 @@ mod tests {
             "$GPBWC,220516,5130.02,N,00046.34,W,213.8,T,218.0,M,0004.6,N,EGLM*21",
         )
         .unwrap();
-        assert_eq!(sentence.checksum, sentence.calc_checksum());
-        assert_eq!(sentence.checksum, 0x21);
+        assert_eq!(sentence.checksum, Some(sentence.calc_checksum()));
+        assert_eq!(sentence.checksum, Some(0x21));
 
         let data = parse_bwc(sentence).unwrap();
 
@@ -150,8 +181,8 @@ mod tests {
     #[test]
     fn test_parse_bwc_with_optional_fields() {
         let sentence = parse_nmea_sentence("$GPBWC,081837,,,,,,T,,M,,N,*13").unwrap();
-        assert_eq!(sentence.checksum, sentence.calc_checksum());
-        assert_eq!(sentence.checksum, 0x13);
+        assert_eq!(sentence.checksum, Some(sentence.calc_checksum()));
+        assert_eq!(sentence.checksum, Some(0x13));
 
         let data = parse_bwc(sentence).unwrap();
 
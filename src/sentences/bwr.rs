@@ -0,0 +1,153 @@
+use arrayvec::ArrayString;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    parse::{NmeaSentence, TEXT_PARAMETER_MAX_LEN},
+    sentences::{bwc::do_parse_bwc, utils::Time, BwcData},
+    Error, SentenceType,
+};
+
+/// BWR - Bearing & Distance to Waypoint - Rhumb Line
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_bwr_bearing_and_distance_to_waypoint_rhumb_line>
+///
+/// ```text
+///                                                         12
+///         1         2       3 4        5 6   7 8   9 10  11|    13 14
+///         |         |       | |        | |   | |   | |   | |    |   |
+/// $--BWR,hhmmss.ss,llll.ll,a,yyyyy.yy,a,x.x,T,x.x,M,x.x,N,c--c,m,*hh<CR><LF>
+/// ```
+///
+/// The fields are the same as in [`BwcData`], but the bearing and distance
+/// are computed along the rhumb line instead of the great circle.
+///
+/// The waypoint ID is bounded by `MAX_LEN` characters, [`TEXT_PARAMETER_MAX_LEN`] by default.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, PartialEq)]
+pub struct BwrData<const MAX_LEN: usize = TEXT_PARAMETER_MAX_LEN> {
+    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
+    pub fix_time: Option<Time>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub true_bearing: Option<f32>,
+    pub magnetic_bearing: Option<f32>,
+    pub distance: Option<f32>,
+    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
+    pub waypoint_id: Option<ArrayString<MAX_LEN>>,
+}
+
+/// # Parse BWR message
+///
+/// See: <https://gpsd.gitlab.io/gpsd/NMEA.html#_bwr_bearing_and_distance_to_waypoint_rhumb_line>
+///
+/// The waypoint ID is bounded by [`TEXT_PARAMETER_MAX_LEN`], use
+/// [`parse_bwr_with_max_len`] for a different bound.
+pub fn parse_bwr(sentence: NmeaSentence) -> Result<BwrData, Error> {
+    parse_bwr_with_max_len(sentence)
+}
+
+/// Parse BWR message with the waypoint ID bounded by `MAX_LEN` characters.
+///
+/// # Errors
+///
+/// [`Error::ParameterLength`] with `max_length` set to `MAX_LEN` if the waypoint ID is longer.
+pub fn parse_bwr_with_max_len<const MAX_LEN: usize>(
+    sentence: NmeaSentence,
+) -> Result<BwrData<MAX_LEN>, Error> {
+    if sentence.message_id != SentenceType::BWR {
+        return Err(Error::WrongSentenceHeader {
+            expected: SentenceType::BWR,
+            found: sentence.message_id,
+        });
+    }
+
+    let BwcData {
+        fix_time,
+        latitude,
+        longitude,
+        true_bearing,
+        magnetic_bearing,
+        distance,
+        waypoint_id,
+    } = do_parse_bwc(sentence.data)?;
+
+    Ok(BwrData {
+        fix_time,
+        latitude,
+        longitude,
+        true_bearing,
+        magnetic_bearing,
+        distance,
+        waypoint_id,
+    })
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use chrono::NaiveTime;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_bwr_full() {
+        let sentence = parse_nmea_sentence(
+            "$GPBWR,220516,5130.02,N,00046.34,W,213.8,T,218.0,M,0004.6,N,EGLM*30",
+        )
+        .unwrap();
+        assert_eq!(sentence.checksum, Some(sentence.calc_checksum()));
+
+        let data = parse_bwr(sentence).unwrap();
+
+        assert_eq!(
+            data.fix_time,
+            Some(NaiveTime::from_hms_opt(22, 5, 16).expect("invalid time"))
+        );
+        assert_relative_eq!(data.latitude.unwrap(), 51. + 30.02 / 60.);
+        assert_relative_eq!(data.longitude.unwrap(), -46.34 / 60.0);
+        assert_relative_eq!(data.true_bearing.unwrap(), 213.8);
+        assert_relative_eq!(data.magnetic_bearing.unwrap(), 218.0);
+        assert_relative_eq!(data.distance.unwrap(), 4.6);
+        assert_eq!(&data.waypoint_id.unwrap(), "EGLM");
+    }
+
+    #[test]
+    fn test_parse_bwr_with_optional_fields() {
+        let sentence = parse_nmea_sentence("$GPBWR,081837,,,,,,T,,M,,N,*02").unwrap();
+        assert_eq!(sentence.checksum, Some(sentence.calc_checksum()));
+
+        assert_eq!(
+            parse_bwr(sentence),
+            Ok(BwrData {
+                fix_time: Some(NaiveTime::from_hms_opt(8, 18, 37).expect("invalid time")),
+                latitude: None,
+                longitude: None,
+                true_bearing: None,
+                magnetic_bearing: None,
+                distance: None,
+                waypoint_id: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_bwr_wrong_sentence() {
+        let sentence = parse_nmea_sentence(
+            "$GPBWC,220516,5130.02,N,00046.34,W,213.8,T,218.0,M,0004.6,N,EGLM*21",
+        )
+        .unwrap();
+        assert_eq!(
+            parse_bwr(sentence),
+            Err(Error::WrongSentenceHeader {
+                expected: SentenceType::BWR,
+                found: SentenceType::BWC,
+            })
+        );
+    }
+}
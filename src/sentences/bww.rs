@@ -31,20 +31,22 @@ use super::utils::array_string;
 /// 5. TO Waypoint ID
 /// 6. FROM Waypoint ID
 /// 7. Checksum
-
+///
+/// The waypoint IDs are bounded by `MAX_LEN` characters, [`TEXT_PARAMETER_MAX_LEN`] by default.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[derive(Debug, PartialEq)]
-pub struct BwwData {
+pub struct BwwData<const MAX_LEN: usize = TEXT_PARAMETER_MAX_LEN> {
     pub true_bearing: Option<f32>,
     pub magnetic_bearing: Option<f32>,
     #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
-    pub to_waypoint_id: Option<ArrayString<TEXT_PARAMETER_MAX_LEN>>,
+    pub to_waypoint_id: Option<ArrayString<MAX_LEN>>,
     #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
-    pub from_waypoint_id: Option<ArrayString<TEXT_PARAMETER_MAX_LEN>>,
+    pub from_waypoint_id: Option<ArrayString<MAX_LEN>>,
 }
 
-fn do_parse_bww(i: &str) -> Result<BwwData, Error> {
+fn do_parse_bww<const MAX_LEN: usize>(i: &str) -> Result<BwwData<MAX_LEN>, Error<'_>> {
     // 1. Bearing, degrees True
     let (i, true_bearing) = opt(float)(i)?;
     let (i, _) = char(',')(i)?;
@@ -62,17 +64,13 @@ fn do_parse_bww(i: &str) -> Result<BwwData, Error> {
     // 5. TO Waypoint ID
     let (i, to_waypoint_id) = opt(is_not(","))(i)?;
 
-    let to_waypoint_id = to_waypoint_id
-        .map(array_string::<TEXT_PARAMETER_MAX_LEN>)
-        .transpose()?;
+    let to_waypoint_id = to_waypoint_id.map(array_string::<MAX_LEN>).transpose()?;
 
     // 6. FROM Waypoint ID
     let (i, _) = char(',')(i)?;
     let (_i, from_waypoint_id) = opt(is_not(",*"))(i)?;
 
-    let from_waypoint_id = from_waypoint_id
-        .map(array_string::<TEXT_PARAMETER_MAX_LEN>)
-        .transpose()?;
+    let from_waypoint_id = from_waypoint_id.map(array_string::<MAX_LEN>).transpose()?;
 
     Ok(BwwData {
         true_bearing,
@@ -85,7 +83,21 @@ fn do_parse_bww(i: &str) -> Result<BwwData, Error> {
 /// # Parse BWW message
 ///
 /// See: <https://gpsd.gitlab.io/gpsd/NMEA.html#_bww_bearing_waypoint_to_waypoint>
+///
+/// The waypoint IDs are bounded by [`TEXT_PARAMETER_MAX_LEN`], use
+/// [`parse_bww_with_max_len`] for a different bound.
 pub fn parse_bww(sentence: NmeaSentence) -> Result<BwwData, Error> {
+    parse_bww_with_max_len(sentence)
+}
+
+/// Parse BWW message with the waypoint IDs bounded by `MAX_LEN` characters.
+///
+/// # Errors
+///
+/// [`Error::ParameterLength`] with `max_length` set to `MAX_LEN` if one of them is longer.
+pub fn parse_bww_with_max_len<const MAX_LEN: usize>(
+    sentence: NmeaSentence,
+) -> Result<BwwData<MAX_LEN>, Error> {
     if sentence.message_id != SentenceType::BWW {
         Err(Error::WrongSentenceHeader {
             expected: SentenceType::BWW,
@@ -106,8 +118,8 @@ mod tests {
     #[test]
     fn test_parse_bww_full() {
         let sentence = parse_nmea_sentence("$GPBWW,213.8,T,218.0,M,TOWPT,FROMWPT*42").unwrap();
-        assert_eq!(sentence.checksum, sentence.calc_checksum());
-        assert_eq!(sentence.checksum, 0x42);
+        assert_eq!(sentence.checksum, Some(sentence.calc_checksum()));
+        assert_eq!(sentence.checksum, Some(0x42));
 
         let data = parse_bww(sentence).unwrap();
 
@@ -120,8 +132,8 @@ mod tests {
     #[test]
     fn test_parse_bww_with_optional_fields() {
         let sentence = parse_nmea_sentence("$GPBWW,,T,,M,,*4C").unwrap();
-        assert_eq!(sentence.checksum, sentence.calc_checksum());
-        assert_eq!(sentence.checksum, 0x4C);
+        assert_eq!(sentence.checksum, Some(sentence.calc_checksum()));
+        assert_eq!(sentence.checksum, Some(0x4C));
 
         let data = parse_bww(sentence).unwrap();
 
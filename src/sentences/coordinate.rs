@@ -0,0 +1,146 @@
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+macro_rules! define_coordinate {
+    (
+        $(#[$outer:meta])*
+        $Name:ident, $positive:literal, $negative:literal
+    ) => {
+        $(#[$outer])*
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+        #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+        pub struct $Name(f64);
+
+        impl $Name {
+            /// Creates the coordinate from decimal degrees, negative for the
+            #[doc = concat!("`", $negative, "` hemisphere.")]
+            pub const fn new(degrees: f64) -> Self {
+                Self(degrees)
+            }
+
+            /// Returns the decimal degrees.
+            pub const fn degrees(self) -> f64 {
+                self.0
+            }
+
+            #[doc = concat!("Returns `", $positive, "` or `", $negative, "`, `", $positive, "` for `0.0` and `-0.0`.")]
+            pub fn hemisphere(self) -> char {
+                if self.0 < 0. {
+                    $negative
+                } else {
+                    $positive
+                }
+            }
+
+            /// Splits the absolute value into degrees, minutes and seconds.
+            pub fn to_dms(self) -> (u16, u8, f64) {
+                to_dms(self.0)
+            }
+        }
+
+        impl From<$Name> for f64 {
+            fn from(coordinate: $Name) -> Self {
+                coordinate.0
+            }
+        }
+
+        /// Formats the coordinate as degrees, minutes and seconds rounded to
+        #[doc = concat!("hundredths, e.g. `48°07'02.28\"", $positive, "`.")]
+        impl fmt::Display for $Name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                // round first so that e.g. 59.999 seconds don't show up as 60.00
+                let centis = (abs(self.0) * 360_000. + 0.5) as u64;
+                write!(
+                    f,
+                    "{}°{:02}'{:02}.{:02}\"{}",
+                    centis / 360_000,
+                    centis / 6_000 % 60,
+                    centis / 100 % 60,
+                    centis % 100,
+                    self.hemisphere()
+                )
+            }
+        }
+    };
+}
+
+define_coordinate!(
+    /// Latitude in decimal degrees, positive north of the equator.
+    Latitude,
+    'N',
+    'S'
+);
+
+define_coordinate!(
+    /// Longitude in decimal degrees, positive east of the prime meridian.
+    Longitude,
+    'E',
+    'W'
+);
+
+fn abs(value: f64) -> f64 {
+    if value < 0. {
+        -value
+    } else {
+        value
+    }
+}
+
+fn to_dms(degrees: f64) -> (u16, u8, f64) {
+    let degrees = abs(degrees);
+    let whole_degrees = degrees as u16;
+    let minutes = (degrees - f64::from(whole_degrees)) * 60.;
+    let whole_minutes = minutes as u8;
+    let seconds = (minutes - f64::from(whole_minutes)) * 60.;
+    (whole_degrees, whole_minutes, seconds)
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_to_dms() {
+        let (degrees, minutes, seconds) = Latitude::new(48. + 7.038 / 60.).to_dms();
+        assert_eq!((degrees, minutes), (48, 7));
+        assert_relative_eq!(seconds, 2.28, epsilon = 1e-9);
+
+        let longitude = Longitude::new(-(11. + 31.324 / 60.));
+        assert_eq!(longitude.hemisphere(), 'W');
+        let (degrees, minutes, seconds) = longitude.to_dms();
+        assert_eq!((degrees, minutes), (11, 31));
+        assert_relative_eq!(seconds, 19.44, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            Latitude::new(48. + 7.038 / 60.).to_string(),
+            "48°07'02.28\"N"
+        );
+        assert_eq!(
+            Longitude::new(-(11. + 31.324 / 60.)).to_string(),
+            "11°31'19.44\"W"
+        );
+        assert_eq!(
+            Longitude::new(-179.999_999_9).to_string(),
+            "180°00'00.00\"W"
+        );
+    }
+
+    #[test]
+    fn test_equator_and_prime_meridian() {
+        for degrees in [0., -0.] {
+            assert_eq!(Latitude::new(degrees).hemisphere(), 'N');
+            assert_eq!(Longitude::new(degrees).hemisphere(), 'E');
+            assert_eq!(Latitude::new(degrees).to_dms(), (0, 0, 0.));
+            assert_eq!(Latitude::new(degrees).to_string(), "0°00'00.00\"N");
+            assert_eq!(Longitude::new(degrees).to_string(), "0°00'00.00\"E");
+        }
+    }
+}
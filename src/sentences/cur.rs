@@ -0,0 +1,239 @@
+use nom::{
+    character::complete::{anychar, char},
+    combinator::{eof, map_res, opt},
+    number::complete::float,
+    IResult,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::utils::{number, parse_valid_status};
+use crate::{sentences::Reference, Error, NmeaSentence, SentenceType};
+
+/// Reference of the current direction in a CUR sentence
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CurDirectionReference {
+    /// `T` - true, i.e. relative to the true North
+    True,
+    /// `R` - relative to the own ship
+    Relative,
+}
+
+impl TryFrom<char> for CurDirectionReference {
+    type Error = Error<'static>;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'T' => Ok(CurDirectionReference::True),
+            'R' => Ok(CurDirectionReference::Relative),
+            _ => Err(Error::InvalidIndicator(c)),
+        }
+    }
+}
+
+/// Reference of the current speed in a CUR sentence
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CurSpeedReference {
+    /// `B` - bottom track
+    BottomTrack,
+    /// `W` - water track
+    WaterTrack,
+    /// `P` - positioning system
+    PositioningSystem,
+}
+
+impl TryFrom<char> for CurSpeedReference {
+    type Error = Error<'static>;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'B' => Ok(CurSpeedReference::BottomTrack),
+            'W' => Ok(CurSpeedReference::WaterTrack),
+            'P' => Ok(CurSpeedReference::PositioningSystem),
+            _ => Err(Error::InvalidIndicator(c)),
+        }
+    }
+}
+
+/// CUR - Water Current Layer
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_cur_water_current_layer>
+///
+/// ```text
+///        1 2 3 4   5   6 7   8   9   10 11 12
+///        | | | |   |   | |   |   |   |  |  |
+/// $--CUR,A,x,x,x.x,x.x,a,x.x,x.x,x.x,a,a*hh<CR><LF>
+/// ```
+/// 1. Validity of the data, A = Valid, V = not valid
+/// 2. Data set number, 0 - 9
+/// 3. Layer number
+/// 4. Current depth, meters
+/// 5. Current direction, degrees
+/// 6. Direction reference in use, T = True, R = Relative
+/// 7. Current speed, knots
+/// 8. Reference layer depth, meters
+/// 9. Heading, degrees
+/// 10. Heading reference in use, T = True, M = Magnetic
+/// 11. Speed reference, B = Bottom track, W = Water track, P = Positioning system
+/// 12. Checksum
+///
+/// Example:
+/// ```text
+/// $VWCUR,A,1,2,5.5,123.4,T,1.2,4.0,45.0,T,W*60
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CurData {
+    /// `true` if the data is valid
+    pub valid: bool,
+    /// Data set number, 0 - 9
+    pub data_set_number: Option<u8>,
+    pub layer_number: Option<u8>,
+    /// Current depth, meters
+    pub current_depth: Option<f32>,
+    /// Current direction, degrees
+    pub current_direction: Option<f32>,
+    pub direction_reference: Option<CurDirectionReference>,
+    /// Current speed, knots
+    pub current_speed: Option<f32>,
+    /// Reference layer depth, meters
+    pub reference_layer_depth: Option<f32>,
+    /// Heading, degrees
+    pub heading: Option<f32>,
+    pub heading_reference: Option<Reference>,
+    pub speed_reference: Option<CurSpeedReference>,
+}
+
+/// # Parse CUR message
+///
+/// See: <https://gpsd.gitlab.io/gpsd/NMEA.html#_cur_water_current_layer>
+pub fn parse_cur(sentence: NmeaSentence) -> Result<CurData, Error> {
+    if sentence.message_id != SentenceType::CUR {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::CUR,
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_cur(sentence.data)?.1)
+    }
+}
+
+fn do_parse_cur(i: &str) -> IResult<&str, CurData> {
+    // 1. Validity of the data
+    let (i, valid) = parse_valid_status(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. Data set number
+    let (i, data_set_number) = opt(number::<u8>)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 3. Layer number
+    let (i, layer_number) = opt(number::<u8>)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 4. Current depth, meters
+    let (i, current_depth) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 5. Current direction, degrees
+    let (i, current_direction) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 6. Direction reference in use
+    let (i, direction_reference) = opt(map_res(anychar, CurDirectionReference::try_from))(i)?;
+    let (i, _) = char(',')(i)?;
+    // 7. Current speed, knots
+    let (i, current_speed) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 8. Reference layer depth, meters
+    let (i, reference_layer_depth) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 9. Heading, degrees
+    let (i, heading) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 10. Heading reference in use
+    let (i, heading_reference) = opt(map_res(anychar, Reference::try_from))(i)?;
+    let (i, _) = char(',')(i)?;
+    // 11. Speed reference
+    let (i, speed_reference) = opt(map_res(anychar, CurSpeedReference::try_from))(i)?;
+    let (i, _) = eof(i)?;
+
+    Ok((
+        i,
+        CurData {
+            valid,
+            data_set_number,
+            layer_number,
+            current_depth,
+            current_direction,
+            direction_reference,
+            current_speed,
+            reference_layer_depth,
+            heading,
+            heading_reference,
+            speed_reference,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_cur() {
+        let s = parse_nmea_sentence("$VWCUR,A,1,2,5.5,123.4,T,1.2,4.0,45.0,T,W*60").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_cur(s).unwrap();
+
+        assert!(data.valid);
+        assert_eq!(data.data_set_number, Some(1));
+        assert_eq!(data.layer_number, Some(2));
+        assert_relative_eq!(data.current_depth.unwrap(), 5.5);
+        assert_relative_eq!(data.current_direction.unwrap(), 123.4);
+        assert_eq!(data.direction_reference, Some(CurDirectionReference::True));
+        assert_relative_eq!(data.current_speed.unwrap(), 1.2);
+        assert_relative_eq!(data.reference_layer_depth.unwrap(), 4.0);
+        assert_relative_eq!(data.heading.unwrap(), 45.0);
+        assert_eq!(data.heading_reference, Some(Reference::True));
+        assert_eq!(data.speed_reference, Some(CurSpeedReference::WaterTrack));
+    }
+
+    #[test]
+    fn test_parse_cur_empty_fields() {
+        let s = parse_nmea_sentence("$VWCUR,V,,,,,,,,,,*3F").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(
+            parse_cur(s),
+            Ok(CurData {
+                valid: false,
+                data_set_number: None,
+                layer_number: None,
+                current_depth: None,
+                current_direction: None,
+                direction_reference: None,
+                current_speed: None,
+                reference_layer_depth: None,
+                heading: None,
+                heading_reference: None,
+                speed_reference: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_cur_invalid_references() {
+        // `M` is not a valid direction reference
+        let s = parse_nmea_sentence("$VWCUR,A,1,2,5.5,123.4,M,1.2,4.0,45.0,T,W*79").unwrap();
+        assert!(parse_cur(s).is_err());
+
+        // `X` is not a valid speed reference
+        let s = parse_nmea_sentence("$VWCUR,A,1,2,5.5,123.4,R,1.2,4.0,45.0,M,X*70").unwrap();
+        assert!(parse_cur(s).is_err());
+    }
+}
@@ -1,14 +1,9 @@
-use nom::{
-    character::complete::{char, one_of},
-    combinator::opt,
-    number::complete::double,
-    sequence::preceded,
-    IResult,
-};
+use nom::IResult;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use super::utils::{depth_in_meters, parse_depths};
 use crate::{parse::NmeaSentence, Error, ParseResult, SentenceType};
 
 /// DBK - Depth Below Keel
@@ -28,14 +23,22 @@ use crate::{parse::NmeaSentence, Error, ParseResult, SentenceType};
 /// 6:    F = Fathoms
 /// 7:    Mandatory NMEA checksum
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct DbkData {
     pub depth_feet: Option<f64>,
     pub depth_meters: Option<f64>,
     pub depth_fathoms: Option<f64>,
 }
 
+impl DbkData {
+    /// Depth in meters, converted from feet or fathoms if the meters are missing.
+    pub fn meters(&self) -> Option<f64> {
+        depth_in_meters((self.depth_feet, self.depth_meters, self.depth_fathoms))
+    }
+}
+
 impl From<DbkData> for ParseResult {
     fn from(value: DbkData) -> Self {
         ParseResult::DBK(value)
@@ -73,20 +76,14 @@ pub fn parse_dbk(sentence: NmeaSentence) -> Result<DbkData, Error> {
 }
 
 fn do_parse_dbk(i: &str) -> IResult<&str, DbkData> {
-    let (i, depth_feet_value) = opt(double)(i)?;
-    let (i, _) = preceded(char(','), one_of("f"))(i)?;
-    let (i, _) = char(',')(i)?;
-    let (i, depth_meters_value) = opt(double)(i)?;
-    let (i, _) = preceded(char(','), one_of("M"))(i)?;
-    let (i, _) = char(',')(i)?;
-    let (i, depth_fathoms_value) = opt(double)(i)?;
-    let (i, _) = preceded(char(','), one_of("F"))(i)?;
+    let (i, (depth_feet, depth_meters, depth_fathoms)) = parse_depths(i)?;
+
     Ok((
         i,
         DbkData {
-            depth_feet: depth_feet_value,
-            depth_meters: depth_meters_value,
-            depth_fathoms: depth_fathoms_value,
+            depth_feet,
+            depth_meters,
+            depth_fathoms,
         },
     ))
 }
@@ -99,66 +96,82 @@ mod tests {
     #[test]
     fn test_parse_dbk() {
         let s = parse_nmea_sentence("$SDDBK,1330.5,f,0405.5,M,0221.6,F*2E").unwrap();
-        assert_eq!(s.checksum, s.calc_checksum());
-        assert_eq!(s.checksum, 0x2E);
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(s.checksum, Some(0x2E));
         let dbk_data = parse_dbk(s).unwrap();
         assert_eq!(Some(1330.5), dbk_data.depth_feet);
         assert_eq!(Some(405.5), dbk_data.depth_meters);
         assert_eq!(Some(221.6), dbk_data.depth_fathoms);
+        assert_eq!(Some(405.5), dbk_data.meters());
     }
+
+    #[test]
+    fn test_parse_dbk_empty_depths() {
+        let s = parse_nmea_sentence("$SDDBK,,f,,M,,F*37").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(
+            parse_dbk(s),
+            Ok(DbkData {
+                depth_feet: None,
+                depth_meters: None,
+                depth_fathoms: None,
+            })
+        );
+    }
+
     #[test]
     fn test_parse_dbk_invalid_depth_feet_value() {
         let s = parse_nmea_sentence("$SDDBK,1FF0.5,f,0405.5,M,0221.6,F*2E").unwrap();
-        assert_eq!(s.checksum, s.calc_checksum());
-        assert_eq!(s.checksum, 0x2E);
-        assert_eq!(true, parse_dbk(s).is_err());
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(s.checksum, Some(0x2E));
+        assert!(parse_dbk(s).is_err());
     }
 
     #[test]
     fn test_parse_dbk_invalid_depth_feet_unit() {
         let s = parse_nmea_sentence("$SDDBK,1330.5,X,0405.5,M,0221.6,F*10").unwrap();
-        assert_eq!(s.checksum, s.calc_checksum());
-        assert_eq!(s.checksum, 0x10);
-        assert_eq!(true, parse_dbk(s).is_err());
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(s.checksum, Some(0x10));
+        assert!(parse_dbk(s).is_err());
     }
 
     #[test]
     fn test_parse_dbk_invalid_depth_meters_value() {
         let s = parse_nmea_sentence("$SDDBK,1330.5,f,04F5.5,M,0221.6,F*58").unwrap();
-        assert_eq!(s.checksum, s.calc_checksum());
-        assert_eq!(s.checksum, 0x58);
-        assert_eq!(true, parse_dbk(s).is_err());
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(s.checksum, Some(0x58));
+        assert!(parse_dbk(s).is_err());
     }
 
     #[test]
     fn test_parse_dbk_invalid_depth_meters_unit() {
         let s = parse_nmea_sentence("$SDDBK,1330.5,f,0405.5,X,0221.6,F*3B").unwrap();
-        assert_eq!(s.checksum, s.calc_checksum());
-        assert_eq!(s.checksum, 0x3B);
-        assert_eq!(true, parse_dbk(s).is_err());
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(s.checksum, Some(0x3B));
+        assert!(parse_dbk(s).is_err());
     }
 
     #[test]
     fn test_parse_dbk_invalid_depth_fathoms_value() {
         let s = parse_nmea_sentence("$SDDBK,1330.5,f,0405.5,M,02F1.6,F*5A").unwrap();
-        assert_eq!(s.checksum, s.calc_checksum());
-        assert_eq!(s.checksum, 0x5A);
-        assert_eq!(true, parse_dbk(s).is_err());
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(s.checksum, Some(0x5A));
+        assert!(parse_dbk(s).is_err());
     }
 
     #[test]
     fn test_parse_dbk_invalid_depth_fathoms_unit() {
         let s = parse_nmea_sentence("$SDDBK,1330.5,f,0405.5,M,0221.6,X*30").unwrap();
-        assert_eq!(s.checksum, s.calc_checksum());
-        assert_eq!(s.checksum, 0x30);
-        assert_eq!(true, parse_dbk(s).is_err());
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(s.checksum, Some(0x30));
+        assert!(parse_dbk(s).is_err());
     }
 
     #[test]
     fn test_parse_dbk_invalid_sentence_type() {
         let s = parse_nmea_sentence("$INMTW,17.9,x*20").unwrap();
-        assert_eq!(s.checksum, s.calc_checksum());
-        assert_eq!(s.checksum, 0x20);
-        assert_eq!(true, parse_dbk(s).is_err());
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(s.checksum, Some(0x20));
+        assert!(parse_dbk(s).is_err());
     }
 }
@@ -0,0 +1,112 @@
+use nom::IResult;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::utils::{depth_in_meters, parse_depths};
+use crate::{parse::NmeaSentence, Error, SentenceType};
+
+/// DBS - Depth Below Surface
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_dbs_depth_below_surface>
+///
+/// ```text
+///        1   2 3   4 5   6 7
+///        |   | |   | |   | |
+/// $--DBS,x.x,f,x.x,M,x.x,F*hh<CR><LF>
+/// ```
+/// 1. Depth, feet
+/// 2. f = feet
+/// 3. Depth, meters
+/// 4. M = meters
+/// 5. Depth, fathoms
+/// 6. F = fathoms
+/// 7. Checksum
+///
+/// Example:
+/// ```text
+/// $SDDBS,1330.5,f,0405.5,M,0221.6,F*36
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DbsData {
+    pub depth_feet: Option<f64>,
+    pub depth_meters: Option<f64>,
+    pub depth_fathoms: Option<f64>,
+}
+
+impl DbsData {
+    /// Depth in meters, converted from feet or fathoms if the meters are missing.
+    pub fn meters(&self) -> Option<f64> {
+        depth_in_meters((self.depth_feet, self.depth_meters, self.depth_fathoms))
+    }
+}
+
+/// # Parse DBS message
+///
+/// See: <https://gpsd.gitlab.io/gpsd/NMEA.html#_dbs_depth_below_surface>
+pub fn parse_dbs(sentence: NmeaSentence) -> Result<DbsData, Error> {
+    if sentence.message_id != SentenceType::DBS {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::DBS,
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_dbs(sentence.data)?.1)
+    }
+}
+
+fn do_parse_dbs(i: &str) -> IResult<&str, DbsData> {
+    let (i, (depth_feet, depth_meters, depth_fathoms)) = parse_depths(i)?;
+
+    Ok((
+        i,
+        DbsData {
+            depth_feet,
+            depth_meters,
+            depth_fathoms,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_dbs() {
+        let s = parse_nmea_sentence("$SDDBS,1330.5,f,0405.5,M,0221.6,F*36").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_dbs(s).unwrap();
+        assert_eq!(data.depth_feet, Some(1330.5));
+        assert_eq!(data.depth_meters, Some(405.5));
+        assert_eq!(data.depth_fathoms, Some(221.6));
+        assert_eq!(data.meters(), Some(405.5));
+    }
+
+    #[test]
+    fn test_dbs_meters_fallback() {
+        let s = parse_nmea_sentence("$SDDBS,10.0,f,,M,,F*30").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_relative_eq!(parse_dbs(s).unwrap().meters().unwrap(), 3.048);
+
+        let s = parse_nmea_sentence("$SDDBS,,f,,M,10.0,F*30").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_relative_eq!(parse_dbs(s).unwrap().meters().unwrap(), 18.288);
+
+        let s = parse_nmea_sentence("$SDDBS,,f,,M,,F*2F").unwrap();
+        assert_eq!(parse_dbs(s).unwrap().meters(), None);
+    }
+
+    #[test]
+    fn test_parse_dbs_invalid_unit() {
+        let s = parse_nmea_sentence("$SDDBS,1330.5,f,0405.5,X,0221.6,F*23").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert!(parse_dbs(s).is_err());
+    }
+}
@@ -0,0 +1,69 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{navigation::METERS_PER_NAUTICAL_MILE, Error};
+
+/// Unit of a distance, e.g. of the cross-track error, `N` for nautical miles
+/// and `K` for kilometers.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DistanceUnit {
+    NauticalMiles,
+    Kilometers,
+}
+
+impl DistanceUnit {
+    /// Converts a distance in this unit to meters.
+    pub fn to_meters(self, value: f32) -> f32 {
+        match self {
+            DistanceUnit::NauticalMiles => value * METERS_PER_NAUTICAL_MILE as f32,
+            DistanceUnit::Kilometers => value * 1000.0,
+        }
+    }
+}
+
+impl TryFrom<char> for DistanceUnit {
+    type Error = Error<'static>;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'N' => Ok(DistanceUnit::NauticalMiles),
+            'K' => Ok(DistanceUnit::Kilometers),
+            _ => Err(Error::InvalidIndicator(c)),
+        }
+    }
+}
+
+impl From<DistanceUnit> for char {
+    fn from(unit: DistanceUnit) -> Self {
+        match unit {
+            DistanceUnit::NauticalMiles => 'N',
+            DistanceUnit::Kilometers => 'K',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_distance_unit_from_char() {
+        assert_eq!(DistanceUnit::try_from('N'), Ok(DistanceUnit::NauticalMiles));
+        assert_eq!(DistanceUnit::try_from('K'), Ok(DistanceUnit::Kilometers));
+        assert_eq!(
+            DistanceUnit::try_from('M'),
+            Err(Error::InvalidIndicator('M'))
+        );
+        assert_eq!(char::from(DistanceUnit::Kilometers), 'K');
+    }
+
+    #[test]
+    fn test_to_meters() {
+        assert_relative_eq!(DistanceUnit::NauticalMiles.to_meters(0.5), 926.0);
+        assert_relative_eq!(DistanceUnit::Kilometers.to_meters(0.25), 250.0);
+    }
+}
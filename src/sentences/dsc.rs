@@ -0,0 +1,267 @@
+use arrayvec::ArrayString;
+use nom::{
+    bytes::complete::take_while,
+    character::complete::{anychar, char},
+    combinator::{eof, map_res, opt},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::utils::{array_string, number};
+use crate::{parse::NmeaSentence, Error, SentenceType};
+
+/// Maximum length of the address and MMSI fields of a DSC sentence.
+pub const DSC_ADDRESS_MAX_LEN: usize = 10;
+/// Maximum length of the position or frequency and the time or telephone
+/// number fields of a DSC sentence.
+pub const DSC_FIELD_MAX_LEN: usize = 16;
+
+/// Acknowledgement of a DSC call
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DscAcknowledgement {
+    /// `R` - acknowledgement request
+    Request,
+    /// `B` - acknowledgement
+    Acknowledgement,
+    /// `S` - neither, e.g. a relay
+    Neither,
+}
+
+impl TryFrom<char> for DscAcknowledgement {
+    type Error = Error<'static>;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'R' => Ok(DscAcknowledgement::Request),
+            'B' => Ok(DscAcknowledgement::Acknowledgement),
+            'S' => Ok(DscAcknowledgement::Neither),
+            _ => Err(Error::InvalidIndicator(c)),
+        }
+    }
+}
+
+/// DSC - Digital Selective Calling Information
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_dsc_digital_selective_calling_information>
+///
+/// ```text
+///                                                             10  12
+///        1  2          3  4  5  6          7    8          9  | 11|
+///        |  |          |  |  |  |          |    |          |  | | |
+/// $--DSC,xx,xxxxxxxxxx,xx,xx,xx,xxxxxxxxxx,xxxx,xxxxxxxxxx,xx,a,a*hh<CR><LF>
+/// ```
+/// 1. Format specifier
+/// 2. Address, the MMSI followed by a `0` or a geographical area
+/// 3. Category
+/// 4. Nature of distress or first telecommand
+/// 5. Type of communication or second telecommand
+/// 6. Position or channel/frequency
+/// 7. Time or telephone number
+/// 8. MMSI of the ship in distress
+/// 9. Nature of distress
+/// 10. Acknowledgement, R = acknowledge request, B = acknowledgement, S = neither
+/// 11. Expansion indicator, E = followed by a DSE sentence
+/// 12. Checksum
+///
+/// The meaning of fields 4 to 7 depends on the format specifier and the
+/// category, so the position, frequency, time and telephone number fields are
+/// kept as the raw digits.
+///
+/// Example:
+/// ```text
+/// $CDDSC,20,3380400790,00,21,26,1394807410,2231,,,B,E*78
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DscData {
+    pub format_specifier: Option<u8>,
+    /// Address, the MMSI followed by a `0` or a geographical area
+    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
+    pub address: Option<ArrayString<DSC_ADDRESS_MAX_LEN>>,
+    pub category: Option<u8>,
+    /// Nature of distress or first telecommand
+    pub nature_of_distress: Option<u8>,
+    /// Type of communication or second telecommand
+    pub type_of_communication: Option<u8>,
+    /// Position or channel/frequency, raw digits
+    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
+    pub position: Option<ArrayString<DSC_FIELD_MAX_LEN>>,
+    /// Time (`hhmm`, UTC) or telephone number, raw digits
+    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
+    pub time: Option<ArrayString<DSC_FIELD_MAX_LEN>>,
+    /// MMSI of the ship in distress
+    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
+    pub distress_mmsi: Option<ArrayString<DSC_ADDRESS_MAX_LEN>>,
+    /// Nature of distress of a relayed distress call
+    pub distress_nature: Option<u8>,
+    pub acknowledgement: Option<DscAcknowledgement>,
+    /// `true` if a DSE sentence with expanded data follows
+    pub expansion: bool,
+}
+
+impl DscData {
+    /// MMSI of the called or calling station, taken from the first nine
+    /// digits of the address.
+    ///
+    /// The address of an area call is a geographical area, its "MMSI" is
+    /// meaningless.
+    pub fn mmsi(&self) -> Option<u32> {
+        self.address.as_deref().and_then(mmsi_from_address)
+    }
+}
+
+/// MMSI from the first nine digits of a DSC address, shared with DSE.
+pub(crate) fn mmsi_from_address(address: &str) -> Option<u32> {
+    let digits = address.get(..9)?;
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+/// # Parse DSC message
+///
+/// See: <https://gpsd.gitlab.io/gpsd/NMEA.html#_dsc_digital_selective_calling_information>
+pub fn parse_dsc(sentence: NmeaSentence) -> Result<DscData, Error> {
+    if sentence.message_id != SentenceType::DSC {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::DSC,
+            found: sentence.message_id,
+        })
+    } else {
+        do_parse_dsc(sentence.data)
+    }
+}
+
+/// Parses a digits field into an `ArrayString`, `None` if it is empty.
+fn digits<const MAX_LEN: usize>(
+    i: &str,
+) -> Result<(&str, Option<ArrayString<MAX_LEN>>), Error<'_>> {
+    let (i, field) = take_while(|c| c != ',')(i)?;
+    if field.is_empty() {
+        Ok((i, None))
+    } else {
+        Ok((i, Some(array_string::<MAX_LEN>(field)?)))
+    }
+}
+
+fn do_parse_dsc(i: &str) -> Result<DscData, Error<'_>> {
+    // 1. Format specifier
+    let (i, format_specifier) = opt(number::<u8>)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. Address
+    let (i, address) = digits(i)?;
+    let (i, _) = char(',')(i)?;
+    // 3. Category
+    let (i, category) = opt(number::<u8>)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 4. Nature of distress or first telecommand
+    let (i, nature_of_distress) = opt(number::<u8>)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 5. Type of communication or second telecommand
+    let (i, type_of_communication) = opt(number::<u8>)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 6. Position or channel/frequency
+    let (i, position) = digits(i)?;
+    let (i, _) = char(',')(i)?;
+    // 7. Time or telephone number
+    let (i, time) = digits(i)?;
+    let (i, _) = char(',')(i)?;
+    // 8. MMSI of the ship in distress
+    let (i, distress_mmsi) = digits(i)?;
+    let (i, _) = char(',')(i)?;
+    // 9. Nature of distress
+    let (i, distress_nature) = opt(number::<u8>)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 10. Acknowledgement
+    let (i, acknowledgement) = opt(map_res(anychar, DscAcknowledgement::try_from))(i)?;
+    let (i, _) = char(',')(i)?;
+    // 11. Expansion indicator
+    let (i, expansion) = opt(char('E'))(i)?;
+    eof(i)?;
+
+    Ok(DscData {
+        format_specifier,
+        address,
+        category,
+        nature_of_distress,
+        type_of_communication,
+        position,
+        time,
+        distress_mmsi,
+        distress_nature,
+        acknowledgement,
+        expansion: expansion.is_some(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_dsc() {
+        let s =
+            parse_nmea_sentence("$CDDSC,20,3380400790,00,21,26,1394807410,2231,,,B,E*78").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_dsc(s).unwrap();
+
+        assert_eq!(data.format_specifier, Some(20));
+        assert_eq!(data.address.as_deref(), Some("3380400790"));
+        assert_eq!(data.mmsi(), Some(338_040_079));
+        assert_eq!(data.category, Some(0));
+        assert_eq!(data.nature_of_distress, Some(21));
+        assert_eq!(data.type_of_communication, Some(26));
+        assert_eq!(data.position.as_deref(), Some("1394807410"));
+        assert_eq!(data.time.as_deref(), Some("2231"));
+        assert_eq!(data.distress_mmsi, None);
+        assert_eq!(data.distress_nature, None);
+        assert_eq!(
+            data.acknowledgement,
+            Some(DscAcknowledgement::Acknowledgement)
+        );
+        assert!(data.expansion);
+    }
+
+    #[test]
+    fn test_parse_dsc_empty_fields() {
+        let s = parse_nmea_sentence("$CDDSC,,,,,,,,,,,*7F").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(
+            parse_dsc(s),
+            Ok(DscData {
+                format_specifier: None,
+                address: None,
+                category: None,
+                nature_of_distress: None,
+                type_of_communication: None,
+                position: None,
+                time: None,
+                distress_mmsi: None,
+                distress_nature: None,
+                acknowledgement: None,
+                expansion: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_dsc_invalid_acknowledgement() {
+        let s =
+            parse_nmea_sentence("$CDDSC,12,3380400790,12,06,00,1423108312,2019,,,X,E*61").unwrap();
+        assert!(parse_dsc(s).is_err());
+    }
+
+    #[test]
+    fn test_mmsi_from_address() {
+        assert_eq!(mmsi_from_address("0023300010"), Some(2_330_001));
+        assert_eq!(mmsi_from_address("12345"), None);
+        assert_eq!(mmsi_from_address("12345678X0"), None);
+    }
+}
@@ -0,0 +1,219 @@
+use arrayvec::{ArrayString, ArrayVec};
+use nom::{
+    bytes::complete::{take_while, take_while1},
+    character::complete::{anychar, char},
+    combinator::{eof, map_res, opt},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{
+    dsc::mmsi_from_address,
+    utils::{array_string, number},
+};
+use crate::{parse::NmeaSentence, Error, SentenceType};
+
+/// Maximum number of data sets in a single DSE sentence.
+pub const DSE_MAX_DATA_SETS: usize = 4;
+/// Maximum length of the data field of a DSE data set.
+pub const DSE_DATA_MAX_LEN: usize = 16;
+
+/// Query/reply flag of a DSE sentence
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DseQueryFlag {
+    /// `Q` - query
+    Query,
+    /// `R` - reply
+    Reply,
+    /// `A` - automatic, i.e. sent without a query
+    Automatic,
+}
+
+impl TryFrom<char> for DseQueryFlag {
+    type Error = Error<'static>;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'Q' => Ok(DseQueryFlag::Query),
+            'R' => Ok(DseQueryFlag::Reply),
+            'A' => Ok(DseQueryFlag::Automatic),
+            _ => Err(Error::InvalidIndicator(c)),
+        }
+    }
+}
+
+/// Expanded data set of a [`DseData`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DseDataSet {
+    /// Code of the data set, e.g. `00` for the enhanced position resolution
+    pub code: Option<u8>,
+    /// Data of the data set, raw digits
+    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
+    pub data: Option<ArrayString<DSE_DATA_MAX_LEN>>,
+}
+
+/// DSE - Expanded Digital Selective Calling
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_dse_extended_digital_selective_calling>
+///
+/// ```text
+///        1 2 3 4          5  6    7
+///        | | | |          |  |    |
+/// $--DSE,x,x,a,xxxxxxxxxx,xx,c--c,...*hh<CR><LF>
+/// ```
+/// 1. Total number of sentences, 1 - 9
+/// 2. Sentence number, 1 - 9
+/// 3. Query/reply flag, Q = query, R = reply, A = automatic
+/// 4. MMSI of the vessel, followed by a `0`
+/// 5. Data set code
+/// 6. Data set
+///
+/// 7. Further data set code and data pairs
+///
+/// A DSE sentence follows the DSC sentence of the same call. The data sets of
+/// a call can be split over several sentences, which are numbered like the
+/// GSV sentences by `total_messages` and `message_number`. More than
+/// [`DSE_MAX_DATA_SETS`] data sets in a sentence result in an
+/// [`Error::ParameterLength`].
+///
+/// Example:
+/// ```text
+/// $CDDSE,1,1,A,3380400790,00,46504437*15
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DseData {
+    pub total_messages: Option<u8>,
+    pub message_number: Option<u8>,
+    pub query_flag: Option<DseQueryFlag>,
+    pub mmsi: Option<u32>,
+    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
+    pub data_sets: ArrayVec<DseDataSet, DSE_MAX_DATA_SETS>,
+}
+
+/// # Parse DSE message
+///
+/// See: <https://gpsd.gitlab.io/gpsd/NMEA.html#_dse_extended_digital_selective_calling>
+pub fn parse_dse(sentence: NmeaSentence) -> Result<DseData, Error> {
+    if sentence.message_id != SentenceType::DSE {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::DSE,
+            found: sentence.message_id,
+        })
+    } else {
+        do_parse_dse(sentence.data)
+    }
+}
+
+fn do_parse_dse(i: &str) -> Result<DseData, Error<'_>> {
+    // 1. Total number of sentences
+    let (i, total_messages) = opt(number::<u8>)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. Sentence number
+    let (i, message_number) = opt(number::<u8>)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 3. Query/reply flag
+    let (i, query_flag) = opt(map_res(anychar, DseQueryFlag::try_from))(i)?;
+    let (i, _) = char(',')(i)?;
+    // 4. MMSI
+    let (mut i, mmsi) = opt(map_res(
+        take_while1(|c: char| c.is_ascii_digit()),
+        |address| mmsi_from_address(address).ok_or("invalid MMSI"),
+    ))(i)?;
+
+    // 5. - 7. Data set code and data pairs
+    let mut data_sets = ArrayVec::new();
+    while let (rest, Some(_)) = opt(char(','))(i)? {
+        let (rest, code) = opt(number::<u8>)(rest)?;
+        let (rest, _) = char(',')(rest)?;
+        let (rest, data) = take_while(|c| c != ',')(rest)?;
+        if data_sets.is_full() {
+            return Err(Error::ParameterLength {
+                max_length: DSE_MAX_DATA_SETS,
+                parameter_length: DSE_MAX_DATA_SETS + (rest.split(',').count() + 1) / 2,
+            });
+        }
+        let data = if data.is_empty() {
+            None
+        } else {
+            Some(array_string::<DSE_DATA_MAX_LEN>(data)?)
+        };
+        data_sets.push(DseDataSet { code, data });
+        i = rest;
+    }
+    eof(i)?;
+
+    Ok(DseData {
+        total_messages,
+        message_number,
+        query_flag,
+        mmsi,
+        data_sets,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_dse() {
+        let s = parse_nmea_sentence("$CDDSE,1,1,A,3380400790,00,46504437*15").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_dse(s).unwrap();
+
+        assert_eq!(data.total_messages, Some(1));
+        assert_eq!(data.message_number, Some(1));
+        assert_eq!(data.query_flag, Some(DseQueryFlag::Automatic));
+        assert_eq!(data.mmsi, Some(338_040_079));
+        assert_eq!(data.data_sets.len(), 1);
+        assert_eq!(data.data_sets[0].code, Some(0));
+        assert_eq!(data.data_sets[0].data.as_deref(), Some("46504437"));
+    }
+
+    #[test]
+    fn test_parse_dse_multiple_data_sets() {
+        let s = parse_nmea_sentence("$CDDSE,2,1,Q,3380400790,00,46504437,01,16465049*0A").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_dse(s).unwrap();
+
+        assert_eq!(data.total_messages, Some(2));
+        assert_eq!(data.query_flag, Some(DseQueryFlag::Query));
+        assert_eq!(data.data_sets.len(), 2);
+        assert_eq!(data.data_sets[1].code, Some(1));
+        assert_eq!(data.data_sets[1].data.as_deref(), Some("16465049"));
+    }
+
+    #[test]
+    fn test_parse_dse_without_data_sets() {
+        let s = parse_nmea_sentence("$CDDSE,1,1,A,3380400790*16").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert!(parse_dse(s).unwrap().data_sets.is_empty());
+    }
+
+    #[test]
+    fn test_parse_dse_invalid() {
+        // invalid query flag
+        let s = parse_nmea_sentence("$CDDSE,1,1,X,3380400790,00,46504437*0C").unwrap();
+        assert!(parse_dse(s).is_err());
+
+        // too many data sets
+        let s = parse_nmea_sentence("$CDDSE,1,1,A,3380400790,00,1,01,2,02,3,03,4,04,5*23").unwrap();
+        assert_eq!(
+            parse_dse(s),
+            Err(Error::ParameterLength {
+                max_length: DSE_MAX_DATA_SETS,
+                parameter_length: 5
+            })
+        );
+    }
+}
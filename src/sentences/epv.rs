@@ -0,0 +1,176 @@
+use arrayvec::ArrayString;
+use nom::{
+    bytes::complete::take_while1,
+    character::complete::{anychar, char},
+    combinator::{eof, map_res, opt},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::utils::{array_string, number};
+use crate::{
+    parse::{NmeaSentence, TEXT_PARAMETER_MAX_LEN},
+    Error, SentenceType,
+};
+
+/// Maximum length of the destination equipment type and the unique
+/// identifier of an EPV sentence.
+pub const EPV_ID_MAX_LEN: usize = 16;
+
+/// Sentence status flag of an EPV sentence
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EpvSentenceStatus {
+    /// `C` - command to set the property value
+    Command,
+    /// `R` - report of the current property value
+    Report,
+}
+
+impl TryFrom<char> for EpvSentenceStatus {
+    type Error = Error<'static>;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'C' => Ok(EpvSentenceStatus::Command),
+            'R' => Ok(EpvSentenceStatus::Report),
+            _ => Err(Error::InvalidIndicator(c)),
+        }
+    }
+}
+
+/// EPV - Command or Report Equipment Property Value
+///
+/// ```text
+///        1 2    3    4   5    6
+///        | |    |    |   |    |
+/// $--EPV,a,c--c,c--c,x.x,c--c*hh<CR><LF>
+/// ```
+/// 1. Sentence status flag, C = command, R = report
+/// 2. Destination equipment type, e.g. `AI` for an AIS transponder
+/// 3. Unique identifier of the equipment, e.g. the MMSI
+/// 4. Property identifier
+/// 5. Value of the property
+/// 6. Checksum
+///
+/// The value is kept as text, its format depends on the property, and is
+/// bounded by `MAX_LEN` characters, [`TEXT_PARAMETER_MAX_LEN`] by default.
+///
+/// Example:
+/// ```text
+/// $ECEPV,C,AI,211239680,102,10*24
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EpvData<const MAX_LEN: usize = TEXT_PARAMETER_MAX_LEN> {
+    pub sentence_status: Option<EpvSentenceStatus>,
+    /// Destination equipment type
+    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
+    pub destination_id: Option<ArrayString<EPV_ID_MAX_LEN>>,
+    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
+    pub unique_id: Option<ArrayString<EPV_ID_MAX_LEN>>,
+    pub property_id: Option<u16>,
+    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
+    pub value: Option<ArrayString<MAX_LEN>>,
+}
+
+/// # Parse EPV message
+///
+/// The value is bounded by [`TEXT_PARAMETER_MAX_LEN`], use
+/// [`parse_epv_with_max_len`] for a different bound.
+pub fn parse_epv(sentence: NmeaSentence) -> Result<EpvData, Error> {
+    parse_epv_with_max_len(sentence)
+}
+
+/// Parse EPV message with the value bounded by `MAX_LEN` characters.
+///
+/// # Errors
+///
+/// [`Error::ParameterLength`] with `max_length` set to `MAX_LEN` if the value is longer.
+pub fn parse_epv_with_max_len<const MAX_LEN: usize>(
+    sentence: NmeaSentence,
+) -> Result<EpvData<MAX_LEN>, Error> {
+    if sentence.message_id != SentenceType::EPV {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::EPV,
+            found: sentence.message_id,
+        })
+    } else {
+        do_parse_epv(sentence.data)
+    }
+}
+
+fn do_parse_epv<const MAX_LEN: usize>(i: &str) -> Result<EpvData<MAX_LEN>, Error<'_>> {
+    // 1. Sentence status flag
+    let (i, sentence_status) = opt(map_res(anychar, EpvSentenceStatus::try_from))(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. Destination equipment type
+    let (i, destination_id) = opt(take_while1(|c| c != ','))(i)?;
+    let (i, _) = char(',')(i)?;
+    // 3. Unique identifier
+    let (i, unique_id) = opt(take_while1(|c| c != ','))(i)?;
+    let (i, _) = char(',')(i)?;
+    // 4. Property identifier
+    let (i, property_id) = opt(number::<u16>)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 5. Value of the property
+    let (i, value) = opt(take_while1(|c| c != ','))(i)?;
+    eof(i)?;
+
+    Ok(EpvData {
+        sentence_status,
+        destination_id: destination_id
+            .map(array_string::<EPV_ID_MAX_LEN>)
+            .transpose()?,
+        unique_id: unique_id.map(array_string::<EPV_ID_MAX_LEN>).transpose()?,
+        property_id,
+        value: value.map(array_string::<MAX_LEN>).transpose()?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_epv() {
+        let s = parse_nmea_sentence("$ECEPV,C,AI,211239680,102,10*24").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let epv = parse_epv(s).unwrap();
+
+        assert_eq!(epv.sentence_status, Some(EpvSentenceStatus::Command));
+        assert_eq!(epv.destination_id.as_deref(), Some("AI"));
+        assert_eq!(epv.unique_id.as_deref(), Some("211239680"));
+        assert_eq!(epv.property_id, Some(102));
+        assert_eq!(epv.value.as_deref(), Some("10"));
+
+        let s = parse_nmea_sentence("$AIEPV,R,AI,211239680,114,MY SHIP NAME*2C").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let epv = parse_epv(s).unwrap();
+        assert_eq!(epv.sentence_status, Some(EpvSentenceStatus::Report));
+        assert_eq!(epv.value.as_deref(), Some("MY SHIP NAME"));
+    }
+
+    #[test]
+    fn test_parse_epv_invalid() {
+        let s = parse_nmea_sentence("$AIEPV,X,AI,211239680,114,1*06").unwrap();
+        assert!(matches!(parse_epv(s), Err(Error::ParsingError(_))));
+
+        let s = parse_nmea_sentence(
+            "$AIEPV,R,AI,211239680,114,0123456789012345678901234567890123456789012345678901234567890123456789*00",
+        )
+        .unwrap();
+        assert_eq!(
+            parse_epv(s),
+            Err(Error::ParameterLength {
+                max_length: TEXT_PARAMETER_MAX_LEN,
+                parameter_length: 70
+            })
+        );
+    }
+}
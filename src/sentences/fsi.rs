@@ -0,0 +1,132 @@
+use nom::{
+    character::complete::{char, satisfy},
+    combinator::{eof, opt},
+    IResult,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::utils::number;
+use crate::{parse::NmeaSentence, Error, SentenceType};
+
+/// FSI - Frequency Set Information
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_fsi_frequency_set_information>
+///
+/// ```text
+///        1      2      3 4 5
+///        |      |      | | |
+/// $--FSI,xxxxxx,xxxxxx,c,x*hh<CR><LF>
+/// ```
+/// 1. Transmitting frequency, 100 Hz
+/// 2. Receiving frequency, 100 Hz
+/// 3. Mode of operation
+/// 4. Power level, 0 = standby, 1 = lowest, 9 = highest
+/// 5. Checksum
+///
+/// The mode of operation is one of `d` = F3E/G3E simplex telephone,
+/// `e` = F3E/G3E duplex telephone, `m` = J3E telephone, `o` = H3E telephone,
+/// `q` = F1B/J2B FEC NBDP telex, `s` = F1B/J2B ARQ NBDP telex,
+/// `w` = F1B/J2B receive only teleprinter/DSC, `x` = A1A Morse tape recorder,
+/// `{` = A1A Morse key/headset and `|` = F1C/F2C/F3C facsimile.
+///
+/// Example:
+/// ```text
+/// $GPFSI,021950,025460,d,5*10
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FsiData {
+    /// Transmitting frequency in units of 100 Hz, e.g. `021950` for 2.195 MHz
+    pub transmit_frequency: Option<u32>,
+    /// Receiving frequency in units of 100 Hz
+    pub receive_frequency: Option<u32>,
+    /// Mode of operation, the raw letter
+    pub mode_of_operation: Option<char>,
+    /// Power level, 0 = standby, 1 = lowest, 9 = highest
+    pub power_level: Option<u8>,
+}
+
+/// # Parse FSI message
+///
+/// See: <https://gpsd.gitlab.io/gpsd/NMEA.html#_fsi_frequency_set_information>
+pub fn parse_fsi(sentence: NmeaSentence) -> Result<FsiData, Error> {
+    if sentence.message_id != SentenceType::FSI {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::FSI,
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_fsi(sentence.data)?.1)
+    }
+}
+
+fn do_parse_fsi(i: &str) -> IResult<&str, FsiData> {
+    // 1. Transmitting frequency
+    let (i, transmit_frequency) = opt(number::<u32>)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. Receiving frequency
+    let (i, receive_frequency) = opt(number::<u32>)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 3. Mode of operation
+    let (i, mode_of_operation) = opt(satisfy(|c| c != ','))(i)?;
+    let (i, _) = char(',')(i)?;
+    // 4. Power level
+    let (i, power_level) = opt(number::<u8>)(i)?;
+    let (i, _) = eof(i)?;
+
+    Ok((
+        i,
+        FsiData {
+            transmit_frequency,
+            receive_frequency,
+            mode_of_operation,
+            power_level,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_fsi() {
+        let s = parse_nmea_sentence("$GPFSI,021950,025460,d,5*10").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(
+            parse_fsi(s),
+            Ok(FsiData {
+                transmit_frequency: Some(21950),
+                receive_frequency: Some(25460),
+                mode_of_operation: Some('d'),
+                power_level: Some(5),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_fsi_empty_fields() {
+        let s = parse_nmea_sentence("$GPFSI,,,,*4B").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(
+            parse_fsi(s),
+            Ok(FsiData {
+                transmit_frequency: None,
+                receive_frequency: None,
+                mode_of_operation: None,
+                power_level: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_fsi_invalid_power_level() {
+        let s = parse_nmea_sentence("$GPFSI,021950,025460,d,X*7D").unwrap();
+        assert!(parse_fsi(s).is_err());
+    }
+}
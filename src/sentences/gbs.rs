@@ -1,62 +1,95 @@
-use chrono::NaiveTime;
-use nom::{character::complete::char, combinator::opt, number::complete::float, IResult};
+use nom::{
+    character::complete::char, combinator::opt, number::complete::float, sequence::preceded,
+    IResult,
+};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use crate::{
     parse::NmeaSentence,
-    sentences::utils::{number, parse_hms, parse_lat_lon},
+    sentences::{
+        utils::{number, parse_hex_id, parse_hms, Time},
+        GnssSystem,
+    },
     Error, SentenceType,
 };
 
-/// GBS - GPS Satellite Fault Detection
+/// GBS - GNSS Satellite Fault Detection
 ///
 /// <https://gpsd.gitlab.io/gpsd/NMEA.html#_gbs_gps_satellite_fault_detection>
 ///
 /// ```text
-/// 1      2   3   4   5   6   7   8   9
-/// |      |   |   |   |   |   |   |   |
-/// $--GBS,hhmmss.ss,x.x,x.x,x.x,x.x,x.x,x.x,x.x*hh<CR><LF>
+///        1         2   3   4   5  6   7   8   9 10
+///        |         |   |   |   |  |   |   |   | |
+/// $--GBS,hhmmss.ss,x.x,x.x,x.x,xx,x.x,x.x,x.x,h,h*hh<CR><LF>
+/// ```
+///
+/// 1. UTC time of the GGA or GNS fix associated with this sentence
+/// 2. Expected 1-sigma error in latitude, meters
+/// 3. Expected 1-sigma error in longitude, meters
+/// 4. Expected 1-sigma error in altitude, meters
+/// 5. ID of the most likely failed satellite
+/// 6. Probability of missed detection for the most likely failed satellite
+/// 7. Estimate of bias on the most likely failed satellite, meters
+/// 8. Standard deviation of the bias estimate
+/// 9. GNSS System ID (NMEA 4.1+)
+/// 10. GNSS Signal ID (NMEA 4.1+)
+///
+/// Example:
+/// ```text
+/// $GPGBS,015509.00,-0.031,-0.186,0.219,19,0.000,-0.354,6.972*4D
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct GbsData {
     #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
-    pub time: Option<NaiveTime>,
-    pub lat_error: Option<f64>,
-    pub lon_error: Option<f64>,
+    pub fix_time: Option<Time>,
+    /// Expected 1-sigma error in latitude, meters
+    pub lat_error: Option<f32>,
+    /// Expected 1-sigma error in longitude, meters
+    pub lon_error: Option<f32>,
+    /// Expected 1-sigma error in altitude, meters
     pub alt_error: Option<f32>,
-    pub most_likely_failed_sat: Option<u8>,
+    pub failed_satellite_id: Option<u8>,
     pub missed_probability: Option<f32>,
-    pub bias_estimate: Option<f32>,
-    pub bias_standard_deviation: Option<f32>,
+    /// Estimate of bias on the most likely failed satellite, meters
+    pub estimated_bias: Option<f32>,
+    pub bias_std_dev: Option<f32>,
+    /// GNSS System ID, only present since NMEA 4.1
+    pub system_id: Option<u8>,
+    /// GNSS Signal ID, only present since NMEA 4.1
+    pub signal_id: Option<u8>,
+}
+
+impl GbsData {
+    /// The GNSS system of the `system_id`, `None` if missing or reserved.
+    pub fn system(&self) -> Option<GnssSystem> {
+        self.system_id.and_then(GnssSystem::from_id)
+    }
 }
 
-/// GBS - GPS Satellite Fault Detection
-///
-/// ```text
-/// 1      2   3   4   5   6   7   8   9
-/// |      |   |   |   |   |   |   |   |
-/// $--GBS,hhmmss.ss,x.x,x.x,x.x,x.x,x.x,x.x,x.x*hh<CR><LF>
-/// ```
 fn do_parse_gbs(i: &str) -> IResult<&str, GbsData> {
-    // 1. UTC time of the GGA or GNS fix associated with this sentence. hh is hours, mm is minutes, ss.ss is seconds
-    let (i, time) = opt(parse_hms)(i)?;
+    // 1. UTC time of the GGA or GNS fix associated with this sentence
+    let (i, fix_time) = opt(parse_hms)(i)?;
     let (i, _) = char(',')(i)?;
 
-    // 2. Expected 1-sigma error in latitude (meters)
-    // 3. Expected 1-sigma error in longitude (meters)
-    let (i, lat_lon_errors) = parse_lat_lon(i)?;
+    // 2. Expected 1-sigma error in latitude, meters
+    let (i, lat_error) = opt(float)(i)?;
     let (i, _) = char(',')(i)?;
 
-    // 4. Expected 1-sigma error in altitude (meters)
+    // 3. Expected 1-sigma error in longitude, meters
+    let (i, lon_error) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+
+    // 4. Expected 1-sigma error in altitude, meters
     let (i, alt_error) = opt(float)(i)?;
     let (i, _) = char(',')(i)?;
 
-    // 5. ID of most likely failed satellite (1 to 138)
-    let (i, most_likely_failed_sat) = opt(number::<u8>)(i)?;
+    // 5. ID of most likely failed satellite
+    let (i, failed_satellite_id) = opt(number::<u8>)(i)?;
     let (i, _) = char(',')(i)?;
 
     // 6. Probability of missed detection for most likely failed satellite
@@ -64,28 +97,36 @@ fn do_parse_gbs(i: &str) -> IResult<&str, GbsData> {
     let (i, _) = char(',')(i)?;
 
     // 7. Estimate of bias in meters on most likely failed satellite
-    let (i, bias_estimate) = opt(float)(i)?;
+    let (i, estimated_bias) = opt(float)(i)?;
     let (i, _) = char(',')(i)?;
+
     // 8. Standard deviation of bias estimate
-    let (i, bias_standard_deviation) = opt(float)(i)?;
-    // 9. Checksum
+    let (i, bias_std_dev) = opt(float)(i)?;
+
+    // 9. GNSS System ID (NMEA 4.1+)
+    let (i, system_id) = opt(preceded(char(','), opt(parse_hex_id)))(i)?;
+
+    // 10. GNSS Signal ID (NMEA 4.1+)
+    let (i, signal_id) = opt(preceded(char(','), opt(parse_hex_id)))(i)?;
 
     Ok((
         i,
         GbsData {
-            time,
-            lat_error: lat_lon_errors.map(|(lat, _lon)| lat),
-            lon_error: lat_lon_errors.map(|(_lat, lon)| lon),
+            fix_time,
+            lat_error,
+            lon_error,
             alt_error,
-            most_likely_failed_sat,
+            failed_satellite_id,
             missed_probability,
-            bias_estimate,
-            bias_standard_deviation,
+            estimated_bias,
+            bias_std_dev,
+            system_id: system_id.flatten(),
+            signal_id: signal_id.flatten(),
         },
     ))
 }
 
-/// # Parse BOD message
+/// # Parse GBS message
 ///
 /// See: <https://gpsd.gitlab.io/gpsd/NMEA.html#_gbs_gps_satellite_fault_detection>
 pub fn parse_gbs(sentence: NmeaSentence) -> Result<GbsData, Error> {
@@ -98,3 +139,61 @@ pub fn parse_gbs(sentence: NmeaSentence) -> Result<GbsData, Error> {
         Ok(do_parse_gbs(sentence.data)?.1)
     }
 }
+
+#[cfg(all(test, feature = "chrono"))]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use chrono::NaiveTime;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_gbs() {
+        let s =
+            parse_nmea_sentence("$GPGBS,015509.00,-0.031,-0.186,0.219,19,0.000,-0.354,6.972*4D")
+                .unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_gbs(s).unwrap();
+
+        assert_eq!(
+            data.fix_time,
+            Some(NaiveTime::from_hms_milli_opt(1, 55, 9, 0).unwrap())
+        );
+        assert_relative_eq!(data.lat_error.unwrap(), -0.031);
+        assert_relative_eq!(data.lon_error.unwrap(), -0.186);
+        assert_relative_eq!(data.alt_error.unwrap(), 0.219);
+        assert_eq!(data.failed_satellite_id, Some(19));
+        assert_relative_eq!(data.missed_probability.unwrap(), 0.0);
+        assert_relative_eq!(data.estimated_bias.unwrap(), -0.354);
+        assert_relative_eq!(data.bias_std_dev.unwrap(), 6.972);
+        assert_eq!(data.system_id, None);
+        assert_eq!(data.signal_id, None);
+    }
+
+    #[test]
+    fn test_parse_gbs_nmea_4_1() {
+        let s = parse_nmea_sentence("$GNGBS,170556.00,3.0,2.9,8.3,,,,,A,1*2C").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_gbs(s).unwrap();
+
+        assert_relative_eq!(data.lat_error.unwrap(), 3.0);
+        assert_relative_eq!(data.lon_error.unwrap(), 2.9);
+        assert_relative_eq!(data.alt_error.unwrap(), 8.3);
+        assert_eq!(data.failed_satellite_id, None);
+        assert_eq!(data.missed_probability, None);
+        assert_eq!(data.system_id, Some(10));
+        assert_eq!(data.system(), None);
+        assert_eq!(data.signal_id, Some(1));
+    }
+
+    #[test]
+    fn test_parse_gbs_empty_fields() {
+        let s = parse_nmea_sentence("$GNGBS,181604.00,,,,,,,*7B").unwrap();
+        let data = parse_gbs(s).unwrap();
+        assert_eq!(data.lat_error, None);
+        assert_eq!(data.bias_std_dev, None);
+        assert_eq!(data.system_id, None);
+    }
+}
@@ -1,4 +1,3 @@
-use chrono::NaiveTime;
 use nom::{
     bytes::complete::take_until,
     character::complete::{char, one_of},
@@ -11,10 +10,11 @@ use nom::{
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    navigation::Position,
     parse::NmeaSentence,
     sentences::{
-        utils::{number, parse_float_num, parse_hms, parse_lat_lon},
-        FixType,
+        utils::{check_lat_lon, number, parse_float_num, parse_hms, parse_lat_lon, Time},
+        FixType, Latitude, Longitude,
     },
     Error, SentenceType,
 };
@@ -30,15 +30,16 @@ use crate::{
 ///  $--GGA,hhmmss.ss,ddmm.mm,a,ddmm.mm,a,x,xx,x.x,x.x,M,x.x,M,x.x,xxxx*hh<CR><LF>
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[derive(Debug, PartialEq)]
 pub struct GgaData {
     #[cfg_attr(
-        not(feature = "std"),
+        all(not(feature = "std"), feature = "chrono"),
         cfg_attr(feature = "serde", serde(with = "serde_naive_time"))
     )]
     #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
-    pub fix_time: Option<NaiveTime>,
+    pub fix_time: Option<Time>,
     pub fix_type: Option<FixType>,
     pub latitude: Option<f64>,
     pub longitude: Option<f64>,
@@ -48,6 +49,54 @@ pub struct GgaData {
     pub geoid_separation: Option<f32>,
 }
 
+impl GgaData {
+    /// The latitude of the fix as a [`Latitude`].
+    pub fn latitude(&self) -> Option<Latitude> {
+        self.latitude.map(Latitude::new)
+    }
+
+    /// The longitude of the fix as a [`Longitude`].
+    pub fn longitude(&self) -> Option<Longitude> {
+        self.longitude.map(Longitude::new)
+    }
+
+    /// Position of the fix, if both latitude and longitude are present.
+    pub fn position(&self) -> Option<Position> {
+        Some(Position::new(self.latitude?, self.longitude?))
+    }
+
+    /// Altitude above the mean sea level (orthometric height), meters.
+    ///
+    /// This is the altitude field as reported. Receivers which leave the
+    /// geoid separation empty may report the ellipsoidal height instead.
+    pub fn altitude_msl(&self) -> Option<f32> {
+        self.altitude
+    }
+
+    /// Height above the WGS84 ellipsoid, meters.
+    ///
+    /// The sum of the altitude above the mean sea level and the geoid
+    /// separation, `None` if either of them is missing.
+    pub fn altitude_ellipsoidal(&self) -> Option<f32> {
+        Some(self.altitude? + self.geoid_separation?)
+    }
+
+    /// Altitude above the mean sea level, using the `geoid` model when the
+    /// sentence reports no geoid separation.
+    ///
+    /// Receivers which leave the geoid separation empty report the height
+    /// above the WGS84 ellipsoid in the altitude field, the geoid undulation
+    /// at the position of the fix is subtracted from it.
+    #[cfg(feature = "geoid")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "geoid")))]
+    pub fn altitude_msl_with_geoid<G: crate::geoid::GeoidModel>(&self, geoid: &G) -> Option<f32> {
+        match self.geoid_separation {
+            Some(_) => self.altitude,
+            None => Some(self.altitude? - geoid.geoid_undulation(&self.position()?)),
+        }
+    }
+}
+
 fn do_parse_gga(i: &str) -> IResult<&str, GgaData> {
     let (i, fix_time) = opt(parse_hms)(i)?;
     let (i, _) = char(',')(i)?;
@@ -72,8 +121,8 @@ fn do_parse_gga(i: &str) -> IResult<&str, GgaData> {
         GgaData {
             fix_time,
             fix_type: Some(FixType::from(fix_quality)),
-            latitude: lat_lon.map(|v| v.0),
-            longitude: lat_lon.map(|v| v.1),
+            latitude: lat_lon.map(|(latitude, _)| latitude.degrees()),
+            longitude: lat_lon.map(|(_, longitude)| longitude.degrees()),
             fix_satellites,
             hdop,
             altitude,
@@ -108,14 +157,16 @@ pub fn parse_gga(sentence: NmeaSentence) -> Result<GgaData, Error> {
             found: sentence.message_id,
         })
     } else {
-        Ok(do_parse_gga(sentence.data)?.1)
+        let data = do_parse_gga(sentence.data)?.1;
+        check_lat_lon(data.latitude, data.longitude)?;
+        Ok(data)
     }
 }
 
-#[cfg(not(feature = "std"))]
+#[cfg(all(not(feature = "std"), feature = "chrono"))]
 #[cfg(feature = "serde")]
 mod serde_naive_time {
-    use super::*;
+    use chrono::NaiveTime;
     use core::fmt::{self, Write};
     use serde::de::Visitor;
 
@@ -187,10 +238,12 @@ mod serde_naive_time {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "chrono"))]
 mod tests {
     use approx::assert_relative_eq;
 
+    use chrono::NaiveTime;
+
     use super::*;
     use crate::parse::parse_nmea_sentence;
 
@@ -200,7 +253,8 @@ mod tests {
             talker_id: "GP",
             message_id: SentenceType::GGA,
             data: "033745.0,5650.82344,N,03548.9778,E,1,07,1.8,101.2,M,14.7,M,,",
-            checksum: 0x57,
+            checksum: Some(0x57),
+            tag_block: None,
         })
         .unwrap();
         assert_eq!(
@@ -210,13 +264,15 @@ mod tests {
         assert_eq!(data.fix_type.unwrap(), FixType::Gps);
         assert_relative_eq!(data.latitude.unwrap(), 56. + 50.82344 / 60.);
         assert_relative_eq!(data.longitude.unwrap(), 35. + 48.9778 / 60.);
+        assert_eq!(data.latitude().unwrap().hemisphere(), 'N');
+        assert_eq!(data.longitude().unwrap().to_dms().0, 35);
         assert_eq!(data.fix_satellites.unwrap(), 7);
         assert_relative_eq!(data.hdop.unwrap(), 1.8);
         assert_relative_eq!(data.altitude.unwrap(), 101.2);
         assert_relative_eq!(data.geoid_separation.unwrap(), 14.7);
 
         let s = parse_nmea_sentence("$GPGGA,,,,,,0,,,,,,,,*66").unwrap();
-        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
         let data = parse_gga(s).unwrap();
         assert_eq!(
             GgaData {
@@ -231,6 +287,8 @@ mod tests {
             },
             data
         );
+        assert_eq!(data.latitude(), None);
+        assert_eq!(data.longitude(), None);
     }
 
     #[test]
@@ -238,12 +296,48 @@ mod tests {
         let sentence =
             parse_nmea_sentence("$GPGGA,133605.0,5521.75946,N,03731.93769,E,0,00,,,M,,M,,*4F")
                 .unwrap();
-        assert_eq!(sentence.checksum, sentence.calc_checksum());
-        assert_eq!(sentence.checksum, 0x4f);
+        assert_eq!(sentence.checksum, Some(sentence.calc_checksum()));
+        assert_eq!(sentence.checksum, Some(0x4f));
         let data = parse_gga(sentence).unwrap();
         assert_eq!(data.fix_type.unwrap(), FixType::Invalid);
     }
 
+    #[test]
+    fn test_gga_altitudes() {
+        let s = parse_nmea_sentence(
+            "$GPGGA,033745.0,5650.82344,N,03548.9778,E,1,07,1.8,101.2,M,14.7,M,,*60",
+        )
+        .unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_gga(s).unwrap();
+        assert_relative_eq!(data.altitude_msl().unwrap(), 101.2);
+        assert_relative_eq!(data.altitude_ellipsoidal().unwrap(), 115.9);
+
+        // the geoid is below the ellipsoid
+        let s = parse_nmea_sentence(
+            "$GPGGA,033745.0,0130.00,N,07700.00,E,1,07,1.8,12.5,M,-96.3,M,,*41",
+        )
+        .unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_gga(s).unwrap();
+        assert_relative_eq!(data.altitude_msl().unwrap(), 12.5);
+        assert_relative_eq!(data.altitude_ellipsoidal().unwrap(), -83.8);
+
+        // without geoid separation
+        let s = parse_nmea_sentence("$GPGGA,033745.0,,,,,1,07,1.8,101.2,M,,M,,*73").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_gga(s).unwrap();
+        assert_relative_eq!(data.altitude_msl().unwrap(), 101.2);
+        assert_eq!(data.altitude_ellipsoidal(), None);
+
+        // without altitude
+        let s = parse_nmea_sentence("$GPGGA,033745.0,,,,,1,07,1.8,,M,14.7,M,,*43").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_gga(s).unwrap();
+        assert_eq!(data.altitude_msl(), None);
+        assert_eq!(data.altitude_ellipsoidal(), None);
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn test_serialize_deserialize_gga_data_with_fix_time_milis() {
@@ -252,7 +346,8 @@ mod tests {
             talker_id: "GP",
             message_id: SentenceType::GGA,
             data: "033745.222,5650.82344,N,03548.9778,E,1,07,1.8,101.2,M,14.7,M,,",
-            checksum: 0x57,
+            checksum: Some(0x57),
+            tag_block: None,
         })
         .unwrap();
 
@@ -275,7 +370,8 @@ mod tests {
             talker_id: "GP",
             message_id: SentenceType::GGA,
             data: "033745.222222222,5650.82344,N,03548.9778,E,1,07,1.8,101.2,M,14.7,M,,",
-            checksum: 0x57,
+            checksum: Some(0x57),
+            tag_block: None,
         })
         .unwrap();
 
@@ -298,7 +394,8 @@ mod tests {
             talker_id: "GP",
             message_id: SentenceType::GGA,
             data: "033745.000,5650.82344,N,03548.9778,E,1,07,1.8,101.2,M,14.7,M,,",
-            checksum: 0x57,
+            checksum: Some(0x57),
+            tag_block: None,
         })
         .unwrap();
 
@@ -320,7 +417,8 @@ mod tests {
             talker_id: "GP",
             message_id: SentenceType::GGA,
             data: ",5650.82344,N,03548.9778,E,1,07,1.8,101.2,M,14.7,M,,",
-            checksum: 0x57,
+            checksum: Some(0x57),
+            tag_block: None,
         })
         .unwrap();
 
@@ -331,4 +429,34 @@ mod tests {
 
         assert_eq!(data.fix_time, gga.fix_time);
     }
+
+    #[cfg(feature = "geoid")]
+    #[test]
+    fn test_gga_altitude_msl_with_geoid() {
+        use crate::geoid::GeoidGrid;
+
+        // a synthetic grid, see the tests of the geoid module
+        let undulations = [
+            10.0, 10.0, 10.0, 10.0, 10.0, //
+            20.0, -10.0, 0.0, 30.0, 20.0, //
+            -30.0, -30.0, -30.0, -30.0, -30.0,
+        ];
+        let grid = GeoidGrid::new(&undulations, 90.0).unwrap();
+
+        // ellipsoidal height without geoid separation
+        let s =
+            parse_nmea_sentence("$GPGGA,033745.0,0000.00,N,09000.00,E,1,07,1.8,101.2,M,,M,,*41")
+                .unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_gga(s).unwrap();
+        assert_relative_eq!(data.altitude_msl_with_geoid(&grid).unwrap(), 111.2);
+
+        // the altitude is already above MSL
+        let s = parse_nmea_sentence(
+            "$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76",
+        )
+        .unwrap();
+        let data = parse_gga(s).unwrap();
+        assert_relative_eq!(data.altitude_msl_with_geoid(&grid).unwrap(), 61.7);
+    }
 }
@@ -0,0 +1,235 @@
+use arrayvec::ArrayVec;
+use nom::{
+    character::complete::{anychar, char},
+    combinator::{eof, map_res, opt},
+    number::complete::double,
+    IResult,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::utils::number;
+use crate::{parse::NmeaSentence, Error, SentenceType};
+
+/// Maximum number of secondary stations in a single GLC sentence.
+pub const GLC_MAX_SECONDARIES: usize = 5;
+
+/// Status of a Loran-C signal
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GlcSignalStatus {
+    /// `A` - valid
+    Valid,
+    /// `B` - blink warning
+    BlinkWarning,
+    /// `C` - cycle warning
+    CycleWarning,
+    /// `S` - signal to noise ratio warning
+    SnrWarning,
+}
+
+impl TryFrom<char> for GlcSignalStatus {
+    type Error = Error<'static>;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'A' => Ok(GlcSignalStatus::Valid),
+            'B' => Ok(GlcSignalStatus::BlinkWarning),
+            'C' => Ok(GlcSignalStatus::CycleWarning),
+            'S' => Ok(GlcSignalStatus::SnrWarning),
+            _ => Err(Error::InvalidIndicator(c)),
+        }
+    }
+}
+
+/// Time difference to a secondary station of a [`GlcData`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GlcTimeDifference {
+    /// Time difference, microseconds
+    pub time_difference: Option<f64>,
+    pub status: Option<GlcSignalStatus>,
+}
+
+/// GLC - Geographic Position, Loran-C
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_glc_geographic_position_loran_c>
+///
+/// ```text
+///        1    2   3 4   5 6   7 8   9 10  11 12  13 14
+///        |    |   | |   | |   | |   | |   |  |   |  |
+/// $--GLC,xxxx,x.x,a,x.x,a,x.x,a,x.x,a,x.x,a,x.x,a*hh<CR><LF>
+/// ```
+/// 1. GRI, tens of microseconds
+/// 2. Master TOA, microseconds
+/// 3. Master TOA signal status
+/// 4. Time difference 1, microseconds
+/// 5. Time difference 1 signal status
+///
+/// 6. - 13. Time differences 2 to 5 and their signal status
+/// 14. Checksum
+///
+/// The signal status is one of `A` = valid, `B` = blink warning,
+/// `C` = cycle warning and `S` = SNR warning.
+///
+/// Each time difference and status pair present in the sentence is kept in
+/// `secondaries`, including empty ones, so the index matches the secondary
+/// station. More than [`GLC_MAX_SECONDARIES`] pairs result in an
+/// [`Error::ParameterLength`].
+///
+/// Example:
+/// ```text
+/// $GPGLC,9960,13000.34,A,27870.12,A,42100.58,B,59120.00,C,,,,*7D
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlcData {
+    /// Group repetition interval, tens of microseconds
+    pub gri: Option<u16>,
+    /// Master TOA, microseconds
+    pub master_toa: Option<f64>,
+    pub master_status: Option<GlcSignalStatus>,
+    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
+    pub secondaries: ArrayVec<GlcTimeDifference, GLC_MAX_SECONDARIES>,
+}
+
+/// # Parse GLC message
+///
+/// See: <https://gpsd.gitlab.io/gpsd/NMEA.html#_glc_geographic_position_loran_c>
+pub fn parse_glc(sentence: NmeaSentence) -> Result<GlcData, Error> {
+    if sentence.message_id != SentenceType::GLC {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::GLC,
+            found: sentence.message_id,
+        })
+    } else {
+        do_parse_glc(sentence.data)
+    }
+}
+
+fn do_parse_glc(i: &str) -> Result<GlcData, Error<'_>> {
+    // 1. GRI
+    let (i, gri) = opt(number::<u16>)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. Master TOA
+    // 3. Master TOA signal status
+    let (mut i, (master_toa, master_status)) = parse_toa_and_status(i)?;
+
+    // 4. - 13. Time differences and their signal status
+    let mut secondaries = ArrayVec::new();
+    while let (rest, Some(_)) = opt(char(','))(i)? {
+        let (rest, (time_difference, status)) = parse_toa_and_status(rest)?;
+        if secondaries.is_full() {
+            return Err(Error::ParameterLength {
+                max_length: GLC_MAX_SECONDARIES,
+                parameter_length: GLC_MAX_SECONDARIES + (rest.split(',').count() + 1) / 2,
+            });
+        }
+        secondaries.push(GlcTimeDifference {
+            time_difference,
+            status,
+        });
+        i = rest;
+    }
+    eof(i)?;
+
+    Ok(GlcData {
+        gri,
+        master_toa,
+        master_status,
+        secondaries,
+    })
+}
+
+fn parse_toa_and_status(i: &str) -> IResult<&str, (Option<f64>, Option<GlcSignalStatus>)> {
+    let (i, toa) = opt(double)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, status) = opt(map_res(anychar, GlcSignalStatus::try_from))(i)?;
+
+    Ok((i, (toa, status)))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_glc() {
+        let s =
+            parse_nmea_sentence("$GPGLC,9960,13000.34,A,27870.12,A,42100.58,B,59120.00,C,,,,*7D")
+                .unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_glc(s).unwrap();
+
+        assert_eq!(data.gri, Some(9960));
+        assert_relative_eq!(data.master_toa.unwrap(), 13000.34);
+        assert_eq!(data.master_status, Some(GlcSignalStatus::Valid));
+        assert_eq!(data.secondaries.len(), GLC_MAX_SECONDARIES);
+        assert_relative_eq!(data.secondaries[0].time_difference.unwrap(), 27870.12);
+        assert_eq!(data.secondaries[0].status, Some(GlcSignalStatus::Valid));
+        assert_relative_eq!(data.secondaries[1].time_difference.unwrap(), 42100.58);
+        assert_eq!(
+            data.secondaries[1].status,
+            Some(GlcSignalStatus::BlinkWarning)
+        );
+        assert_relative_eq!(data.secondaries[2].time_difference.unwrap(), 59120.0);
+        assert_eq!(
+            data.secondaries[2].status,
+            Some(GlcSignalStatus::CycleWarning)
+        );
+        for secondary in &data.secondaries[3..] {
+            assert_eq!(
+                secondary,
+                &GlcTimeDifference {
+                    time_difference: None,
+                    status: None
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_glc_single_secondary() {
+        let s = parse_nmea_sentence("$GPGLC,9960,13000.34,A,27870.12,A*79").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_glc(s).unwrap();
+        assert_eq!(data.secondaries.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_glc_empty_fields() {
+        let s = parse_nmea_sentence("$GPGLC,,,,,,,,,,,,,*73").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_glc(s).unwrap();
+        assert_eq!(data.gri, None);
+        assert_eq!(data.master_toa, None);
+        assert_eq!(data.master_status, None);
+        assert_eq!(data.secondaries.len(), GLC_MAX_SECONDARIES);
+    }
+
+    #[test]
+    fn test_parse_glc_invalid() {
+        // invalid signal status
+        let s = parse_nmea_sentence("$GPGLC,9960,13000.34,X,27870.12,A*60").unwrap();
+        assert!(parse_glc(s).is_err());
+
+        // too many secondaries
+        let s = parse_nmea_sentence("$GPGLC,9960,13000.34,A,1,A,2,A,3,A,4,A,5,A,6,A*28").unwrap();
+        assert_eq!(
+            parse_glc(s),
+            Err(Error::ParameterLength {
+                max_length: GLC_MAX_SECONDARIES,
+                parameter_length: 6
+            })
+        );
+    }
+}
@@ -1,4 +1,3 @@
-use chrono::NaiveTime;
 use nom::{
     character::complete::{anychar, char, one_of},
     combinator::opt,
@@ -10,8 +9,12 @@ use serde::{Deserialize, Serialize};
 
 use super::{faa_mode::parse_faa_mode, nom_parse_failure, FaaMode};
 use crate::{
+    navigation::Position,
     parse::NmeaSentence,
-    sentences::utils::{parse_hms, parse_lat_lon},
+    sentences::{
+        utils::{check_lat_lon, parse_hms, parse_lat_lon, Time},
+        Latitude, Longitude,
+    },
     Error, SentenceType,
 };
 
@@ -31,19 +34,36 @@ use crate::{
 ///         |       | |        | |         | |
 ///  $--GLL,ddmm.mm,a,dddmm.mm,a,hhmmss.ss,a,m*hh<CR><LF>
 /// ```
-
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[derive(Debug, PartialEq)]
 pub struct GllData {
     pub latitude: Option<f64>,
     pub longitude: Option<f64>,
     #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
-    pub fix_time: NaiveTime,
+    pub fix_time: Time,
     pub valid: bool,
     pub faa_mode: Option<FaaMode>,
 }
 
+impl GllData {
+    /// The latitude of the fix as a [`Latitude`].
+    pub fn latitude(&self) -> Option<Latitude> {
+        self.latitude.map(Latitude::new)
+    }
+
+    /// The longitude of the fix as a [`Longitude`].
+    pub fn longitude(&self) -> Option<Longitude> {
+        self.longitude.map(Longitude::new)
+    }
+
+    /// Position of the fix, if both latitude and longitude are present.
+    pub fn position(&self) -> Option<Position> {
+        Some(Position::new(self.latitude?, self.longitude?))
+    }
+}
+
 /// # Parse GLL (Geographic position) message
 ///
 /// From <https://docs.novatel.com/OEM7/Content/Logs/GPGLL.htm>
@@ -66,7 +86,9 @@ pub fn parse_gll(sentence: NmeaSentence) -> Result<GllData, Error> {
             found: sentence.message_id,
         })
     } else {
-        Ok(do_parse_gll(sentence.data)?.1)
+        let data = do_parse_gll(sentence.data)?.1;
+        check_lat_lon(data.latitude, data.longitude)?;
+        Ok(data)
     }
 }
 
@@ -90,8 +112,8 @@ fn do_parse_gll(i: &str) -> IResult<&str, GllData> {
     Ok((
         rest,
         GllData {
-            latitude: lat_lon.map(|x| x.0),
-            longitude: lat_lon.map(|x| x.1),
+            latitude: lat_lon.map(|(latitude, _)| latitude.degrees()),
+            longitude: lat_lon.map(|(_, longitude)| longitude.degrees()),
             valid,
             fix_time,
             faa_mode,
@@ -99,10 +121,12 @@ fn do_parse_gll(i: &str) -> IResult<&str, GllData> {
     ))
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "chrono"))]
 mod tests {
     use approx::assert_relative_eq;
 
+    use chrono::NaiveTime;
+
     use super::*;
     use crate::parse::parse_nmea_sentence;
 
@@ -110,8 +134,8 @@ mod tests {
     fn test_parse_gpgll() {
         let parse = |data, checksum| {
             let s = parse_nmea_sentence(data).unwrap();
-            assert_eq!(s.checksum, s.calc_checksum());
-            assert_eq!(s.checksum, checksum);
+            assert_eq!(s.checksum, Some(s.calc_checksum()));
+            assert_eq!(s.checksum, Some(checksum));
             s
         };
 
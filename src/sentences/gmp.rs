@@ -0,0 +1,225 @@
+use arrayvec::ArrayString;
+use nom::{
+    bytes::complete::{is_not, take_until},
+    character::complete::char,
+    combinator::{eof, map_parser, opt},
+    number::complete::{double, float},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{
+    faa_mode::parse_faa_modes,
+    utils::{array_string, number, parse_hms, Time},
+    FaaModes,
+};
+use crate::{
+    parse::{NmeaSentence, TEXT_PARAMETER_MAX_LEN},
+    Error, SentenceType,
+};
+
+/// GMP - GNSS Map Projection Fix Data
+///
+/// ```text
+///        1         2    3    4   5   6    7  8   9   10  11  12   13
+///        |         |    |    |   |   |    |  |   |   |   |   |    |
+/// $--GMP,hhmmss.ss,c--c,c--c,x.x,x.x,c--c,xx,x.x,x.x,x.x,x.x,xxxx*hh<CR><LF>
+/// ```
+/// 1. UTC time of the fix
+/// 2. Map projection, e.g. `UTM` or `LOC` for a local system
+/// 3. Map zone
+/// 4. X (northing) coordinate, meters
+/// 5. Y (easting) coordinate, meters
+/// 6. Mode indicator, one character per system like in GNS
+/// 7. Total number of satellites in use, 00 - 99
+/// 8. HDOP
+/// 9. Antenna altitude, meters, re: mean-sea-level (geoid)
+/// 10. Geoidal separation, meters
+/// 11. Age of differential data, seconds
+/// 12. Differential reference station ID, 0000 - 1023
+/// 13. Checksum
+///
+/// Example:
+/// ```text
+/// $GNGMP,123519.00,UTM,33U,5457812.345,402567.890,AA,08,1.2,105.3,47.1,2.5,0138*63
+/// ```
+///
+/// The map projection and zone are bounded by `MAX_LEN` characters,
+/// [`TEXT_PARAMETER_MAX_LEN`] by default.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GmpData<const MAX_LEN: usize = TEXT_PARAMETER_MAX_LEN> {
+    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
+    pub fix_time: Option<Time>,
+    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
+    pub map_projection: Option<ArrayString<MAX_LEN>>,
+    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
+    pub map_zone: Option<ArrayString<MAX_LEN>>,
+    /// X (northing) coordinate, meters
+    pub x_coordinate: Option<f64>,
+    /// Y (easting) coordinate, meters
+    pub y_coordinate: Option<f64>,
+    pub mode_indicator: Option<FaaModes>,
+    pub satellites_used: Option<u8>,
+    pub hdop: Option<f32>,
+    /// Antenna altitude above the mean sea level, meters
+    pub altitude: Option<f32>,
+    /// Geoidal separation, meters
+    pub geoid_separation: Option<f32>,
+    /// Age of the differential data, seconds
+    pub dgps_age: Option<f32>,
+    pub dgps_station_id: Option<u16>,
+}
+
+/// # Parse GMP message
+///
+/// The mode indicator supports up to two systems, like for GNS.
+///
+/// The map projection and zone are bounded by [`TEXT_PARAMETER_MAX_LEN`], use
+/// [`parse_gmp_with_max_len`] for a different bound.
+pub fn parse_gmp(sentence: NmeaSentence) -> Result<GmpData, Error> {
+    parse_gmp_with_max_len(sentence)
+}
+
+/// Parse GMP message with the map projection and zone bounded by `MAX_LEN` characters.
+///
+/// # Errors
+///
+/// [`Error::ParameterLength`] with `max_length` set to `MAX_LEN` if one of them is longer.
+pub fn parse_gmp_with_max_len<const MAX_LEN: usize>(
+    sentence: NmeaSentence,
+) -> Result<GmpData<MAX_LEN>, Error> {
+    if sentence.message_id != SentenceType::GMP {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::GMP,
+            found: sentence.message_id,
+        })
+    } else {
+        do_parse_gmp(sentence.data)
+    }
+}
+
+fn do_parse_gmp<const MAX_LEN: usize>(i: &str) -> Result<GmpData<MAX_LEN>, Error<'_>> {
+    // 1. UTC time of the fix
+    let (i, fix_time) = opt(parse_hms)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. Map projection
+    let (i, map_projection) = opt(is_not(","))(i)?;
+    let (i, _) = char(',')(i)?;
+    // 3. Map zone
+    let (i, map_zone) = opt(is_not(","))(i)?;
+    let (i, _) = char(',')(i)?;
+    // 4. X (northing) coordinate
+    let (i, x_coordinate) = opt(double)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 5. Y (easting) coordinate
+    let (i, y_coordinate) = opt(double)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 6. Mode indicator
+    let (i, mode_indicator) = opt(map_parser(take_until(","), parse_faa_modes))(i)?;
+    let (i, _) = char(',')(i)?;
+    // 7. Total number of satellites in use
+    let (i, satellites_used) = opt(number::<u8>)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 8. HDOP
+    let (i, hdop) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 9. Antenna altitude
+    let (i, altitude) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 10. Geoidal separation
+    let (i, geoid_separation) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 11. Age of differential data
+    let (i, dgps_age) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 12. Differential reference station ID
+    let (i, dgps_station_id) = opt(number::<u16>)(i)?;
+    eof(i)?;
+
+    Ok(GmpData {
+        fix_time,
+        map_projection: map_projection.map(array_string::<MAX_LEN>).transpose()?,
+        map_zone: map_zone.map(array_string::<MAX_LEN>).transpose()?,
+        x_coordinate,
+        y_coordinate,
+        mode_indicator,
+        satellites_used,
+        hdop,
+        altitude,
+        geoid_separation,
+        dgps_age,
+        dgps_station_id,
+    })
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use chrono::NaiveTime;
+
+    use super::*;
+    use crate::{parse::parse_nmea_sentence, sentences::FixType};
+
+    #[test]
+    fn test_parse_gmp() {
+        let s = parse_nmea_sentence(
+            "$GNGMP,123519.00,UTM,33U,5457812.345,402567.890,AA,08,1.2,105.3,47.1,2.5,0138*63",
+        )
+        .unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_gmp(s).unwrap();
+
+        assert_eq!(
+            data.fix_time,
+            Some(NaiveTime::from_hms_milli_opt(12, 35, 19, 0).unwrap())
+        );
+        assert_eq!(data.map_projection.as_deref(), Some("UTM"));
+        assert_eq!(data.map_zone.as_deref(), Some("33U"));
+        assert_relative_eq!(data.x_coordinate.unwrap(), 5457812.345);
+        assert_relative_eq!(data.y_coordinate.unwrap(), 402567.890);
+        assert_eq!(data.mode_indicator.map(FixType::from), Some(FixType::Gps));
+        assert_eq!(data.satellites_used, Some(8));
+        assert_relative_eq!(data.hdop.unwrap(), 1.2);
+        assert_relative_eq!(data.altitude.unwrap(), 105.3);
+        assert_relative_eq!(data.geoid_separation.unwrap(), 47.1);
+        assert_relative_eq!(data.dgps_age.unwrap(), 2.5);
+        assert_eq!(data.dgps_station_id, Some(138));
+    }
+
+    #[test]
+    fn test_parse_gmp_empty_fields() {
+        let s = parse_nmea_sentence("$GNGMP,,,,,,,,,,,,*53").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(
+            parse_gmp(s),
+            Ok(GmpData {
+                fix_time: None,
+                map_projection: None,
+                map_zone: None,
+                x_coordinate: None,
+                y_coordinate: None,
+                mode_indicator: None,
+                satellites_used: None,
+                hdop: None,
+                altitude: None,
+                geoid_separation: None,
+                dgps_age: None,
+                dgps_station_id: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_gmp_invalid_mode() {
+        let s = parse_nmea_sentence(
+            "$GNGMP,123519.00,UTM,33U,5457812.345,402567.890,XX,08,1.2,105.3,47.1,,*40",
+        )
+        .unwrap();
+        assert!(parse_gmp(s).is_err());
+    }
+}
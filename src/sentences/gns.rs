@@ -1,4 +1,3 @@
-use chrono::NaiveTime;
 use nom::{
     bytes::complete::{take_until, take_while},
     character::complete::{char, one_of},
@@ -13,10 +12,14 @@ use serde::{Deserialize, Serialize};
 
 use super::{
     faa_mode::parse_faa_modes,
-    utils::{number, parse_hms, parse_lat_lon},
+    utils::{check_lat_lon, number, parse_hms, parse_lat_lon, Time},
     FaaModes,
 };
-use crate::{parse::NmeaSentence, Error, SentenceType};
+use crate::{
+    parse::NmeaSentence,
+    sentences::{Latitude, Longitude},
+    Error, SentenceType,
+};
 
 /// GNS - Fix data
 ///
@@ -28,11 +31,12 @@ use crate::{parse::NmeaSentence, Error, SentenceType};
 /// $--GNS,hhmmss.ss,ddmm.mm,a,dddmm.mm,a,c--c,xx,x.x,x.x,x.x,x.x,x.x*hh
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[derive(Debug, PartialEq)]
 pub struct GnsData {
     #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
-    pub fix_time: Option<NaiveTime>,
+    pub fix_time: Option<Time>,
     pub lat: Option<f64>,
     pub lon: Option<f64>,
     pub faa_modes: FaaModes,
@@ -43,6 +47,18 @@ pub struct GnsData {
     pub nav_status: Option<NavigationStatus>,
 }
 
+impl GnsData {
+    /// The latitude of the fix as a [`Latitude`].
+    pub fn latitude(&self) -> Option<Latitude> {
+        self.lat.map(Latitude::new)
+    }
+
+    /// The longitude of the fix as a [`Longitude`].
+    pub fn longitude(&self) -> Option<Longitude> {
+        self.lon.map(Longitude::new)
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -101,7 +117,9 @@ pub fn parse_gns(sentence: NmeaSentence) -> Result<GnsData, Error> {
             found: sentence.message_id,
         })
     } else {
-        Ok(do_parse_gns(sentence.data)?.1)
+        let data = do_parse_gns(sentence.data)?.1;
+        check_lat_lon(data.lat, data.lon)?;
+        Ok(data)
     }
 }
 
@@ -135,8 +153,8 @@ fn do_parse_gns(i: &str) -> IResult<&str, GnsData> {
         i,
         GnsData {
             fix_time,
-            lat: lat_lon.map(|x| x.0),
-            lon: lat_lon.map(|x| x.1),
+            lat: lat_lon.map(|(latitude, _)| latitude.degrees()),
+            lon: lat_lon.map(|(_, longitude)| longitude.degrees()),
             faa_modes,
             nsattelites,
             hdop,
@@ -147,18 +165,20 @@ fn do_parse_gns(i: &str) -> IResult<&str, GnsData> {
     ))
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "chrono"))]
 mod tests {
     use approx::assert_relative_eq;
 
+    use chrono::NaiveTime;
+
     use super::*;
     use crate::parse::parse_nmea_sentence;
 
     #[test]
     fn test_parse_gns() {
         let s = parse_nmea_sentence("$GPGNS,224749.00,3333.4268304,N,11153.3538273,W,D,19,0.6,406.110,-26.294,6.0,0138,S,*46").unwrap();
-        assert_eq!(s.checksum, s.calc_checksum());
-        assert_eq!(s.checksum, 0x46);
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(s.checksum, Some(0x46));
         let gns_data = parse_gns(s).unwrap();
         assert_eq!(
             gns_data.fix_time,
@@ -59,3 +59,94 @@ impl fmt::Display for GnssType {
         }
     }
 }
+
+/// GNSS System ID of the NMEA 4.1 `systemId` fields
+///
+/// | ID  | System  |
+/// |-----|---------|
+/// | `1` | GPS     |
+/// | `2` | GLONASS |
+/// | `3` | Galileo |
+/// | `4` | BeiDou  |
+/// | `5` | QZSS    |
+/// | `6` | NavIC   |
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(PartialEq, Debug, Hash, Eq, Clone, Copy)]
+#[repr(u8)]
+pub enum GnssSystem {
+    Gps = 1,
+    Glonass = 2,
+    Galileo = 3,
+    BeiDou = 4,
+    Qzss = 5,
+    NavIC = 6,
+}
+
+impl GnssSystem {
+    /// Looks up the system of a numeric System ID, `None` for reserved IDs.
+    pub fn from_id(id: u8) -> Option<Self> {
+        match id {
+            1 => Some(GnssSystem::Gps),
+            2 => Some(GnssSystem::Glonass),
+            3 => Some(GnssSystem::Galileo),
+            4 => Some(GnssSystem::BeiDou),
+            5 => Some(GnssSystem::Qzss),
+            6 => Some(GnssSystem::NavIC),
+            _ => None,
+        }
+    }
+
+    /// The numeric System ID.
+    pub fn id(self) -> u8 {
+        self as u8
+    }
+}
+
+impl From<GnssSystem> for GnssType {
+    fn from(system: GnssSystem) -> Self {
+        match system {
+            GnssSystem::Gps => GnssType::Gps,
+            GnssSystem::Glonass => GnssType::Glonass,
+            GnssSystem::Galileo => GnssType::Galileo,
+            GnssSystem::BeiDou => GnssType::Beidou,
+            GnssSystem::Qzss => GnssType::Qzss,
+            GnssSystem::NavIC => GnssType::NavIC,
+        }
+    }
+}
+
+impl fmt::Display for GnssSystem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        GnssType::from(*self).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gnss_system_ids() {
+        let table = [
+            (1, GnssSystem::Gps, GnssType::Gps),
+            (2, GnssSystem::Glonass, GnssType::Glonass),
+            (3, GnssSystem::Galileo, GnssType::Galileo),
+            (4, GnssSystem::BeiDou, GnssType::Beidou),
+            (5, GnssSystem::Qzss, GnssType::Qzss),
+            (6, GnssSystem::NavIC, GnssType::NavIC),
+        ];
+        for (id, system, gnss_type) in table {
+            assert_eq!(GnssSystem::from_id(id), Some(system));
+            assert_eq!(system.id(), id);
+            assert_eq!(GnssType::from(system), gnss_type);
+        }
+    }
+
+    #[test]
+    fn test_gnss_system_reserved_ids() {
+        assert_eq!(GnssSystem::from_id(0), None);
+        assert_eq!(GnssSystem::from_id(7), None);
+        assert_eq!(GnssSystem::from_id(0xF), None);
+    }
+}
@@ -0,0 +1,176 @@
+use arrayvec::ArrayVec;
+use nom::{
+    character::complete::{char, one_of},
+    combinator::opt,
+    number::complete::float,
+    sequence::preceded,
+    IResult,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    parse::NmeaSentence,
+    sentences::{
+        utils::{parse_hex_id, parse_hms, Time},
+        GnssSystem,
+    },
+    Error, SentenceType,
+};
+
+/// Number of range residual slots in a GRS sentence
+pub const GRS_RESIDUALS_COUNT: usize = 12;
+
+/// GRS - GNSS Range Residuals
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_grs_gps_range_residuals>
+///
+/// ```text
+///        1         2 3   4   5   6   7   8   9   10  11  12  13  14  15 16
+///        |         | |   |   |   |   |   |   |   |   |   |   |   |   |  |
+/// $--GRS,hhmmss.ss,m,x.x,x.x,x.x,x.x,x.x,x.x,x.x,x.x,x.x,x.x,x.x,x.x,h,h*hh<CR><LF>
+/// ```
+///
+/// 1. UTC time of the GGA or GNS fix associated with this sentence
+/// 2. Mode:
+///     - 0 = residuals were used to calculate the position given in the matching GGA or GNS sentence
+///     - 1 = residuals were recomputed after the GGA or GNS position was computed
+/// 3. to 14. Range residuals in meters, in the satellite order of the matching GSA sentence
+/// 15. GNSS System ID (NMEA 4.1+)
+/// 16. GNSS Signal ID (NMEA 4.1+)
+///
+/// Example:
+/// ```text
+/// $GPGRS,220320.0,0,-0.8,-0.2,-0.1,-0.2,0.8,0.6,,,,,,*79
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrsData {
+    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
+    pub fix_time: Option<Time>,
+    pub mode: u8,
+    /// Range residuals in meters, each slot may be blank
+    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
+    pub residuals: ArrayVec<Option<f32>, GRS_RESIDUALS_COUNT>,
+    /// GNSS System ID, only present since NMEA 4.1
+    pub system_id: Option<u8>,
+    /// GNSS Signal ID, only present since NMEA 4.1
+    pub signal_id: Option<u8>,
+}
+
+impl GrsData {
+    /// The GNSS system of the `system_id`, `None` if missing or reserved.
+    pub fn system(&self) -> Option<GnssSystem> {
+        self.system_id.and_then(GnssSystem::from_id)
+    }
+}
+
+fn do_parse_grs(i: &str) -> IResult<&str, GrsData> {
+    // 1. UTC time of the GGA or GNS fix associated with this sentence
+    let (i, fix_time) = opt(parse_hms)(i)?;
+    let (i, _) = char(',')(i)?;
+
+    // 2. Mode
+    let (mut i, mode) = one_of("01")(i)?;
+
+    // 3. - 14. Range residuals
+    let mut residuals = ArrayVec::new();
+    for _ in 0..GRS_RESIDUALS_COUNT {
+        let (rest, residual) = preceded(char(','), opt(float))(i)?;
+        residuals.push(residual);
+        i = rest;
+    }
+
+    // 15. GNSS System ID (NMEA 4.1+)
+    let (i, system_id) = opt(preceded(char(','), opt(parse_hex_id)))(i)?;
+
+    // 16. GNSS Signal ID (NMEA 4.1+)
+    let (i, signal_id) = opt(preceded(char(','), opt(parse_hex_id)))(i)?;
+
+    Ok((
+        i,
+        GrsData {
+            fix_time,
+            mode: if mode == '1' { 1 } else { 0 },
+            residuals,
+            system_id: system_id.flatten(),
+            signal_id: signal_id.flatten(),
+        },
+    ))
+}
+
+/// # Parse GRS message
+///
+/// See: <https://gpsd.gitlab.io/gpsd/NMEA.html#_grs_gps_range_residuals>
+pub fn parse_grs(sentence: NmeaSentence) -> Result<GrsData, Error> {
+    if sentence.message_id != SentenceType::GRS {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::GRS,
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_grs(sentence.data)?.1)
+    }
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use chrono::NaiveTime;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_grs() {
+        let s =
+            parse_nmea_sentence("$GPGRS,220320.0,0,-0.8,-0.2,-0.1,-0.2,0.8,0.6,,,,,,*79").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_grs(s).unwrap();
+
+        assert_eq!(
+            data.fix_time,
+            Some(NaiveTime::from_hms_opt(22, 3, 20).unwrap())
+        );
+        assert_eq!(data.mode, 0);
+        assert_eq!(data.residuals.len(), GRS_RESIDUALS_COUNT);
+        assert_relative_eq!(data.residuals[0].unwrap(), -0.8);
+        assert_relative_eq!(data.residuals[5].unwrap(), 0.6);
+        assert!(data.residuals[6..].iter().all(Option::is_none));
+        assert_eq!(data.system_id, None);
+        assert_eq!(data.signal_id, None);
+    }
+
+    #[test]
+    fn test_parse_grs_nmea_4_1() {
+        let s = parse_nmea_sentence(
+            "$GNGRS,104148.00,1,2.6,2.2,-1.6,-1.1,-1.7,-1.5,5.8,1.7,,,,,1,1*52",
+        )
+        .unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_grs(s).unwrap();
+
+        assert_eq!(data.mode, 1);
+        assert_relative_eq!(data.residuals[7].unwrap(), 1.7);
+        assert_eq!(data.residuals[8], None);
+        assert_eq!(data.system_id, Some(1));
+        assert_eq!(data.system(), Some(GnssSystem::Gps));
+        assert_eq!(data.signal_id, Some(1));
+    }
+
+    #[test]
+    fn test_parse_grs_invalid() {
+        // invalid mode
+        let s =
+            parse_nmea_sentence("$GPGRS,220320.0,2,-0.8,-0.2,-0.1,-0.2,0.8,0.6,,,,,,*7B").unwrap();
+        assert!(parse_grs(s).is_err());
+
+        // missing residual slots
+        let s = parse_nmea_sentence("$GPGRS,220320.0,0,-0.8,-0.2*74").unwrap();
+        assert!(parse_grs(s).is_err());
+    }
+}
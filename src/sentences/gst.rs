@@ -1,5 +1,8 @@
-use crate::{parse::NmeaSentence, sentences::utils::parse_hms, Error, SentenceType};
-use chrono::NaiveTime;
+use crate::{
+    parse::NmeaSentence,
+    sentences::utils::{parse_hms, Time},
+    Error, SentenceType,
+};
 use nom::{character::complete::char, combinator::opt, number::complete::float, IResult};
 
 #[cfg(feature = "serde")]
@@ -22,13 +25,13 @@ use serde::{Deserialize, Serialize};
 /// 7. Standard deviation (meters) of longitude error
 /// 8. Standard deviation (meters) of altitude error
 /// 9. Checksum
-
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[derive(Debug, PartialEq)]
 pub struct GstData {
     #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
-    pub time: Option<NaiveTime>,
+    pub time: Option<Time>,
     pub rms_sd: Option<f32>,
     pub ellipse_semi_major_sd: Option<f32>,
     pub ellipse_semi_minor_sd: Option<f32>,
@@ -87,14 +90,16 @@ pub fn parse_gst(sentence: NmeaSentence) -> Result<GstData, Error> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "chrono"))]
 mod tests {
+    use chrono::NaiveTime;
+
     use super::*;
     use crate::{parse::parse_nmea_sentence, Error};
 
-    fn run_parse_gst(line: &str) -> Result<GstData, Error> {
+    fn run_parse_gst(line: &str) -> Result<GstData, Error<'_>> {
         let s = parse_nmea_sentence(line).expect("GST sentence initial parse failed");
-        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
         parse_gst(s)
     }
 
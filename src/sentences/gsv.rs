@@ -24,6 +24,7 @@ use crate::{
 /// $--GSV,x,x,x,x,x,x,x,...*hh<CR><LF>
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[derive(Debug, PartialEq)]
 pub struct GsvData {
@@ -86,6 +87,37 @@ fn do_parse_gsv(i: &str) -> IResult<&str, GsvData> {
     ))
 }
 
+/// Number of fields preceding the satellites.
+const GSV_HEADER_FIELDS: usize = 3;
+/// Number of fields of a single satellite.
+const GSV_SATELLITE_FIELDS: usize = 4;
+/// Maximum number of fields: the header, 4 satellites and the signal ID.
+const GSV_MAX_FIELDS: usize = GSV_HEADER_FIELDS + 4 * GSV_SATELLITE_FIELDS + 1;
+
+fn check_gsv_fields(i: &str) -> Result<(), Error<'static>> {
+    let fields = i.split(',').count();
+    if fields > GSV_MAX_FIELDS {
+        return Err(Error::TooManyFields {
+            sentence: SentenceType::GSV,
+            max: GSV_MAX_FIELDS,
+        });
+    }
+
+    let expected = if fields < GSV_HEADER_FIELDS {
+        GSV_HEADER_FIELDS
+    } else {
+        // a single trailing field is the signal ID
+        match (fields - GSV_HEADER_FIELDS) % GSV_SATELLITE_FIELDS {
+            0 | 1 => return Ok(()),
+            partial => fields + GSV_SATELLITE_FIELDS - partial,
+        }
+    };
+    Err(Error::TooFewFields {
+        sentence: SentenceType::GSV,
+        expected,
+    })
+}
+
 /// # Parse one GSV message
 ///
 /// From gpsd/driver_nmea0183.c:
@@ -116,6 +148,10 @@ fn do_parse_gsv(i: &str) -> IResult<&str, GsvData> {
 /// GL may be (incorrectly) used when GSVs are mixed containing
 /// GLONASS, GN may be (incorrectly) used when GSVs contain GLONASS
 /// only.  Usage is inconsistent.
+///
+/// A sentence with more than the 4 satellites and the NMEA 4.10 signal ID
+/// results in an [`Error::TooManyFields`], a sentence cut off within the
+/// header or a satellite in an [`Error::TooFewFields`].
 pub fn parse_gsv(sentence: NmeaSentence) -> Result<GsvData, Error> {
     if sentence.message_id != SentenceType::GSV {
         Err(Error::WrongSentenceHeader {
@@ -132,6 +168,7 @@ pub fn parse_gsv(sentence: NmeaSentence) -> Result<GsvData, Error> {
             "PQ" | "QZ" => GnssType::Qzss,
             _ => return Err(Error::UnknownGnssType(sentence.talker_id)),
         };
+        check_gsv_fields(sentence.data)?;
         let mut res = do_parse_gsv(sentence.data)?.1;
         res.gnss_type = gnss_type;
         for sat in &mut res.sats_info {
@@ -146,6 +183,30 @@ pub fn parse_gsv(sentence: NmeaSentence) -> Result<GsvData, Error> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::sentences::GnssSystem;
+
+    #[test]
+    fn test_gsv_satellite_constellation() {
+        // GP is also used for the SBAS and QZSS satellites
+        let data = parse_gsv(NmeaSentence {
+            talker_id: "GP",
+            message_id: SentenceType::GSV,
+            data: "1,1,03,01,40,083,46,46,32,210,40,193,67,112,45",
+            checksum: None,
+            tag_block: None,
+        })
+        .unwrap();
+        let constellations = data
+            .sats_info
+            .iter()
+            .flatten()
+            .map(Satellite::constellation)
+            .collect::<Vec<_, 4>>();
+        assert_eq!(
+            constellations,
+            [Some(GnssSystem::Gps), None, Some(GnssSystem::Qzss)]
+        );
+    }
 
     #[test]
     fn test_parse_gsv_full() {
@@ -153,7 +214,8 @@ mod tests {
             talker_id: "GP",
             message_id: SentenceType::GSV,
             data: "2,1,08,01,,083,46,02,17,308,,12,07,344,39,14,22,228,",
-            checksum: 0,
+            checksum: Some(0),
+            tag_block: None,
         })
         .unwrap();
         assert_eq!(data.gnss_type, GnssType::Gps);
@@ -205,7 +267,8 @@ mod tests {
             talker_id: "GL",
             message_id: SentenceType::GSV,
             data: "3,3,10,72,40,075,43,87,00,000,",
-            checksum: 0,
+            checksum: Some(0),
+            tag_block: None,
         })
         .unwrap();
         assert_eq!(data.gnss_type, GnssType::Glonass);
@@ -213,4 +276,42 @@ mod tests {
         assert_eq!(data.sentence_num, 3);
         assert_eq!(data.sats_in_view, 10);
     }
+
+    #[test]
+    fn test_parse_gsv_field_count() {
+        let gsv = |data| {
+            parse_gsv(NmeaSentence {
+                talker_id: "GP",
+                message_id: SentenceType::GSV,
+                data,
+                checksum: None,
+                tag_block: None,
+            })
+        };
+
+        // NMEA 4.10 signal ID
+        assert!(gsv("1,1,01,01,40,083,46,1").is_ok());
+
+        assert_eq!(
+            gsv("1,1"),
+            Err(Error::TooFewFields {
+                sentence: SentenceType::GSV,
+                expected: 3
+            })
+        );
+        assert_eq!(
+            gsv("1,1,02,01,40,083,46,02,17"),
+            Err(Error::TooFewFields {
+                sentence: SentenceType::GSV,
+                expected: 11
+            })
+        );
+        assert_eq!(
+            gsv("2,1,08,01,40,083,46,02,17,308,41,12,07,344,39,14,22,228,45,1,33"),
+            Err(Error::TooManyFields {
+                sentence: SentenceType::GSV,
+                max: 20
+            })
+        );
+    }
 }
@@ -0,0 +1,134 @@
+use arrayvec::ArrayVec;
+use nom::{
+    character::complete::char,
+    combinator::{eof, opt},
+    number::complete::float,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{parse::NmeaSentence, Error, SentenceType};
+
+/// Maximum number of time differences in a single GTD sentence.
+pub const GTD_MAX_TIME_DIFFERENCES: usize = 5;
+
+/// GTD - Geographic Location in Time Differences
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_gtd_geographic_location_in_time_differences>
+///
+/// ```text
+///        1   2   3   4   5   6
+///        |   |   |   |   |   |
+/// $--GTD,x.x,x.x,x.x,x.x,x.x*hh<CR><LF>
+/// ```
+/// 1. - 5. Time differences, microseconds
+/// 6. Checksum
+///
+/// Each time difference present in the sentence is kept in
+/// `time_differences`, including empty ones, so the index matches the field.
+/// More than [`GTD_MAX_TIME_DIFFERENCES`] fields result in an
+/// [`Error::ParameterLength`].
+///
+/// Example:
+/// ```text
+/// $GPGTD,15234.5,42123.8,57123.9,,*43
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GtdData {
+    /// Loran-C or Decca time differences, microseconds
+    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
+    pub time_differences: ArrayVec<Option<f32>, GTD_MAX_TIME_DIFFERENCES>,
+}
+
+/// # Parse GTD message
+///
+/// See: <https://gpsd.gitlab.io/gpsd/NMEA.html#_gtd_geographic_location_in_time_differences>
+pub fn parse_gtd(sentence: NmeaSentence) -> Result<GtdData, Error> {
+    if sentence.message_id != SentenceType::GTD {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::GTD,
+            found: sentence.message_id,
+        })
+    } else {
+        do_parse_gtd(sentence.data)
+    }
+}
+
+fn do_parse_gtd(i: &str) -> Result<GtdData, Error<'_>> {
+    let (mut i, first) = opt(float)(i)?;
+    let mut time_differences = ArrayVec::new();
+    time_differences.push(first);
+
+    while let (rest, Some(_)) = opt(char(','))(i)? {
+        let (rest, time_difference) = opt(float)(rest)?;
+        if time_differences.is_full() {
+            return Err(Error::ParameterLength {
+                max_length: GTD_MAX_TIME_DIFFERENCES,
+                parameter_length: GTD_MAX_TIME_DIFFERENCES + rest.split(',').count(),
+            });
+        }
+        time_differences.push(time_difference);
+        i = rest;
+    }
+    eof(i)?;
+
+    Ok(GtdData { time_differences })
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_gtd() {
+        let s = parse_nmea_sentence("$GPGTD,15234.5,42123.8,57123.9,,*43").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_gtd(s).unwrap();
+
+        assert_eq!(data.time_differences.len(), GTD_MAX_TIME_DIFFERENCES);
+        assert_relative_eq!(data.time_differences[0].unwrap(), 15234.5);
+        assert_relative_eq!(data.time_differences[1].unwrap(), 42123.8);
+        assert_relative_eq!(data.time_differences[2].unwrap(), 57123.9);
+        assert_eq!(data.time_differences[3], None);
+        assert_eq!(data.time_differences[4], None);
+    }
+
+    #[test]
+    fn test_parse_gtd_variable_count() {
+        let s = parse_nmea_sentence("$GPGTD,15234.5*46").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(
+            parse_gtd(s).unwrap().time_differences.as_slice(),
+            &[Some(15234.5)]
+        );
+
+        let s = parse_nmea_sentence("$GPGTD,,,,,*6C").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(
+            parse_gtd(s).unwrap().time_differences.as_slice(),
+            &[None; 5]
+        );
+    }
+
+    #[test]
+    fn test_parse_gtd_invalid() {
+        let s = parse_nmea_sentence("$GPGTD,1,X,3*36").unwrap();
+        assert!(parse_gtd(s).is_err());
+
+        let s = parse_nmea_sentence("$GPGTD,1,2,3,4,5,6*47").unwrap();
+        assert_eq!(
+            parse_gtd(s),
+            Err(Error::ParameterLength {
+                max_length: GTD_MAX_TIME_DIFFERENCES,
+                parameter_length: 6
+            })
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use nom::{
+    character::complete::char,
+    combinator::{eof, opt},
+    number::complete::float,
+    IResult,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::utils::{number, parse_valid_status};
+use crate::{parse::NmeaSentence, Error, SentenceType};
+
+/// HBT - Heartbeat Supervision
+///
+/// ```text
+///        1   2 3 4
+///        |   | | |
+/// $--HBT,x.x,A,x*hh<CR><LF>
+/// ```
+/// 1. Configured repeat interval, seconds
+/// 2. Equipment status, A = normal, V = abnormal
+/// 3. Sequence number, 0 - 9
+/// 4. Checksum
+///
+/// The sequence number is incremented with every sentence, so a missing
+/// increment indicates a lost heartbeat.
+///
+/// Example:
+/// ```text
+/// $GPHBT,30.0,A,7*0E
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HbtData {
+    /// Repeat interval, seconds
+    pub interval: Option<f32>,
+    /// `true` if the equipment is in normal operation
+    pub status: bool,
+    /// Sequence number, 0 - 9
+    pub sequence_number: Option<u8>,
+}
+
+/// # Parse HBT message
+pub fn parse_hbt(sentence: NmeaSentence) -> Result<HbtData, Error> {
+    if sentence.message_id != SentenceType::HBT {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::HBT,
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_hbt(sentence.data)?.1)
+    }
+}
+
+fn do_parse_hbt(i: &str) -> IResult<&str, HbtData> {
+    // 1. Repeat interval
+    let (i, interval) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. Equipment status
+    let (i, status) = parse_valid_status(i)?;
+    let (i, _) = char(',')(i)?;
+    // 3. Sequence number
+    let (i, sequence_number) = opt(number::<u8>)(i)?;
+    let (i, _) = eof(i)?;
+
+    Ok((
+        i,
+        HbtData {
+            interval,
+            status,
+            sequence_number,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_hbt() {
+        let s = parse_nmea_sentence("$GPHBT,30.0,A,7*0E").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_hbt(s).unwrap();
+        assert_relative_eq!(data.interval.unwrap(), 30.0);
+        assert!(data.status);
+        assert_eq!(data.sequence_number, Some(7));
+    }
+
+    #[test]
+    fn test_parse_hbt_empty_fields() {
+        let s = parse_nmea_sentence("$GPHBT,,V,*33").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(
+            parse_hbt(s),
+            Ok(HbtData {
+                interval: None,
+                status: false,
+                sequence_number: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_hbt_invalid_status() {
+        let s = parse_nmea_sentence("$GPHBT,30.0,X,7*17").unwrap();
+        assert!(parse_hbt(s).is_err());
+    }
+}
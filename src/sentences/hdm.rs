@@ -0,0 +1,87 @@
+use nom::{
+    bytes::complete::take_until,
+    character::complete::char,
+    combinator::{map_res, opt},
+    IResult,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::utils::parse_float_num;
+use crate::{Error, NmeaSentence, SentenceType};
+
+/// HDM - Heading - Magnetic
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_hdm_heading_magnetic>
+///
+/// ```text
+///        1   2 3
+///        |   | |
+/// $--HDM,x.x,M*hh<CR><LF>
+/// ```
+/// 1. Heading, degrees Magnetic
+/// 2. M = Magnetic
+/// 3. Checksum
+///
+/// Example:
+/// ```text
+/// $HCHDM,172.5,M*28
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HdmData {
+    /// Heading, degrees Magnetic
+    pub heading: Option<f32>,
+}
+
+/// # Parse HDM message
+///
+/// The only data field is the magnetic heading in degrees.
+/// The following field is required to be 'M' indicating a magnetic heading.
+pub fn parse_hdm(sentence: NmeaSentence) -> Result<HdmData, Error> {
+    if sentence.message_id != SentenceType::HDM {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::HDM,
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_hdm(sentence.data)?.1)
+    }
+}
+
+fn do_parse_hdm(i: &str) -> IResult<&str, HdmData> {
+    let (i, heading) = opt(map_res(take_until(","), parse_float_num::<f32>))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = char('M')(i)?;
+    Ok((i, HdmData { heading }))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_hdm() {
+        let s = parse_nmea_sentence("$HCHDM,172.5,M*28").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_hdm(s).unwrap();
+        assert_relative_eq!(data.heading.unwrap(), 172.5);
+
+        let s = parse_nmea_sentence("$HCHDM,,M*07").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(parse_hdm(s), Ok(HdmData { heading: None }));
+    }
+
+    #[test]
+    fn test_parse_hdm_invalid_unit() {
+        let s = parse_nmea_sentence("$HCHDM,172.5,T*31").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert!(parse_hdm(s).is_err());
+    }
+}
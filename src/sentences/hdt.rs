@@ -24,6 +24,7 @@ use crate::{Error, NmeaSentence, SentenceType};
 /// 2. T = True
 /// 3. Checksum
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[derive(Debug, PartialEq)]
 pub struct HdtData {
@@ -75,15 +76,29 @@ mod tests {
             talker_id: "GP",
             message_id: SentenceType::HDT,
             data: "274.07,T",
-            checksum: 0x03,
+            checksum: Some(0x03),
+            tag_block: None,
         })
         .unwrap();
         assert_relative_eq!(data.heading.unwrap(), 274.07);
 
         let s = parse_nmea_sentence("$GPHDT,,T*1B").unwrap();
-        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
 
         let data = parse_hdt(s);
         assert_eq!(data, Ok(HdtData { heading: None }));
     }
+
+    #[test]
+    fn test_parse_hdt_without_checksum() {
+        let s = parse_nmea_sentence("$GPHDT,123.4,T").unwrap();
+        assert_eq!(s.checksum, None);
+        assert!(!s.rechecksum());
+
+        let data = parse_hdt(s).unwrap();
+        assert_relative_eq!(data.heading.unwrap(), 123.4);
+
+        // a malformed checksum is still an error
+        assert!(parse_nmea_sentence("$GPHDT,123.4,T*1").is_err());
+    }
 }
@@ -0,0 +1,129 @@
+use nom::{
+    character::complete::{anychar, char},
+    combinator::{map_res, opt, verify},
+    number::complete::float,
+    IResult,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{sentences::Reference, Error, NmeaSentence, SentenceType};
+
+/// HSC - Heading Steering Command
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_hsc_heading_steering_command>
+///
+/// ```text
+///        1   2 3   4 5
+///        |   | |   | |
+/// $--HSC,x.x,T,x.x,M*hh<CR><LF>
+/// ```
+/// 1. Heading, degrees True
+/// 2. T = True
+/// 3. Heading, degrees Magnetic
+/// 4. M = Magnetic
+/// 5. Checksum
+///
+/// Example:
+/// ```text
+/// $FTHSC,40.12,T,39.11,M*5E
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HscData {
+    /// Commanded heading, degrees True
+    pub heading_true: Option<f32>,
+    /// Commanded heading, degrees Magnetic
+    pub heading_magnetic: Option<f32>,
+}
+
+/// # Parse HSC message
+///
+/// The reference letters have to be `T` and `M` respectively when present.
+pub fn parse_hsc(sentence: NmeaSentence) -> Result<HscData, Error> {
+    if sentence.message_id != SentenceType::HSC {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::HSC,
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_hsc(sentence.data)?.1)
+    }
+}
+
+/// Parses an optional reference letter, which has to match `expected` if present.
+///
+/// VDR uses the same references and shares this parser.
+pub(crate) fn reference(expected: Reference) -> impl Fn(&str) -> IResult<&str, Option<Reference>> {
+    move |i| {
+        opt(verify(map_res(anychar, Reference::try_from), |r| {
+            *r == expected
+        }))(i)
+    }
+}
+
+fn do_parse_hsc(i: &str) -> IResult<&str, HscData> {
+    let (i, heading_true) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = reference(Reference::True)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, heading_magnetic) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = reference(Reference::Magnetic)(i)?;
+
+    Ok((
+        i,
+        HscData {
+            heading_true,
+            heading_magnetic,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_hsc() {
+        let s = parse_nmea_sentence("$FTHSC,40.12,T,39.11,M*5E").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_hsc(s).unwrap();
+        assert_relative_eq!(data.heading_true.unwrap(), 40.12);
+        assert_relative_eq!(data.heading_magnetic.unwrap(), 39.11);
+    }
+
+    #[test]
+    fn test_parse_hsc_empty_fields() {
+        let s = parse_nmea_sentence("$FTHSC,,,,*4A").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(
+            parse_hsc(s),
+            Ok(HscData {
+                heading_true: None,
+                heading_magnetic: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_hsc_wrong_reference() {
+        let s = parse_nmea_sentence("$FTHSC,40.12,M,39.11,M*47").unwrap();
+        assert!(matches!(parse_hsc(s), Err(Error::ParsingError(_))));
+    }
+
+    #[test]
+    fn test_parse_hsc_wrong_sentence() {
+        let s = parse_nmea_sentence("$GPHDT,274.07,T*03").unwrap();
+        assert!(matches!(
+            parse_hsc(s),
+            Err(Error::WrongSentenceHeader { .. })
+        ));
+    }
+}
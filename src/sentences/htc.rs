@@ -0,0 +1,259 @@
+use nom::{
+    character::complete::{anychar, char},
+    combinator::{eof, map_res, opt},
+    number::complete::float,
+    IResult,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    sentences::{utils::parse_valid_status, Reference, SteerDirection},
+    Error, NmeaSentence, SentenceType,
+};
+
+/// Steering mode selected on a heading/track controller
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HtcSteeringMode {
+    /// `M` - manual steering
+    Manual,
+    /// `S` - stand-alone heading control
+    StandAlone,
+    /// `H` - heading control with an external heading source
+    HeadingControl,
+    /// `T` - track control
+    TrackControl,
+    /// `R` - direct rudder control
+    RudderControl,
+}
+
+impl TryFrom<char> for HtcSteeringMode {
+    type Error = Error<'static>;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'M' => Ok(HtcSteeringMode::Manual),
+            'S' => Ok(HtcSteeringMode::StandAlone),
+            'H' => Ok(HtcSteeringMode::HeadingControl),
+            'T' => Ok(HtcSteeringMode::TrackControl),
+            'R' => Ok(HtcSteeringMode::RudderControl),
+            _ => Err(Error::InvalidIndicator(c)),
+        }
+    }
+}
+
+/// How the turns of a heading/track controller are controlled
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HtcTurnMode {
+    /// `R` - radius controlled
+    Radius,
+    /// `T` - turn rate controlled
+    TurnRate,
+    /// `N` - turn not controlled
+    NotControlled,
+}
+
+impl TryFrom<char> for HtcTurnMode {
+    type Error = Error<'static>;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'R' => Ok(HtcTurnMode::Radius),
+            'T' => Ok(HtcTurnMode::TurnRate),
+            'N' => Ok(HtcTurnMode::NotControlled),
+            _ => Err(Error::InvalidIndicator(c)),
+        }
+    }
+}
+
+/// HTC - Heading/Track Control Command
+///
+/// ```text
+///        1 2   3 4 5 6   7   8   9   10  11  12  13
+///        | |   | | | |   |   |   |   |   |   |   |
+/// $--HTC,A,x.x,a,a,a,x.x,x.x,x.x,x.x,x.x,x.x,x.x,a*hh<CR><LF>
+/// ```
+/// 1. Override, A = in use, V = not in use
+/// 2. Commanded rudder angle, degrees
+/// 3. Commanded rudder direction, L = port, R = starboard
+/// 4. Selected steering mode
+/// 5. Turn mode
+/// 6. Commanded rudder limit, degrees
+/// 7. Commanded off-heading limit, degrees
+/// 8. Commanded radius of turn for heading changes, nautical miles
+/// 9. Commanded rate of turn for heading changes, degrees/minute
+/// 10. Commanded heading to steer, degrees
+/// 11. Commanded off-track limit, nautical miles
+/// 12. Commanded track, degrees
+/// 13. Heading reference in use, T = true, M = magnetic
+///
+/// The steering mode is one of `M` = manual, `S` = stand-alone heading
+/// control, `H` = heading control with an external source, `T` = track
+/// control and `R` = direct rudder control. The turn mode is one of
+/// `R` = radius controlled, `T` = turn rate controlled and `N` = turn not
+/// controlled.
+///
+/// Example:
+/// ```text
+/// $IIHTC,V,10.0,L,H,R,35.0,10.0,0.5,20.0,275.0,0.1,270.0,T*22
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HtcData {
+    /// Whether the override is in use
+    pub override_active: Option<bool>,
+    /// Commanded rudder angle, degrees
+    pub commanded_rudder_angle: Option<f32>,
+    pub commanded_rudder_direction: Option<SteerDirection>,
+    pub steering_mode: Option<HtcSteeringMode>,
+    pub turn_mode: Option<HtcTurnMode>,
+    /// Commanded rudder limit, degrees
+    pub rudder_limit: Option<f32>,
+    /// Commanded off-heading limit, degrees
+    pub off_heading_limit: Option<f32>,
+    /// Commanded radius of turn, nautical miles
+    pub turn_radius: Option<f32>,
+    /// Commanded rate of turn, degrees/minute
+    pub turn_rate: Option<f32>,
+    /// Commanded heading to steer, degrees
+    pub commanded_heading: Option<f32>,
+    /// Commanded off-track limit, nautical miles
+    pub off_track_limit: Option<f32>,
+    /// Commanded track, degrees
+    pub commanded_track: Option<f32>,
+    pub heading_reference: Option<Reference>,
+}
+
+/// # Parse HTC message
+///
+/// Parses the 13 fields of a HTC sentence.
+pub fn parse_htc(sentence: NmeaSentence) -> Result<HtcData, Error> {
+    if sentence.message_id != SentenceType::HTC {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::HTC,
+            found: sentence.message_id,
+        })
+    } else {
+        let (i, data) = do_parse_htc(sentence.data)?;
+        eof(i)?;
+        Ok(data)
+    }
+}
+
+/// Parses the fields of a HTC sentence, leaving any following fields.
+///
+/// HTD starts with the exact same fields and is parsed by this function too.
+pub(crate) fn do_parse_htc(i: &str) -> IResult<&str, HtcData> {
+    // 1. Override
+    let (i, override_active) = opt(parse_valid_status)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. Commanded rudder angle
+    let (i, commanded_rudder_angle) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 3. Commanded rudder direction
+    let (i, commanded_rudder_direction) = opt(map_res(anychar, SteerDirection::try_from))(i)?;
+    let (i, _) = char(',')(i)?;
+    // 4. Selected steering mode
+    let (i, steering_mode) = opt(map_res(anychar, HtcSteeringMode::try_from))(i)?;
+    let (i, _) = char(',')(i)?;
+    // 5. Turn mode
+    let (i, turn_mode) = opt(map_res(anychar, HtcTurnMode::try_from))(i)?;
+    let (i, _) = char(',')(i)?;
+    // 6. Commanded rudder limit
+    let (i, rudder_limit) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 7. Commanded off-heading limit
+    let (i, off_heading_limit) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 8. Commanded radius of turn
+    let (i, turn_radius) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 9. Commanded rate of turn
+    let (i, turn_rate) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 10. Commanded heading to steer
+    let (i, commanded_heading) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 11. Commanded off-track limit
+    let (i, off_track_limit) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 12. Commanded track
+    let (i, commanded_track) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 13. Heading reference in use
+    let (i, heading_reference) = opt(map_res(anychar, Reference::try_from))(i)?;
+
+    Ok((
+        i,
+        HtcData {
+            override_active,
+            commanded_rudder_angle,
+            commanded_rudder_direction,
+            steering_mode,
+            turn_mode,
+            rudder_limit,
+            off_heading_limit,
+            turn_radius,
+            turn_rate,
+            commanded_heading,
+            off_track_limit,
+            commanded_track,
+            heading_reference,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_htc() {
+        let s = parse_nmea_sentence("$IIHTC,V,10.0,L,H,R,35.0,10.0,0.5,20.0,275.0,0.1,270.0,T*22")
+            .unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let htc = parse_htc(s).unwrap();
+
+        assert_eq!(htc.override_active, Some(false));
+        assert_relative_eq!(htc.commanded_rudder_angle.unwrap(), 10.0);
+        assert_eq!(htc.commanded_rudder_direction, Some(SteerDirection::Left));
+        assert_eq!(htc.steering_mode, Some(HtcSteeringMode::HeadingControl));
+        assert_eq!(htc.turn_mode, Some(HtcTurnMode::Radius));
+        assert_relative_eq!(htc.rudder_limit.unwrap(), 35.0);
+        assert_relative_eq!(htc.off_heading_limit.unwrap(), 10.0);
+        assert_relative_eq!(htc.turn_radius.unwrap(), 0.5);
+        assert_relative_eq!(htc.turn_rate.unwrap(), 20.0);
+        assert_relative_eq!(htc.commanded_heading.unwrap(), 275.0);
+        assert_relative_eq!(htc.off_track_limit.unwrap(), 0.1);
+        assert_relative_eq!(htc.commanded_track.unwrap(), 270.0);
+        assert_eq!(htc.heading_reference, Some(Reference::True));
+    }
+
+    #[test]
+    fn test_parse_htc_empty_fields() {
+        let s = parse_nmea_sentence("$IIHTC,,,,,,,,,,,,,*73").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let htc = parse_htc(s).unwrap();
+        assert_eq!(htc.override_active, None);
+        assert_eq!(htc.steering_mode, None);
+        assert_eq!(htc.commanded_heading, None);
+        assert_eq!(htc.heading_reference, None);
+    }
+
+    #[test]
+    fn test_parse_htc_invalid_steering_mode() {
+        let s = parse_nmea_sentence("$IIHTC,V,10.0,L,X,R,35.0,10.0,0.5,20.0,275.0,0.1,270.0,T*32")
+            .unwrap();
+        assert!(matches!(parse_htc(s), Err(Error::ParsingError(_))));
+    }
+}
@@ -0,0 +1,173 @@
+use nom::{
+    character::complete::char,
+    combinator::{eof, opt},
+    number::complete::float,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    sentences::{
+        htc::do_parse_htc, utils::parse_valid_status, HtcData, HtcSteeringMode, HtcTurnMode,
+        Reference, SteerDirection,
+    },
+    Error, NmeaSentence, SentenceType,
+};
+
+/// HTD - Heading/Track Control Data
+///
+/// ```text
+///        1 2   3 4 5 6   7   8   9   10  11  12  13 14 15 16 17
+///        | |   | | | |   |   |   |   |   |   |   |  |  |  |  |
+/// $--HTD,A,x.x,a,a,a,x.x,x.x,x.x,x.x,x.x,x.x,x.x,a,A,A,A,x.x*hh<CR><LF>
+/// ```
+/// 1. - 13. The same fields as in [`HtcData`]
+/// 14. Rudder status, A = within limits, V = limit reached or exceeded
+/// 15. Off-heading status, A = within limits, V = limit reached or exceeded
+/// 16. Off-track status, A = within limits, V = limit reached or exceeded
+/// 17. Vessel heading, degrees
+///
+/// Reports the settings of a heading/track controller together with the
+/// state of the vessel.
+///
+/// Example:
+/// ```text
+/// $IIHTD,V,10.0,L,H,R,35.0,10.0,0.5,20.0,275.0,0.1,270.0,T,A,A,V,274.5*59
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HtdData {
+    /// Whether the override is in use
+    pub override_active: Option<bool>,
+    /// Commanded rudder angle, degrees
+    pub commanded_rudder_angle: Option<f32>,
+    pub commanded_rudder_direction: Option<SteerDirection>,
+    pub steering_mode: Option<HtcSteeringMode>,
+    pub turn_mode: Option<HtcTurnMode>,
+    /// Commanded rudder limit, degrees
+    pub rudder_limit: Option<f32>,
+    /// Commanded off-heading limit, degrees
+    pub off_heading_limit: Option<f32>,
+    /// Commanded radius of turn, nautical miles
+    pub turn_radius: Option<f32>,
+    /// Commanded rate of turn, degrees/minute
+    pub turn_rate: Option<f32>,
+    /// Commanded heading to steer, degrees
+    pub commanded_heading: Option<f32>,
+    /// Commanded off-track limit, nautical miles
+    pub off_track_limit: Option<f32>,
+    /// Commanded track, degrees
+    pub commanded_track: Option<f32>,
+    pub heading_reference: Option<Reference>,
+    /// Whether the rudder is within its limit
+    pub rudder_status: Option<bool>,
+    /// Whether the vessel is within the off-heading limit
+    pub off_heading_status: Option<bool>,
+    /// Whether the vessel is within the off-track limit
+    pub off_track_status: Option<bool>,
+    /// Vessel heading, degrees
+    pub heading: Option<f32>,
+}
+
+/// # Parse HTD message
+///
+/// The first 13 fields are parsed like the ones of a HTC sentence.
+pub fn parse_htd(sentence: NmeaSentence) -> Result<HtdData, Error> {
+    if sentence.message_id != SentenceType::HTD {
+        return Err(Error::WrongSentenceHeader {
+            expected: SentenceType::HTD,
+            found: sentence.message_id,
+        });
+    }
+
+    let (
+        i,
+        HtcData {
+            override_active,
+            commanded_rudder_angle,
+            commanded_rudder_direction,
+            steering_mode,
+            turn_mode,
+            rudder_limit,
+            off_heading_limit,
+            turn_radius,
+            turn_rate,
+            commanded_heading,
+            off_track_limit,
+            commanded_track,
+            heading_reference,
+        },
+    ) = do_parse_htc(sentence.data)?;
+    let (i, _) = char(',')(i)?;
+    // 14. Rudder status
+    let (i, rudder_status) = opt(parse_valid_status)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 15. Off-heading status
+    let (i, off_heading_status) = opt(parse_valid_status)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 16. Off-track status
+    let (i, off_track_status) = opt(parse_valid_status)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 17. Vessel heading
+    let (i, heading) = opt(float)(i)?;
+    eof(i)?;
+
+    Ok(HtdData {
+        override_active,
+        commanded_rudder_angle,
+        commanded_rudder_direction,
+        steering_mode,
+        turn_mode,
+        rudder_limit,
+        off_heading_limit,
+        turn_radius,
+        turn_rate,
+        commanded_heading,
+        off_track_limit,
+        commanded_track,
+        heading_reference,
+        rudder_status,
+        off_heading_status,
+        off_track_status,
+        heading,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_htd() {
+        let s = parse_nmea_sentence(
+            "$IIHTD,V,10.0,L,H,R,35.0,10.0,0.5,20.0,275.0,0.1,270.0,T,A,A,V,274.5*59",
+        )
+        .unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let htd = parse_htd(s).unwrap();
+
+        assert_eq!(htd.override_active, Some(false));
+        assert_eq!(htd.steering_mode, Some(HtcSteeringMode::HeadingControl));
+        assert_relative_eq!(htd.commanded_heading.unwrap(), 275.0);
+        assert_eq!(htd.heading_reference, Some(Reference::True));
+        assert_eq!(htd.rudder_status, Some(true));
+        assert_eq!(htd.off_heading_status, Some(true));
+        assert_eq!(htd.off_track_status, Some(false));
+        assert_relative_eq!(htd.heading.unwrap(), 274.5);
+    }
+
+    #[test]
+    fn test_parse_htd_without_status_fields() {
+        // a HTC sentence sent as HTD
+        let s = parse_nmea_sentence("$IIHTD,V,10.0,L,H,R,35.0,10.0,0.5,20.0,275.0,0.1,270.0,T*25")
+            .unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert!(matches!(parse_htd(s), Err(Error::ParsingError(_))));
+    }
+}
@@ -3,9 +3,13 @@ use nom::{character::complete::char, combinator::opt, number::complete::float, I
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::{parse::NmeaSentence, Error, SentenceType};
+use crate::{
+    parse::NmeaSentence,
+    sentences::utils::{knots_to_mps, mps_to_knots},
+    Error, SentenceType,
+};
 
-/// MDA - Meterological Composite
+/// MDA - Meteorological Composite
 ///
 /// <https://gpsd.gitlab.io/gpsd/NMEA.html#_mda_meteorological_composite>
 ///
@@ -15,6 +19,7 @@ use crate::{parse::NmeaSentence, Error, SentenceType};
 ///  $--MDA,n.nn,I,n.nnn,B,n.n,C,n.C,n.n,n,n.n,C,n.n,T,n.n,M,n.n,N,n.n,M*hh<CR><LF>
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[derive(Debug, PartialEq)]
 pub struct MdaData {
@@ -42,6 +47,20 @@ pub struct MdaData {
     pub wind_speed_ms: Option<f32>,
 }
 
+impl MdaData {
+    /// Wind speed in knots, converted from meters/second if only that is present.
+    pub fn wind_speed_in_knots(&self) -> Option<f32> {
+        self.wind_speed_knots
+            .or_else(|| self.wind_speed_ms.map(mps_to_knots))
+    }
+
+    /// Wind speed in meters/second, converted from knots if only that is present.
+    pub fn wind_speed_in_mps(&self) -> Option<f32> {
+        self.wind_speed_ms
+            .or_else(|| self.wind_speed_knots.map(knots_to_mps))
+    }
+}
+
 /// # Parse MDA message
 ///
 /// Information from mda:
@@ -76,7 +95,8 @@ pub struct MdaData {
 /// 19: 0.6        Wind speed meters/second
 /// 20: M
 /// 21: *16        Mandatory NMEA checksum
-
+///
+/// The unit letters are validated, a sentence with a wrong unit letter is rejected.
 pub fn parse_mda(sentence: NmeaSentence) -> Result<MdaData, Error> {
     if sentence.message_id != SentenceType::MDA {
         Err(Error::WrongSentenceHeader {
@@ -161,8 +181,8 @@ mod tests {
             "$WIMDA,29.7544,I,1.0076,B,35.5,C,,,42.1,,20.6,C,116.4,T,107.7,M,1.2,N,0.6,M*66",
         )
         .unwrap();
-        assert_eq!(s.checksum, s.calc_checksum());
-        assert_eq!(s.checksum, 0x66);
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(s.checksum, Some(0x66));
         let mda_data = parse_mda(s).unwrap();
         assert_relative_eq!(29.7544, mda_data.pressure_in_hg.unwrap());
         assert_relative_eq!(1.0076, mda_data.pressure_bar.unwrap());
@@ -176,4 +196,37 @@ mod tests {
         assert_relative_eq!(1.2, mda_data.wind_speed_knots.unwrap());
         assert_relative_eq!(0.6, mda_data.wind_speed_ms.unwrap());
     }
+
+    #[test]
+    fn test_parse_mda_invalid_unit() {
+        let s = parse_nmea_sentence(
+            "$WIMDA,29.7544,X,1.0076,B,35.5,C,,,42.1,,20.6,C,116.4,T,107.7,M,1.2,N,0.6,M*77",
+        )
+        .unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert!(parse_mda(s).is_err());
+    }
+
+    #[test]
+    fn test_mda_wind_speed_conversions() {
+        let s = parse_nmea_sentence("$WIMDA,,,,,,,,,,,,,,,,,1.2,N,,*35").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let mda_data = parse_mda(s).unwrap();
+        assert_relative_eq!(mda_data.wind_speed_in_knots().unwrap(), 1.2);
+        assert_relative_eq!(
+            mda_data.wind_speed_in_mps().unwrap(),
+            0.617_333,
+            epsilon = 1e-6
+        );
+
+        let s = parse_nmea_sentence("$WIMDA,,,,,,,,,,,,,,,,,,,0.6,M*33").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let mda_data = parse_mda(s).unwrap();
+        assert_relative_eq!(mda_data.wind_speed_in_mps().unwrap(), 0.6);
+        assert_relative_eq!(
+            mda_data.wind_speed_in_knots().unwrap(),
+            1.166_307,
+            epsilon = 1e-5
+        );
+    }
 }
@@ -0,0 +1,116 @@
+use nom::{
+    character::complete::char,
+    combinator::{eof, opt},
+    number::complete::float,
+    IResult,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{parse::NmeaSentence, Error, SentenceType};
+
+/// MHU - Humidity
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_mhu_humidity>
+///
+/// ```text
+///        1   2   3   4 5
+///        |   |   |   | |
+/// $--MHU,x.x,x.x,x.x,C*hh<CR><LF>
+/// ```
+/// 1. Relative humidity, percent
+/// 2. Absolute humidity, percent
+/// 3. Dew point, degrees
+/// 4. C = degrees Celsius
+/// 5. Checksum
+///
+/// Example:
+/// ```text
+/// $IIMHU,42.1,30.6,20.6,C*0B
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MhuData {
+    /// Relative humidity, percent
+    pub relative_humidity: Option<f32>,
+    /// Absolute humidity, percent
+    pub absolute_humidity: Option<f32>,
+    /// Dew point, degrees Celsius
+    pub dew_point: Option<f32>,
+}
+
+/// # Parse MHU message
+///
+/// The dew point unit has to be `C`, a sentence with another unit is rejected.
+pub fn parse_mhu(sentence: NmeaSentence) -> Result<MhuData, Error> {
+    if sentence.message_id != SentenceType::MHU {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::MHU,
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_mhu(sentence.data)?.1)
+    }
+}
+
+fn do_parse_mhu(i: &str) -> IResult<&str, MhuData> {
+    let (i, relative_humidity) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, absolute_humidity) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, dew_point) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('C'))(i)?;
+    let (i, _) = eof(i)?;
+
+    Ok((
+        i,
+        MhuData {
+            relative_humidity,
+            absolute_humidity,
+            dew_point,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_mhu() {
+        let s = parse_nmea_sentence("$IIMHU,42.1,30.6,20.6,C*0B").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_mhu(s).unwrap();
+        assert_relative_eq!(data.relative_humidity.unwrap(), 42.1);
+        assert_relative_eq!(data.absolute_humidity.unwrap(), 30.6);
+        assert_relative_eq!(data.dew_point.unwrap(), 20.6);
+    }
+
+    #[test]
+    fn test_parse_mhu_empty_fields() {
+        let s = parse_nmea_sentence("$IIMHU,,,,C*13").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(
+            parse_mhu(s),
+            Ok(MhuData {
+                relative_humidity: None,
+                absolute_humidity: None,
+                dew_point: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_mhu_invalid_unit() {
+        let s = parse_nmea_sentence("$IIMHU,42.1,30.6,20.6,F*0E").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert!(parse_mhu(s).is_err());
+    }
+}
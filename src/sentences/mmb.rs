@@ -0,0 +1,140 @@
+use nom::{
+    character::complete::char,
+    combinator::{eof, opt},
+    number::complete::float,
+    IResult,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{parse::NmeaSentence, Error, SentenceType};
+
+/// Hectopascals in an inch of mercury
+const HPA_PER_INCH_OF_MERCURY: f32 = 33.863_89;
+
+/// Hectopascals in a bar
+const HPA_PER_BAR: f32 = 1000.0;
+
+/// MMB - Barometer
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_mmb_barometer>
+///
+/// ```text
+///        1   2 3   4 5
+///        |   | |   | |
+/// $--MMB,x.x,I,x.x,B*hh<CR><LF>
+/// ```
+/// 1. Barometric pressure, inches of mercury
+/// 2. I = inches of mercury
+/// 3. Barometric pressure, bars
+/// 4. B = bars
+/// 5. Checksum
+///
+/// Example:
+/// ```text
+/// $IIMMB,29.9870,I,1.0154,B*75
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MmbData {
+    /// Pressure in inches of mercury
+    pub pressure_inches: Option<f32>,
+    /// Pressure in bars
+    pub pressure_bars: Option<f32>,
+}
+
+impl MmbData {
+    /// Pressure in hectopascals, converted from inches of mercury if the
+    /// pressure in bars is missing.
+    pub fn pressure_hpa(&self) -> Option<f32> {
+        self.pressure_bars
+            .map(|bars| bars * HPA_PER_BAR)
+            .or_else(|| {
+                self.pressure_inches
+                    .map(|inches| inches * HPA_PER_INCH_OF_MERCURY)
+            })
+    }
+}
+
+/// # Parse MMB message
+///
+/// The unit letters are validated, a sentence with a wrong unit letter is rejected.
+pub fn parse_mmb(sentence: NmeaSentence) -> Result<MmbData, Error> {
+    if sentence.message_id != SentenceType::MMB {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::MMB,
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_mmb(sentence.data)?.1)
+    }
+}
+
+fn do_parse_mmb(i: &str) -> IResult<&str, MmbData> {
+    let (i, pressure_inches) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('I'))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, pressure_bars) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('B'))(i)?;
+    let (i, _) = eof(i)?;
+
+    Ok((
+        i,
+        MmbData {
+            pressure_inches,
+            pressure_bars,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_mmb() {
+        let s = parse_nmea_sentence("$IIMMB,29.9870,I,1.0154,B*75").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_mmb(s).unwrap();
+        assert_relative_eq!(data.pressure_inches.unwrap(), 29.987);
+        assert_relative_eq!(data.pressure_bars.unwrap(), 1.0154);
+        assert_relative_eq!(data.pressure_hpa().unwrap(), 1015.4);
+    }
+
+    #[test]
+    fn test_parse_mmb_empty_fields() {
+        let s = parse_nmea_sentence("$IIMMB,,I,,B*49").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_mmb(s).unwrap();
+        assert_eq!(
+            data,
+            MmbData {
+                pressure_inches: None,
+                pressure_bars: None,
+            }
+        );
+        assert_eq!(data.pressure_hpa(), None);
+    }
+
+    #[test]
+    fn test_mmb_pressure_hpa_from_inches() {
+        let s = parse_nmea_sentence("$IIMMB,29.9870,I,,B*6A").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_mmb(s).unwrap();
+        assert_relative_eq!(data.pressure_hpa().unwrap(), 1015.47, epsilon = 0.01);
+    }
+
+    #[test]
+    fn test_parse_mmb_invalid_unit() {
+        let s = parse_nmea_sentence("$IIMMB,29.9870,B,1.0154,I*75").unwrap();
+        assert!(parse_mmb(s).is_err());
+    }
+}
@@ -1,75 +1,173 @@
 //! All the supported sentence type data and parsers.
 
 pub mod aam;
+pub mod ack;
 pub mod alm;
+pub mod alr;
 pub mod apa;
 pub mod bod;
 pub mod bwc;
+pub mod bwr;
 pub mod bww;
+pub mod cur;
 pub mod dbk;
+pub mod dbs;
+pub mod dsc;
+pub mod dse;
+pub mod epv;
+pub mod fsi;
 pub mod gbs;
 pub mod gga;
+pub mod glc;
 pub mod gll;
+pub mod gmp;
 pub mod gns;
+pub mod grs;
 pub mod gsa;
 pub mod gst;
 pub mod gsv;
+pub mod gtd;
+pub mod hbt;
+pub mod hdm;
 pub mod hdt;
+pub mod hsc;
+pub mod htc;
+pub mod htd;
 pub mod mda;
+pub mod mhu;
+pub mod mmb;
+pub mod mta;
 pub mod mtw;
+pub mod mwd;
+pub mod mwh;
 pub mod mwv;
+pub mod nrx;
+pub mod osd;
+pub mod rma;
+pub mod rmb;
 pub mod rmc;
+pub mod rmm;
 pub mod rmz;
+pub mod rpm;
+pub mod sfi;
+pub mod stn;
+pub mod ths;
+pub mod tll;
+pub mod trf;
 pub mod ttm;
 pub mod txt;
 pub mod utils;
+pub mod vbw;
+pub mod vdm;
+pub mod vdr;
 pub mod vhw;
+pub mod vpw;
 pub mod vtg;
+pub mod vwr;
+pub mod vwt;
+pub mod wcv;
 pub mod wnc;
+pub mod xdr;
 pub mod zda;
 pub mod zfo;
 pub mod ztg;
 
+pub mod coordinate;
+pub mod distance_unit;
 pub mod faa_mode;
 pub mod fix_type;
 pub mod gnss_type;
+pub mod reference;
+pub mod steer_direction;
+pub mod talker_id;
+
+#[cfg(all(test, feature = "chrono"))]
+mod proptests;
 
 #[doc(inline)]
 pub use {
-    aam::{parse_aam, AamData},
+    aam::{parse_aam, parse_aam_with_max_len, AamData},
+    ack::{parse_ack, AckData},
     alm::{parse_alm, AlmData},
-    apa::{parse_apa, ApaData},
-    bod::{parse_bod, BodData},
-    bwc::{parse_bwc, BwcData},
-    bww::{parse_bww, BwwData},
+    alr::{parse_alr, parse_alr_with_max_len, AlrData},
+    apa::{parse_apa, parse_apa_with_max_len, ApaData},
+    bod::{parse_bod, parse_bod_with_max_len, BodData},
+    bwc::{parse_bwc, parse_bwc_with_max_len, BwcData},
+    bwr::{parse_bwr, parse_bwr_with_max_len, BwrData},
+    bww::{parse_bww, parse_bww_with_max_len, BwwData},
+    coordinate::{Latitude, Longitude},
+    cur::{parse_cur, CurData, CurDirectionReference, CurSpeedReference},
     dbk::{parse_dbk, DbkData},
+    dbs::{parse_dbs, DbsData},
+    distance_unit::DistanceUnit,
+    dsc::{parse_dsc, DscAcknowledgement, DscData},
+    dse::{parse_dse, DseData, DseDataSet, DseQueryFlag},
+    epv::{parse_epv, parse_epv_with_max_len, EpvData, EpvSentenceStatus},
     faa_mode::{FaaMode, FaaModes},
     fix_type::FixType,
+    fsi::{parse_fsi, FsiData},
     gbs::{parse_gbs, GbsData},
     gga::{parse_gga, GgaData},
+    glc::{parse_glc, GlcData, GlcSignalStatus, GlcTimeDifference},
     gll::{parse_gll, GllData},
+    gmp::{parse_gmp, parse_gmp_with_max_len, GmpData},
     gns::{parse_gns, GnsData},
-    gnss_type::GnssType,
+    gnss_type::{GnssSystem, GnssType},
+    grs::{parse_grs, GrsData},
     gsa::{parse_gsa, GsaData},
     gst::{parse_gst, GstData},
     gsv::{parse_gsv, GsvData},
+    gtd::{parse_gtd, GtdData},
+    hbt::{parse_hbt, HbtData},
+    hdm::{parse_hdm, HdmData},
     hdt::{parse_hdt, HdtData},
+    hsc::{parse_hsc, HscData},
+    htc::{parse_htc, HtcData, HtcSteeringMode, HtcTurnMode},
+    htd::{parse_htd, HtdData},
     mda::{parse_mda, MdaData},
+    mhu::{parse_mhu, MhuData},
+    mmb::{parse_mmb, MmbData},
+    mta::{parse_mta, MtaData},
     mtw::{parse_mtw, MtwData},
+    mwd::{parse_mwd, MwdData},
+    mwh::{parse_mwh, MwhData},
     mwv::{parse_mwv, MwvData},
+    nrx::{concat_nrx_text, parse_nrx, parse_nrx_with_max_len, NrxData},
+    osd::{parse_osd, OsdData, OsdReference, OsdSpeedUnit},
+    reference::Reference,
+    rma::{parse_rma, RmaData},
+    rmb::{parse_rmb, parse_rmb_with_max_len, RmbData},
     rmc::{parse_rmc, RmcData},
+    rmm::{parse_rmm, parse_rmm_with_max_len, RmmData},
     rmz::{parse_pgrmz, PgrmzData},
+    rpm::{parse_rpm, RpmData, RpmSource},
+    sfi::{concat_sfi_frequencies, parse_sfi, SfiData, SfiFrequency},
+    steer_direction::SteerDirection,
+    stn::{parse_stn, StnData},
+    talker_id::TalkerId,
+    ths::{parse_ths, ThsData},
+    tll::{parse_tll, parse_tll_with_max_len, TllData},
+    trf::{parse_trf, TrfData},
     ttm::{
         parse_ttm, TtmAngle, TtmData, TtmDistanceUnit, TtmReference, TtmStatus,
         TtmTypeOfAcquisition,
     },
-    txt::{parse_txt, TxtData},
+    txt::{parse_txt, parse_txt_with_max_len, TxtData},
+    utils::{Date, DayMonthYear, Duration, Speed, Time, TimeOfDay},
+    vbw::{parse_vbw, VbwData},
+    vdm::{concat_vdm_payloads, dearmor_ais_payload, parse_vdm, AisBits, VdmData},
+    vdr::{parse_vdr, VdrData},
     vhw::{parse_vhw, VhwData},
+    vpw::{parse_vpw, VpwData},
     vtg::{parse_vtg, VtgData},
-    wnc::{parse_wnc, WncData},
+    vwr::{parse_vwr, VwrData},
+    vwt::{parse_vwt, VwtData},
+    wcv::{parse_wcv, parse_wcv_with_max_len, WcvData},
+    wnc::{parse_wnc, parse_wnc_with_max_len, WncData},
+    xdr::{parse_xdr, XdrData},
     zda::{parse_zda, ZdaData},
-    zfo::{parse_zfo, ZfoData},
-    ztg::{parse_ztg, ZtgData},
+    zfo::{parse_zfo, parse_zfo_with_max_len, ZfoData},
+    ztg::{parse_ztg, parse_ztg_with_max_len, ZtgData},
 };
 
 pub(crate) fn nom_parse_failure(inp: &str) -> nom::Err<nom::error::Error<&str>> {
@@ -0,0 +1,88 @@
+use nom::{
+    character::complete::{char, one_of},
+    combinator::opt,
+    number::complete::float,
+    sequence::preceded,
+    IResult,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{parse::NmeaSentence, Error, SentenceType};
+
+/// MTA - Air Temperature
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_mta_air_temperature>
+///
+/// ```text
+///        1   2 3
+///        |   | |
+/// $--MTA,x.x,C*hh<CR><LF>
+/// ```
+/// 1:  Temperature, degrees
+/// 2:  Unit of Measurement, (only) Celsius
+/// 3:  Mandatory NMEA checksum
+///
+/// Example:
+/// ```text
+/// $IIMTA,13.1,C*06
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MtaData {
+    /// Air temperature, degrees Celsius
+    pub temperature: Option<f32>,
+}
+
+/// # Parse MTA message
+///
+/// The unit of measurement has to be `C`, just like in MTW.
+pub fn parse_mta(sentence: NmeaSentence) -> Result<MtaData, Error> {
+    if sentence.message_id != SentenceType::MTA {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::MTA,
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_mta(sentence.data)?.1)
+    }
+}
+
+fn do_parse_mta(i: &str) -> IResult<&str, MtaData> {
+    let (i, temperature) = opt(float)(i)?;
+    let (i, _) = preceded(char(','), one_of("C"))(i)?;
+    Ok((i, MtaData { temperature }))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_mta() {
+        let s = parse_nmea_sentence("$IIMTA,13.1,C*06").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_relative_eq!(parse_mta(s).unwrap().temperature.unwrap(), 13.1);
+
+        let s = parse_nmea_sentence("$IIMTA,-2.5,C*1F").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_relative_eq!(parse_mta(s).unwrap().temperature.unwrap(), -2.5);
+
+        let s = parse_nmea_sentence("$IIMTA,,C*1B").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(parse_mta(s), Ok(MtaData { temperature: None }));
+    }
+
+    #[test]
+    fn test_parse_mta_invalid_unit() {
+        let s = parse_nmea_sentence("$IIMTA,13.1,F*03").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert!(parse_mta(s).is_err());
+    }
+}
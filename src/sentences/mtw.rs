@@ -24,6 +24,7 @@ use crate::{parse::NmeaSentence, Error, SentenceType};
 /// 2:  Unit of Measurement, (only) Celsius
 /// 3:  Mandatory NMEA checksum
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[derive(Debug, PartialEq)]
 pub struct MtwData {
@@ -82,8 +83,8 @@ mod tests {
     #[test]
     fn test_parse_mtw() {
         let s = parse_nmea_sentence("$INMTW,17.9,C*1B").unwrap();
-        assert_eq!(s.checksum, s.calc_checksum());
-        assert_eq!(s.checksum, 0x1B);
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(s.checksum, Some(0x1B));
         let mtw_data = parse_mtw(s).unwrap();
         assert_eq!(Some(17.9), mtw_data.temperature);
     }
@@ -91,16 +92,16 @@ mod tests {
     #[test]
     fn test_parse_mtw_invalid_unit() {
         let s = parse_nmea_sentence("$INMTW,17.9,x*20").unwrap();
-        assert_eq!(s.checksum, s.calc_checksum());
-        assert_eq!(s.checksum, 0x20);
-        assert_eq!(true, parse_mtw(s).is_err());
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(s.checksum, Some(0x20));
+        assert!(parse_mtw(s).is_err());
     }
 
     #[test]
     fn test_parse_mtw_invalid_temp() {
         let s = parse_nmea_sentence("$INMTW,x.9,C*65").unwrap();
-        assert_eq!(s.checksum, s.calc_checksum());
-        assert_eq!(s.checksum, 0x65);
-        assert_eq!(true, parse_mtw(s).is_err());
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(s.checksum, Some(0x65));
+        assert!(parse_mtw(s).is_err());
     }
 }
@@ -0,0 +1,141 @@
+use nom::{
+    character::complete::char,
+    combinator::{all_consuming, opt},
+    number::complete::float,
+    sequence::{preceded, terminated},
+    IResult,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{parse::NmeaSentence, Error, SentenceType};
+
+/// MWD - Wind Direction & Speed
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_mwd_wind_direction_speed>
+///
+/// ```text
+///        1   2 3   4 5   6 7   8 9
+///        |   | |   | |   | |   | |
+/// $--MWD,x.x,T,x.x,M,x.x,N,x.x,M*hh<CR><LF>
+/// ```
+///
+/// 1. Wind direction, 0.0 to 359.9 degrees True
+/// 2. T = True
+/// 3. Wind direction, 0.0 to 359.9 degrees Magnetic
+/// 4. M = Magnetic
+/// 5. Wind speed, knots
+/// 6. N = Knots
+/// 7. Wind speed, meters/second
+/// 8. M = Meters/second
+/// 9. Checksum
+///
+/// Some instruments fill only the true direction and the speed in knots and
+/// leave the rest of the fields empty or omit them entirely.
+///
+/// Example:
+/// ```text
+/// $WIMWD,184.0,T,190.5,M,12.4,N,6.4,M*6F
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MwdData {
+    /// Wind direction, degrees True
+    pub direction_true: Option<f32>,
+    /// Wind direction, degrees Magnetic
+    pub direction_magnetic: Option<f32>,
+    /// Wind speed, knots
+    pub speed_knots: Option<f32>,
+    /// Wind speed, meters/second
+    pub speed_mps: Option<f32>,
+}
+
+/// # Parse MWD message
+pub fn parse_mwd(sentence: NmeaSentence) -> Result<MwdData, Error> {
+    if sentence.message_id != SentenceType::MWD {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::MWD,
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_mwd(sentence.data)?.1)
+    }
+}
+
+/// Parses a value followed by its unit letter, e.g. `184.0,T`.
+fn value_with_unit<'a>(unit: char) -> impl FnMut(&'a str) -> IResult<&'a str, Option<f32>> {
+    terminated(opt(float), preceded(char(','), opt(char(unit))))
+}
+
+/// Parses a value with its unit which may be omitted at the end of the sentence.
+fn next_value_with_unit<'a>(unit: char) -> impl FnMut(&'a str) -> IResult<&'a str, Option<f32>> {
+    move |i| {
+        let (i, value) = opt(preceded(char(','), value_with_unit(unit)))(i)?;
+        Ok((i, value.flatten()))
+    }
+}
+
+fn do_parse_mwd(i: &str) -> IResult<&str, MwdData> {
+    let (i, direction_true) = value_with_unit('T')(i)?;
+    let (i, direction_magnetic) = next_value_with_unit('M')(i)?;
+    let (i, speed_knots) = next_value_with_unit('N')(i)?;
+    let (i, speed_mps) = all_consuming(next_value_with_unit('M'))(i)?;
+
+    Ok((
+        i,
+        MwdData {
+            direction_true,
+            direction_magnetic,
+            speed_knots,
+            speed_mps,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_mwd() {
+        let s = parse_nmea_sentence("$WIMWD,184.0,T,190.5,M,12.4,N,6.4,M*6F").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+
+        let data = parse_mwd(s).unwrap();
+        assert_relative_eq!(data.direction_true.unwrap(), 184.0);
+        assert_relative_eq!(data.direction_magnetic.unwrap(), 190.5);
+        assert_relative_eq!(data.speed_knots.unwrap(), 12.4);
+        assert_relative_eq!(data.speed_mps.unwrap(), 6.4);
+    }
+
+    #[test]
+    fn test_parse_mwd_true_direction_and_knots_only() {
+        let expected = MwdData {
+            direction_true: Some(184.0),
+            direction_magnetic: None,
+            speed_knots: Some(12.4),
+            speed_mps: None,
+        };
+
+        let s = parse_nmea_sentence("$WIMWD,184.0,T,,,12.4,N,,*60").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(parse_mwd(s), Ok(expected));
+
+        // trailing fields omitted
+        let s = parse_nmea_sentence("$WIMWD,184.0,T,,,12.4,N*60").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(parse_mwd(s), Ok(expected));
+    }
+
+    #[test]
+    fn test_parse_mwd_invalid_unit() {
+        let s = parse_nmea_sentence("$WIMWD,184.0,X,190.5,M,12.4,N,6.4,M*63").unwrap();
+        assert!(parse_mwd(s).is_err());
+    }
+}
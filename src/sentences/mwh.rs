@@ -0,0 +1,131 @@
+use nom::{
+    character::complete::char,
+    combinator::{eof, opt},
+    number::complete::float,
+    IResult,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{parse::NmeaSentence, Error, SentenceType};
+
+/// Meters in a foot
+const METERS_PER_FOOT: f32 = 0.3048;
+
+/// MWH - Wave Height
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_mwh_wave_height>
+///
+/// ```text
+///        1   2 3   4 5
+///        |   | |   | |
+/// $--MWH,x.x,f,x.x,M*hh<CR><LF>
+/// ```
+/// 1. Wave height, feet
+/// 2. f = feet
+/// 3. Wave height, meters
+/// 4. M = meters
+/// 5. Checksum
+///
+/// Example:
+/// ```text
+/// $WIMWH,6.6,f,2.0,M*65
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MwhData {
+    /// Significant wave height, feet
+    pub height_feet: Option<f32>,
+    /// Significant wave height, meters
+    pub height_meters: Option<f32>,
+}
+
+impl MwhData {
+    /// Wave height in meters, converted from feet if only that is present.
+    pub fn meters(&self) -> Option<f32> {
+        self.height_meters
+            .or_else(|| self.height_feet.map(|feet| feet * METERS_PER_FOOT))
+    }
+}
+
+/// # Parse MWH message
+///
+/// The unit letters are validated, a sentence with a wrong unit letter is rejected.
+pub fn parse_mwh(sentence: NmeaSentence) -> Result<MwhData, Error> {
+    if sentence.message_id != SentenceType::MWH {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::MWH,
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_mwh(sentence.data)?.1)
+    }
+}
+
+fn do_parse_mwh(i: &str) -> IResult<&str, MwhData> {
+    let (i, height_feet) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('f'))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, height_meters) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('M'))(i)?;
+    let (i, _) = eof(i)?;
+
+    Ok((
+        i,
+        MwhData {
+            height_feet,
+            height_meters,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_mwh() {
+        let s = parse_nmea_sentence("$WIMWH,6.6,f,2.0,M*65").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_mwh(s).unwrap();
+        assert_relative_eq!(data.height_feet.unwrap(), 6.6);
+        assert_relative_eq!(data.height_meters.unwrap(), 2.0);
+        assert_relative_eq!(data.meters().unwrap(), 2.0);
+    }
+
+    #[test]
+    fn test_parse_mwh_empty_fields() {
+        let s = parse_nmea_sentence("$WIMWH,,f,,M*67").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_mwh(s).unwrap();
+        assert_eq!(
+            data,
+            MwhData {
+                height_feet: None,
+                height_meters: None,
+            }
+        );
+        assert_eq!(data.meters(), None);
+    }
+
+    #[test]
+    fn test_mwh_meters_from_feet() {
+        let s = parse_nmea_sentence("$WIMWH,6.6,f,,M*49").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_relative_eq!(parse_mwh(s).unwrap().meters().unwrap(), 2.01168);
+    }
+
+    #[test]
+    fn test_parse_mwh_invalid_unit() {
+        let s = parse_nmea_sentence("$WIMWH,6.6,F,2.0,M*45").unwrap();
+        assert!(parse_mwh(s).is_err());
+    }
+}
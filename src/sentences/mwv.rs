@@ -9,7 +9,7 @@ use nom::{
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::{parse::NmeaSentence, Error, SentenceType};
+use crate::{parse::NmeaSentence, sentences::utils::Speed, Error, SentenceType};
 
 /// MWV - Wind Speed and Angle
 ///
@@ -21,6 +21,7 @@ use crate::{parse::NmeaSentence, Error, SentenceType};
 /// $--MWV,x.x,a,x.x,a*hh<CR><LF>
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[derive(Debug, PartialEq)]
 pub struct MwvData {
@@ -49,6 +50,20 @@ pub enum MwvWindSpeedUnits {
     MilesPerHour,
 }
 
+impl MwvData {
+    /// Wind speed as a typed [`Speed`], converted from its [`MwvWindSpeedUnits`].
+    pub fn speed(&self) -> Option<Speed> {
+        let wind_speed = self.wind_speed?;
+        Some(match self.wind_speed_units? {
+            MwvWindSpeedUnits::KilometersPerHour => Speed::from_kph(wind_speed),
+            MwvWindSpeedUnits::MetersPerSecond => Speed::from_mps(wind_speed),
+            MwvWindSpeedUnits::Knots => Speed::from_knots(wind_speed),
+            // a statute mile is 1609.344 meters
+            MwvWindSpeedUnits::MilesPerHour => Speed::from_kph(wind_speed * 1.609_344),
+        })
+    }
+}
+
 /// # Parse MWV message
 ///
 /// Information from mwv:
@@ -125,8 +140,8 @@ mod tests {
     #[test]
     fn test_parse_mwv() {
         let s = parse_nmea_sentence("$WIMWV,041.1,R,01.0,N,A*16").unwrap();
-        assert_eq!(s.checksum, s.calc_checksum());
-        assert_eq!(s.checksum, 0x16);
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(s.checksum, Some(0x16));
         let wimwv_data = parse_mwv(s).unwrap();
         assert_relative_eq!(41.1, wimwv_data.wind_direction.unwrap());
         assert_eq!(MwvReference::Relative, wimwv_data.reference.unwrap());
@@ -136,5 +151,17 @@ mod tests {
             wimwv_data.wind_speed_units.unwrap()
         );
         assert!(wimwv_data.data_valid);
+        assert_relative_eq!(wimwv_data.speed().unwrap().as_knots(), 1.0);
+    }
+
+    #[test]
+    fn test_mwv_speed_units() {
+        let s = parse_nmea_sentence("$WIMWV,041.1,R,36.0,K,A*17").unwrap();
+        let speed = parse_mwv(s).unwrap().speed().unwrap();
+        assert_relative_eq!(speed.as_mps(), 10.0, epsilon = 1e-5);
+
+        let s = parse_nmea_sentence("$WIMWV,041.1,R,10.0,S,A*0B").unwrap();
+        let speed = parse_mwv(s).unwrap().speed().unwrap();
+        assert_relative_eq!(speed.as_kph(), 16.093_44, epsilon = 1e-4);
     }
 }
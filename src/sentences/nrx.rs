@@ -0,0 +1,329 @@
+use arrayvec::ArrayString;
+#[cfg(feature = "chrono")]
+use chrono::{NaiveDate, NaiveDateTime};
+use nom::{
+    bytes::complete::take_while_m_n,
+    character::complete::char,
+    combinator::{opt, rest},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::utils::{array_string, number, parse_hms, parse_valid_status, Time};
+use crate::{
+    parse::{NmeaSentence, TEXT_PARAMETER_MAX_LEN},
+    Error, SentenceType,
+};
+
+/// NRX - NAVTEX Received Message
+///
+/// ```text
+///        1   2   3  4    5 6         7  8  9    10  11  12 13
+///        |   |   |  |    | |         |  |  |    |   |   |  |
+/// $--NRX,xxx,xxx,xx,aaxx,x,hhmmss.ss,xx,xx,xxxx,x.x,x.x,A,c--c*hh<CR><LF>
+/// ```
+///
+/// 1. Total number of sentences needed to transfer the message, 001 to 999
+/// 2. Sentence number, 001 to 999
+/// 3. Sequential message identifier, 00 to 99
+/// 4. NAVTEX message code: transmitter identity, subject indicator and serial number
+/// 5. Frequency index, 0 = not received over air, 1 = 490 kHz, 2 = 518 kHz, 3 = 4209.5 kHz
+/// 6. UTC of receipt of the message
+/// 7. Day, 01 to 31
+/// 8. Month, 01 to 12
+/// 9. Year
+/// 10. Total number of characters in this series of NRX sentences
+/// 11. Total number of bad characters
+/// 12. Status, A = valid, V = invalid
+/// 13. Message body
+///
+/// Fields 4 to 12 are only sent in the first sentence of a message, the
+/// following sentences leave them empty. Reserved characters in the message
+/// body are encoded as `^` followed by their two hex digits, the body of a
+/// complete message can be decoded and joined with [`concat_nrx_text`].
+///
+/// Example:
+/// ```text
+/// $CRNRX,007,001,00,IE69,1,135600,27,06,2001,241,3,A,==========================*09
+/// $CRNRX,007,002,00,,,,,,,,,,========^0D^0AISSUED ON SATURDAY 06 JANUARY 2001.*29
+/// ```
+///
+/// The message body is bounded by `MAX_LEN` characters, [`TEXT_PARAMETER_MAX_LEN`] by default.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NrxData<const MAX_LEN: usize = TEXT_PARAMETER_MAX_LEN> {
+    pub total_messages: u16,
+    pub message_number: u16,
+    pub sequential_id: Option<u8>,
+    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
+    pub message_code: Option<ArrayString<4>>,
+    pub frequency_index: Option<u8>,
+    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
+    pub utc_time: Option<Time>,
+    pub day: Option<u8>,
+    pub month: Option<u8>,
+    pub year: Option<u16>,
+    pub total_chars: Option<u32>,
+    pub bad_chars: Option<u32>,
+    pub status: Option<bool>,
+    /// Message body of this sentence, still encoded
+    #[cfg_attr(feature = "defmt-03", defmt(Display2Format))]
+    pub text: ArrayString<MAX_LEN>,
+}
+
+impl<const MAX_LEN: usize> NrxData<MAX_LEN> {
+    /// Get the UTC date of receipt by `day`, `month` and `year` fields.
+    /// Returns `None` if any field is `None`.
+    #[cfg(feature = "chrono")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+    pub fn utc_date(&self) -> Option<NaiveDate> {
+        if let Some(((day, month), year)) = self.day.zip(self.month).zip(self.year) {
+            NaiveDate::from_ymd_opt(year.into(), month.into(), day.into())
+        } else {
+            None
+        }
+    }
+
+    /// Get the UTC date time of receipt by `utc_time`, `day`, `month`, and `year` fields.
+    /// Returns `None` if any field is `None`.
+    #[cfg(feature = "chrono")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+    pub fn utc_date_time(&self) -> Option<NaiveDateTime> {
+        self.utc_time.and_then(|utc_time| {
+            self.utc_date()
+                .map(|utc_date| NaiveDateTime::new(utc_date, utc_time))
+        })
+    }
+}
+
+/// # Parse NRX message
+///
+/// The message body is kept encoded, see [`concat_nrx_text`].
+///
+/// The message body is bounded by [`TEXT_PARAMETER_MAX_LEN`], use
+/// [`parse_nrx_with_max_len`] for a different bound.
+pub fn parse_nrx(sentence: NmeaSentence) -> Result<NrxData, Error> {
+    parse_nrx_with_max_len(sentence)
+}
+
+/// Parse NRX message with the message body bounded by `MAX_LEN` characters.
+///
+/// # Errors
+///
+/// [`Error::ParameterLength`] with `max_length` set to `MAX_LEN` if the message body is longer.
+pub fn parse_nrx_with_max_len<const MAX_LEN: usize>(
+    sentence: NmeaSentence,
+) -> Result<NrxData<MAX_LEN>, Error> {
+    if sentence.message_id != SentenceType::NRX {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::NRX,
+            found: sentence.message_id,
+        })
+    } else {
+        do_parse_nrx(sentence.data)
+    }
+}
+
+fn do_parse_nrx<const MAX_LEN: usize>(i: &str) -> Result<NrxData<MAX_LEN>, Error<'_>> {
+    // 1. Total number of sentences
+    let (i, total_messages) = number::<u16>(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. Sentence number
+    let (i, message_number) = number::<u16>(i)?;
+    let (i, _) = char(',')(i)?;
+    // 3. Sequential message identifier
+    let (i, sequential_id) = opt(number::<u8>)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 4. NAVTEX message code
+    let (i, message_code) = opt(take_while_m_n(4, 4, |c: char| c.is_ascii_alphanumeric()))(i)?;
+    let (i, _) = char(',')(i)?;
+    // 5. Frequency index
+    let (i, frequency_index) = opt(number::<u8>)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 6. UTC time
+    let (i, utc_time) = opt(parse_hms)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 7. Day
+    let (i, day) = opt(number::<u8>)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 8. Month
+    let (i, month) = opt(number::<u8>)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 9. Year
+    let (i, year) = opt(number::<u16>)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 10. Total number of characters
+    let (i, total_chars) = opt(number::<u32>)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 11. Total number of bad characters
+    let (i, bad_chars) = opt(number::<u32>)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 12. Status
+    let (i, status) = opt(parse_valid_status)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 13. Message body
+    let (_i, text) = rest(i)?;
+
+    Ok(NrxData {
+        total_messages,
+        message_number,
+        sequential_id,
+        message_code: message_code.map(array_string::<4>).transpose()?,
+        frequency_index,
+        utc_time,
+        day,
+        month,
+        year,
+        total_chars,
+        bad_chars,
+        status,
+        text: array_string::<MAX_LEN>(text)?,
+    })
+}
+
+/// Decodes and concatenates the message bodies of the sentences of a single NRX message.
+///
+/// The sentences have to be complete and given in order. Reserved characters
+/// encoded as `^` followed by two hex digits are decoded, a `^` which isn't
+/// followed by two hex digits is kept as is.
+///
+/// # Errors
+///
+/// - [`Error::InvalidFragments`] when sentences are missing, out of order
+///   or belong to different messages.
+/// - [`Error::ParameterLength`] when the decoded text does not fit in `MAX_LEN`.
+pub fn concat_nrx_text<const MAX_LEN: usize>(
+    sentences: &[NrxData],
+) -> Result<ArrayString<MAX_LEN>, Error<'static>> {
+    let first = sentences.first().ok_or(Error::InvalidFragments)?;
+    if usize::from(first.total_messages) != sentences.len() {
+        return Err(Error::InvalidFragments);
+    }
+
+    let mut text = ArrayString::new();
+    for (index, sentence) in sentences.iter().enumerate() {
+        if usize::from(sentence.message_number) != index + 1
+            || sentence.total_messages != first.total_messages
+            || sentence.sequential_id != first.sequential_id
+        {
+            return Err(Error::InvalidFragments);
+        }
+
+        let mut remaining = sentence.text.as_str();
+        while let Some(c) = remaining.chars().next() {
+            let decoded = remaining
+                .strip_prefix('^')
+                .and_then(|escaped| escaped.get(..2))
+                .filter(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit()))
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+            let c = match decoded {
+                Some(byte) => {
+                    remaining = &remaining[3..];
+                    char::from(byte)
+                }
+                None => {
+                    remaining = &remaining[c.len_utf8()..];
+                    c
+                }
+            };
+
+            text.try_push(c).map_err(|_| Error::ParameterLength {
+                max_length: MAX_LEN,
+                parameter_length: sentences.iter().map(|s| s.text.len()).sum(),
+            })?;
+        }
+    }
+
+    Ok(text)
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_nrx() {
+        let s = parse_nmea_sentence(
+            "$CRNRX,007,001,00,IE69,1,135600,27,06,2001,241,3,A,==========================*09",
+        )
+        .unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let nrx = parse_nrx(s).unwrap();
+        assert_eq!(nrx.total_messages, 7);
+        assert_eq!(nrx.message_number, 1);
+        assert_eq!(nrx.sequential_id, Some(0));
+        assert_eq!(nrx.message_code.as_deref(), Some("IE69"));
+        assert_eq!(nrx.frequency_index, Some(1));
+        assert_eq!(
+            nrx.utc_date_time(),
+            NaiveDate::from_ymd_opt(2001, 6, 27).and_then(|d| d.and_hms_opt(13, 56, 0))
+        );
+        assert_eq!(nrx.total_chars, Some(241));
+        assert_eq!(nrx.bad_chars, Some(3));
+        assert_eq!(nrx.status, Some(true));
+        assert_eq!(&nrx.text, "==========================");
+
+        let s = parse_nmea_sentence(
+            "$CRNRX,007,002,00,,,,,,,,,,========^0D^0AISSUED ON SATURDAY 06 JANUARY 2001.*29",
+        )
+        .unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let nrx = parse_nrx(s).unwrap();
+        assert_eq!(nrx.message_number, 2);
+        assert_eq!(nrx.message_code, None);
+        assert_eq!(nrx.utc_date_time(), None);
+        assert_eq!(nrx.status, None);
+        assert_eq!(
+            &nrx.text,
+            "========^0D^0AISSUED ON SATURDAY 06 JANUARY 2001."
+        );
+    }
+
+    #[test]
+    fn test_concat_nrx_text() {
+        let sentences = [
+            "$CRNRX,002,001,07,IE69,1,135600,27,06,2001,34,0,A,GALE^2C FORCE 8^0D^0A*78",
+            "$CRNRX,002,002,07,,,,,,,,,,EXPECTED SOON*5D",
+        ]
+        .map(|sentence| parse_nrx(parse_nmea_sentence(sentence).unwrap()).unwrap());
+
+        let text = concat_nrx_text::<64>(&sentences).unwrap();
+        assert_eq!(&text, "GALE, FORCE 8\r\nEXPECTED SOON");
+
+        assert_eq!(
+            concat_nrx_text::<16>(&sentences),
+            Err(Error::ParameterLength {
+                max_length: 16,
+                parameter_length: 34
+            })
+        );
+        // out of order
+        assert_eq!(
+            concat_nrx_text::<64>(&[sentences[1], sentences[0]]),
+            Err(Error::InvalidFragments)
+        );
+        // missing sentence
+        assert_eq!(
+            concat_nrx_text::<64>(&sentences[..1]),
+            Err(Error::InvalidFragments)
+        );
+    }
+
+    #[test]
+    fn test_concat_nrx_text_keeps_invalid_escapes() {
+        let mut nrx =
+            parse_nrx(parse_nmea_sentence("$CRNRX,002,002,07,,,,,,,,,,EXPECTED SOON*5D").unwrap())
+                .unwrap();
+        nrx.total_messages = 1;
+        nrx.message_number = 1;
+        nrx.text = ArrayString::from("^5E^ZZ^+1 100%^2").unwrap();
+
+        assert_eq!(&concat_nrx_text::<32>(&[nrx]).unwrap(), "^^ZZ^+1 100%^2");
+    }
+}
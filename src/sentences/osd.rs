@@ -0,0 +1,240 @@
+use nom::{
+    character::complete::{anychar, char},
+    combinator::{eof, map_res, opt},
+    number::complete::float,
+    IResult,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::utils::parse_valid_status;
+use crate::{Error, NmeaSentence, SentenceType};
+
+/// Reference of the course and speed in an OSD sentence
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OsdReference {
+    /// `B` - bottom tracking log
+    BottomTrackingLog,
+    /// `M` - manually entered
+    Manual,
+    /// `W` - water referenced
+    WaterReferenced,
+    /// `R` - radar tracking of a fixed target
+    RadarTracking,
+    /// `P` - positioning system ground reference
+    PositioningSystem,
+}
+
+impl TryFrom<char> for OsdReference {
+    type Error = Error<'static>;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'B' => Ok(OsdReference::BottomTrackingLog),
+            'M' => Ok(OsdReference::Manual),
+            'W' => Ok(OsdReference::WaterReferenced),
+            'R' => Ok(OsdReference::RadarTracking),
+            'P' => Ok(OsdReference::PositioningSystem),
+            _ => Err(Error::InvalidIndicator(c)),
+        }
+    }
+}
+
+/// Unit of the speed and drift in an OSD sentence
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OsdSpeedUnit {
+    /// `K` - km/h
+    KilometersPerHour,
+    /// `N` - knots
+    Knots,
+    /// `S` - statute miles/h
+    StatuteMilesPerHour,
+}
+
+impl TryFrom<char> for OsdSpeedUnit {
+    type Error = Error<'static>;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'K' => Ok(OsdSpeedUnit::KilometersPerHour),
+            'N' => Ok(OsdSpeedUnit::Knots),
+            'S' => Ok(OsdSpeedUnit::StatuteMilesPerHour),
+            _ => Err(Error::InvalidIndicator(c)),
+        }
+    }
+}
+
+/// OSD - Own Ship Data
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_osd_own_ship_data>
+///
+/// ```text
+///        1   2 3   4 5   6 7   8   9 10
+///        |   | |   | |   | |   |   | |
+/// $--OSD,x.x,A,x.x,a,x.x,a,x.x,x.x,a*hh<CR><LF>
+/// ```
+/// 1. Heading, degrees True
+/// 2. Status, A = Data Valid, V = Invalid
+/// 3. Vessel Course, degrees True
+/// 4. Course Reference, B/M/W/R/P
+/// 5. Vessel Speed
+/// 6. Speed Reference, B/M/W/R/P
+/// 7. Vessel Set, degrees True
+/// 8. Vessel drift (speed)
+/// 9. Speed Units, K = km/h, N = knots, S = statute miles/h
+/// 10. Checksum
+///
+/// Example:
+/// ```text
+/// $RAOSD,35.1,A,36.0,P,10.2,P,15.3,0.1,N*41
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OsdData {
+    /// Heading, degrees True
+    pub heading: Option<f32>,
+    /// `true` if the heading is valid
+    pub heading_valid: Option<bool>,
+    /// Vessel course, degrees True
+    pub course: Option<f32>,
+    pub course_reference: Option<OsdReference>,
+    /// Vessel speed in [`OsdData::speed_unit`]
+    pub speed: Option<f32>,
+    pub speed_reference: Option<OsdReference>,
+    /// Set (direction) of the current, degrees True
+    pub set: Option<f32>,
+    /// Drift (speed) of the current in [`OsdData::speed_unit`]
+    pub drift: Option<f32>,
+    pub speed_unit: Option<OsdSpeedUnit>,
+}
+
+/// # Parse OSD message
+///
+/// See: <https://gpsd.gitlab.io/gpsd/NMEA.html#_osd_own_ship_data>
+pub fn parse_osd(sentence: NmeaSentence) -> Result<OsdData, Error> {
+    if sentence.message_id != SentenceType::OSD {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::OSD,
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_osd(sentence.data)?.1)
+    }
+}
+
+fn do_parse_osd(i: &str) -> IResult<&str, OsdData> {
+    // 1. Heading, degrees True
+    let (i, heading) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. Heading status
+    let (i, heading_valid) = opt(parse_valid_status)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 3. Vessel course, degrees True
+    let (i, course) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 4. Course reference
+    let (i, course_reference) = opt(map_res(anychar, OsdReference::try_from))(i)?;
+    let (i, _) = char(',')(i)?;
+    // 5. Vessel speed
+    let (i, speed) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 6. Speed reference
+    let (i, speed_reference) = opt(map_res(anychar, OsdReference::try_from))(i)?;
+    let (i, _) = char(',')(i)?;
+    // 7. Vessel set, degrees True
+    let (i, set) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 8. Vessel drift
+    let (i, drift) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 9. Speed units
+    let (i, speed_unit) = opt(map_res(anychar, OsdSpeedUnit::try_from))(i)?;
+    let (i, _) = eof(i)?;
+
+    Ok((
+        i,
+        OsdData {
+            heading,
+            heading_valid,
+            course,
+            course_reference,
+            speed,
+            speed_reference,
+            set,
+            drift,
+            speed_unit,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_osd() {
+        let s = parse_nmea_sentence("$RAOSD,35.1,A,36.0,P,10.2,P,15.3,0.1,N*41").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_osd(s).unwrap();
+
+        assert_relative_eq!(data.heading.unwrap(), 35.1);
+        assert_eq!(data.heading_valid, Some(true));
+        assert_relative_eq!(data.course.unwrap(), 36.0);
+        assert_eq!(data.course_reference, Some(OsdReference::PositioningSystem));
+        assert_relative_eq!(data.speed.unwrap(), 10.2);
+        assert_eq!(data.speed_reference, Some(OsdReference::PositioningSystem));
+        assert_relative_eq!(data.set.unwrap(), 15.3);
+        assert_relative_eq!(data.drift.unwrap(), 0.1);
+        assert_eq!(data.speed_unit, Some(OsdSpeedUnit::Knots));
+    }
+
+    #[test]
+    fn test_parse_osd_empty_fields() {
+        let s = parse_nmea_sentence("$RAOSD,,V,,,,,,,*31").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(
+            parse_osd(s),
+            Ok(OsdData {
+                heading: None,
+                heading_valid: Some(false),
+                course: None,
+                course_reference: None,
+                speed: None,
+                speed_reference: None,
+                set: None,
+                drift: None,
+                speed_unit: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_osd_invalid_indicators() {
+        // invalid course reference
+        let s = parse_nmea_sentence("$RAOSD,35.1,A,36.0,X,10.2,P,15.3,0.1,N*49").unwrap();
+        assert!(parse_osd(s).is_err());
+
+        // invalid speed unit
+        let s = parse_nmea_sentence("$RAOSD,35.1,A,36.0,P,10.2,P,15.3,0.1,M*42").unwrap();
+        assert!(parse_osd(s).is_err());
+    }
+
+    #[test]
+    fn test_parse_osd_wrong_sentence() {
+        let s = parse_nmea_sentence("$GPHDT,274.07,T*03").unwrap();
+        assert!(matches!(
+            parse_osd(s),
+            Err(Error::WrongSentenceHeader { .. })
+        ));
+    }
+}
@@ -0,0 +1,191 @@
+//! Property based round trip tests of the [`ToNmeaSentence`] implementations.
+//!
+//! Arbitrary data structs are encoded, parsed again and compared with the
+//! original. The [`Arbitrary`] implementations only generate values the
+//! parsers can produce, e.g. floats with the precision the encoder writes,
+//! except for the ZTG durations which can't be encoded.
+
+use arrayvec::ArrayString;
+use chrono::{Duration, NaiveTime};
+use proptest::{
+    arbitrary::{any, Arbitrary},
+    option, prop_oneof, proptest,
+    strategy::{BoxedStrategy, Just, Strategy},
+};
+
+use crate::{
+    encode::ToNmeaSentence,
+    parse::{parse_nmea_sentence, TEXT_PARAMETER_MAX_LEN},
+    sentences::{
+        parse_apa, parse_rpm, parse_ths, parse_ztg, ApaData, DistanceUnit, Reference, RpmData,
+        RpmSource, SteerDirection, ThsData, ZtgData,
+    },
+    Error, SentenceType,
+};
+
+/// A float with at most two decimals, as written by `{:.2}`.
+fn hundredths(range: core::ops::Range<i32>) -> impl Strategy<Value = f32> {
+    range.prop_map(|n| n as f32 / 100.0)
+}
+
+/// A float with at most one decimal.
+fn tenths(range: core::ops::Range<i32>) -> impl Strategy<Value = f32> {
+    range.prop_map(|n| n as f32 / 10.0)
+}
+
+fn waypoint_id() -> impl Strategy<Value = ArrayString<TEXT_PARAMETER_MAX_LEN>> {
+    "[A-Z0-9]{1,10}".prop_map(|id| ArrayString::from(&id).unwrap())
+}
+
+fn status() -> impl Strategy<Value = bool> {
+    any::<bool>()
+}
+
+impl Arbitrary for ApaData {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            (status(), status(), option::of(hundredths(0..100_000))),
+            prop_oneof![Just(SteerDirection::Left), Just(SteerDirection::Right)],
+            prop_oneof![
+                Just(DistanceUnit::NauticalMiles),
+                Just(DistanceUnit::Kilometers)
+            ],
+            (status(), status()),
+            option::of((0..360).prop_map(|bearing| bearing as f32)),
+            prop_oneof![Just(Reference::Magnetic), Just(Reference::True)],
+            option::of(waypoint_id()),
+        )
+            .prop_map(
+                |(
+                    (status_warning, status_cycle_warning, cross_track_error_magnitude),
+                    steer_direction,
+                    cross_track_units,
+                    (status_arrived, status_passed),
+                    bearing_origin_destination,
+                    magnetic_true,
+                    waypoint_id,
+                )| ApaData {
+                    status_warning: Some(status_warning),
+                    status_cycle_warning: Some(status_cycle_warning),
+                    cross_track_error_magnitude,
+                    steer_direction: Some(steer_direction),
+                    cross_track_units: Some(cross_track_units),
+                    status_arrived: Some(status_arrived),
+                    status_passed: Some(status_passed),
+                    bearing_origin_destination,
+                    magnetic_true: Some(magnetic_true),
+                    waypoint_id,
+                },
+            )
+            .boxed()
+    }
+}
+
+impl Arbitrary for ZtgData {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        let time = (0..24u32, 0..60u32, 0..60u32, 0..100u32).prop_map(|(h, m, s, centis)| {
+            NaiveTime::from_hms_milli_opt(h, m, s, centis * 10).unwrap()
+        });
+        // including the negative durations and the ones of 24 hours and more,
+        // which can't be encoded
+        let duration =
+            (-8_640_000..17_280_000i64).prop_map(|centis| Duration::milliseconds(centis * 10));
+
+        (
+            option::of(time),
+            option::of(duration),
+            option::of(waypoint_id()),
+        )
+            .prop_map(|(fix_time, fix_duration, waypoint_id)| ZtgData {
+                fix_time,
+                fix_duration,
+                waypoint_id,
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for RpmData {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            option::of(prop_oneof![Just(RpmSource::Shaft), Just(RpmSource::Engine)]),
+            option::of(any::<u8>()),
+            option::of(tenths(-100_000..100_000)),
+            option::of(tenths(-1000..1001)),
+            option::of(status()),
+        )
+            .prop_map(|(source, source_number, rpm, pitch, valid)| RpmData {
+                source,
+                source_number,
+                rpm,
+                pitch,
+                valid,
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for ThsData {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            option::of(hundredths(0..36_000)),
+            prop_oneof![Just('A'), Just('E'), Just('M'), Just('S'), Just('V')],
+        )
+            .prop_map(|(heading, mode)| ThsData { heading, mode })
+            .boxed()
+    }
+}
+
+/// Encodes `data` and parses the sentence again with `parse`.
+macro_rules! round_trip {
+    ($data:expr, $parse:ident) => {{
+        let mut encoded = heapless::String::<82>::new();
+        $data.write_sentence("GP", &mut encoded).unwrap();
+        let sentence = parse_nmea_sentence(&encoded).unwrap();
+        assert_eq!(sentence.checksum, Some(sentence.calc_checksum()));
+        $parse(sentence).unwrap()
+    }};
+}
+
+proptest! {
+    #[test]
+    fn apa_round_trip(data: ApaData) {
+        assert_eq!(round_trip!(data, parse_apa), data);
+    }
+
+    #[test]
+    fn ztg_round_trip(data: ZtgData) {
+        match data.fix_duration {
+            Some(duration) if duration < Duration::zero() || duration >= Duration::days(1) => {
+                let mut encoded = heapless::String::<82>::new();
+                assert_eq!(
+                    data.write_sentence("GP", &mut encoded),
+                    Err(Error::InvalidFieldValue(SentenceType::ZTG))
+                );
+            }
+            _ => assert_eq!(round_trip!(data, parse_ztg), data),
+        }
+    }
+
+    #[test]
+    fn rpm_round_trip(data: RpmData) {
+        assert_eq!(round_trip!(data, parse_rpm), data);
+    }
+
+    #[test]
+    fn ths_round_trip(data: ThsData) {
+        assert_eq!(round_trip!(data, parse_ths), data);
+    }
+}
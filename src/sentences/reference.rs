@@ -0,0 +1,47 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Error;
+
+/// Reference of a bearing or heading, `M` for magnetic and `T` for true north.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reference {
+    Magnetic,
+    True,
+}
+
+impl TryFrom<char> for Reference {
+    type Error = Error<'static>;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'M' => Ok(Reference::Magnetic),
+            'T' => Ok(Reference::True),
+            _ => Err(Error::InvalidIndicator(c)),
+        }
+    }
+}
+
+impl From<Reference> for char {
+    fn from(reference: Reference) -> Self {
+        match reference {
+            Reference::Magnetic => 'M',
+            Reference::True => 'T',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reference_from_char() {
+        assert_eq!(Reference::try_from('M'), Ok(Reference::Magnetic));
+        assert_eq!(Reference::try_from('T'), Ok(Reference::True));
+        assert_eq!(Reference::try_from('m'), Err(Error::InvalidIndicator('m')));
+        assert_eq!(char::from(Reference::True), 'T');
+    }
+}
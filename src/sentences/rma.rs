@@ -0,0 +1,227 @@
+use nom::{
+    character::complete::{anychar, char},
+    combinator::{cond, map_res, opt},
+    number::complete::float,
+    IResult,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    navigation::Position,
+    parse::NmeaSentence,
+    sentences::{
+        utils::{
+            check_lat_lon, parse_lat_lon, parse_magnetic_variation, parse_valid_status, Speed,
+        },
+        Latitude, Longitude,
+    },
+    Error, SentenceType,
+};
+
+use super::{faa_mode::parse_faa_mode, FaaMode};
+
+/// RMA - Recommended Minimum Specific Loran-C Data
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_rma_recommended_minimum_navigation_information>
+///
+/// ```text
+///        1 2       3 4        5 6   7   8   9   10  11 12
+///        | |       | |        | |   |   |   |   |   |  |
+/// $--RMA,A,ddmm.mm,a,dddmm.mm,a,x.x,x.x,x.x,x.x,x.x,a,m*hh<CR><LF>
+/// ```
+/// 1. Blink Warning / Status, `A` = Valid, `V` = Warning
+/// 2. Latitude
+/// 3. `N` or `S`
+/// 4. Longitude
+/// 5. `E` or `W`
+/// 6. Time Difference A, microseconds
+/// 7. Time Difference B, microseconds
+/// 8. Speed Over Ground, knots
+/// 9. Track Made Good, degrees true
+/// 10. Magnetic Variation, degrees
+/// 11. `E` or `W`
+/// 12. FAA mode indicator (NMEA 2.3 and later)
+///
+/// Example:
+/// ```text
+/// $GPRMA,A,4917.24,S,12309.57,W,1000.0,2000.0,123.4,321.0,10,E,A*38
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RmaData {
+    /// `true` if the data is valid, `false` on a blink warning
+    pub status: bool,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    /// Time difference A, microseconds
+    pub time_difference_a: Option<f32>,
+    /// Time difference B, microseconds
+    pub time_difference_b: Option<f32>,
+    /// Speed over ground, knots
+    pub speed_over_ground: Option<f32>,
+    /// Track made good, degrees true
+    pub course_over_ground: Option<f32>,
+    /// Magnetic variation, positive to the East and negative to the West
+    pub magnetic_variation: Option<f32>,
+    pub faa_mode: Option<FaaMode>,
+}
+
+impl RmaData {
+    /// The latitude of the fix as a [`Latitude`].
+    pub fn latitude(&self) -> Option<Latitude> {
+        self.latitude.map(Latitude::new)
+    }
+
+    /// The longitude of the fix as a [`Longitude`].
+    pub fn longitude(&self) -> Option<Longitude> {
+        self.longitude.map(Longitude::new)
+    }
+
+    /// Position of the fix, if both latitude and longitude are present.
+    pub fn position(&self) -> Option<Position> {
+        Some(Position::new(self.latitude?, self.longitude?))
+    }
+
+    /// Speed over ground as a typed [`Speed`].
+    pub fn ground_speed(&self) -> Option<Speed> {
+        self.speed_over_ground.map(Speed::from_knots)
+    }
+}
+
+/// # Parse RMA message
+///
+/// See: <https://gpsd.gitlab.io/gpsd/NMEA.html#_rma_recommended_minimum_navigation_information>
+pub fn parse_rma(sentence: NmeaSentence) -> Result<RmaData, Error> {
+    if sentence.message_id != SentenceType::RMA {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::RMA,
+            found: sentence.message_id,
+        })
+    } else {
+        let data = do_parse_rma(sentence.data)?.1;
+        check_lat_lon(data.latitude, data.longitude)?;
+        Ok(data)
+    }
+}
+
+fn do_parse_rma(i: &str) -> IResult<&str, RmaData> {
+    // 1. Blink Warning / Status
+    let (i, status) = parse_valid_status(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. Latitude
+    // 3. `N` or `S`
+    // 4. Longitude
+    // 5. `E` or `W`
+    let (i, lat_lon) = parse_lat_lon(i)?;
+    let (i, _) = char(',')(i)?;
+    // 6. Time Difference A, microseconds
+    let (i, time_difference_a) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 7. Time Difference B, microseconds
+    let (i, time_difference_b) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 8. Speed Over Ground, knots
+    let (i, speed_over_ground) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 9. Track Made Good, degrees true
+    let (i, course_over_ground) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 10. Magnetic Variation, degrees
+    // 11. `E` or `W`
+    let (i, magnetic_variation) = parse_magnetic_variation(i)?;
+    let (i, next) = opt(char(','))(i)?;
+    // 12. FAA mode indicator (NMEA 2.3 and later)
+    let (i, faa_mode) = cond(
+        next.is_some(),
+        opt(map_res(anychar, |c| {
+            parse_faa_mode(c).ok_or("invalid FAA mode")
+        })),
+    )(i)?;
+
+    Ok((
+        i,
+        RmaData {
+            status,
+            latitude: lat_lon.map(|(latitude, _)| latitude.degrees()),
+            longitude: lat_lon.map(|(_, longitude)| longitude.degrees()),
+            time_difference_a,
+            time_difference_b,
+            speed_over_ground,
+            course_over_ground,
+            magnetic_variation,
+            faa_mode: faa_mode.flatten(),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_rma() {
+        let s = parse_nmea_sentence(
+            "$GPRMA,A,4917.24,S,12309.57,W,1000.0,2000.0,123.4,321.0,10,E,A*38",
+        )
+        .unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_rma(s).unwrap();
+
+        assert!(data.status);
+        assert_relative_eq!(data.latitude.unwrap(), -(49.0 + 17.24 / 60.));
+        assert_relative_eq!(data.longitude.unwrap(), -(123.0 + 9.57 / 60.));
+        assert_relative_eq!(data.time_difference_a.unwrap(), 1000.0);
+        assert_relative_eq!(data.time_difference_b.unwrap(), 2000.0);
+        assert_relative_eq!(data.speed_over_ground.unwrap(), 123.4);
+        assert_relative_eq!(data.course_over_ground.unwrap(), 321.0);
+        assert_relative_eq!(data.magnetic_variation.unwrap(), 10.0);
+        assert_eq!(data.faa_mode, Some(FaaMode::Autonomous));
+    }
+
+    #[test]
+    fn test_parse_rma_without_faa_mode() {
+        let s =
+            parse_nmea_sentence("$GPRMA,A,4917.24,S,12309.57,W,1000.0,2000.0,123.4,321.0,10,E*55")
+                .unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_rma(s).unwrap();
+        assert!(data.position().is_some());
+        assert_eq!(data.faa_mode, None);
+    }
+
+    #[test]
+    fn test_parse_rma_empty_fields() {
+        let s = parse_nmea_sentence("$GPRMA,V,,,,,,,,,,*33").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(
+            parse_rma(s),
+            Ok(RmaData {
+                status: false,
+                latitude: None,
+                longitude: None,
+                time_difference_a: None,
+                time_difference_b: None,
+                speed_over_ground: None,
+                course_over_ground: None,
+                magnetic_variation: None,
+                faa_mode: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_rma_wrong_sentence() {
+        let s = parse_nmea_sentence("$GPHDT,274.07,T*03").unwrap();
+        assert!(matches!(
+            parse_rma(s),
+            Err(Error::WrongSentenceHeader { .. })
+        ));
+    }
+}
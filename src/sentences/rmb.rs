@@ -0,0 +1,243 @@
+use arrayvec::ArrayString;
+use nom::{
+    bytes::complete::is_not,
+    character::complete::{anychar, char},
+    combinator::{map_res, opt},
+    number::complete::float,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{
+    faa_mode::parse_faa_mode,
+    nom_parse_failure,
+    utils::{array_string, check_lat_lon, parse_lat_lon, parse_valid_status},
+    FaaMode, SteerDirection,
+};
+use crate::{
+    parse::{NmeaSentence, TEXT_PARAMETER_MAX_LEN},
+    sentences::{Latitude, Longitude},
+    Error, SentenceType,
+};
+
+/// RMB - Recommended Minimum Navigation Information
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_rmb_recommended_minimum_navigation_information>
+///
+/// ```text
+///        1 2   3 4    5    6       7 8        9 10  11  12  13 14
+///        | |   | |    |    |       | |        | |   |   |   |  |
+/// $--RMB,A,x.x,a,c--c,c--c,llll.ll,a,yyyyy.yy,a,x.x,x.x,x.x,A,m*hh<CR><LF>
+/// ```
+///
+/// 1. Status, A = Active, V = Void
+/// 2. Cross Track error - nautical miles
+/// 3. Direction to Steer, L or R
+/// 4. Origin Waypoint ID
+/// 5. Destination Waypoint ID
+/// 6. Destination Waypoint Latitude
+/// 7. N or S
+/// 8. Destination Waypoint Longitude
+/// 9. E or W
+/// 10. Range to destination in nautical miles
+/// 11. Bearing to destination in degrees True
+/// 12. Destination closing velocity in knots
+/// 13. Arrival Status, A = Arrival Circle Entered, V = Not Entered
+/// 14. FAA mode indicator (NMEA 2.3 and later)
+///
+/// Example:
+/// ```text
+/// $GPRMB,A,0.66,L,003,004,4917.24,N,12309.57,W,001.3,052.5,000.5,V*20
+/// ```
+///
+/// The waypoint IDs are bounded by `MAX_LEN` characters, [`TEXT_PARAMETER_MAX_LEN`] by default.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RmbData<const MAX_LEN: usize = TEXT_PARAMETER_MAX_LEN> {
+    /// Status, `true` when the data is valid (active)
+    pub status: bool,
+    /// Cross track error in nautical miles
+    pub cross_track_error: Option<f32>,
+    /// Direction to steer to get back on the track
+    pub steer_direction: Option<SteerDirection>,
+    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
+    pub origin_waypoint_id: Option<ArrayString<MAX_LEN>>,
+    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
+    pub destination_waypoint_id: Option<ArrayString<MAX_LEN>>,
+    /// Latitude of the destination waypoint in degrees, positive to the North
+    pub destination_latitude: Option<f64>,
+    /// Longitude of the destination waypoint in degrees, positive to the East
+    pub destination_longitude: Option<f64>,
+    /// Range to the destination in nautical miles
+    pub range_to_destination: Option<f32>,
+    /// Bearing to the destination in degrees True
+    pub bearing_to_destination: Option<f32>,
+    /// Destination closing velocity in knots
+    pub closing_velocity: Option<f32>,
+    pub arrival_circle_entered: bool,
+    pub faa_mode: Option<FaaMode>,
+}
+
+impl<const MAX_LEN: usize> RmbData<MAX_LEN> {
+    /// The latitude of the destination waypoint as a [`Latitude`].
+    pub fn destination_latitude(&self) -> Option<Latitude> {
+        self.destination_latitude.map(Latitude::new)
+    }
+
+    /// The longitude of the destination waypoint as a [`Longitude`].
+    pub fn destination_longitude(&self) -> Option<Longitude> {
+        self.destination_longitude.map(Longitude::new)
+    }
+}
+
+/// # Parse RMB message
+///
+/// The waypoint IDs are bounded by [`TEXT_PARAMETER_MAX_LEN`], use
+/// [`parse_rmb_with_max_len`] for a different bound.
+pub fn parse_rmb(sentence: NmeaSentence) -> Result<RmbData, Error> {
+    parse_rmb_with_max_len(sentence)
+}
+
+/// Parse RMB message with the waypoint IDs bounded by `MAX_LEN` characters.
+///
+/// # Errors
+///
+/// [`Error::ParameterLength`] with `max_length` set to `MAX_LEN` if one of them is longer.
+pub fn parse_rmb_with_max_len<const MAX_LEN: usize>(
+    sentence: NmeaSentence,
+) -> Result<RmbData<MAX_LEN>, Error> {
+    if sentence.message_id != SentenceType::RMB {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::RMB,
+            found: sentence.message_id,
+        })
+    } else {
+        do_parse_rmb(sentence.data)
+    }
+}
+
+fn do_parse_rmb<const MAX_LEN: usize>(i: &str) -> Result<RmbData<MAX_LEN>, Error<'_>> {
+    let (i, status) = parse_valid_status(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, cross_track_error) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, steer_direction) = opt(map_res(anychar, SteerDirection::try_from))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, origin_waypoint_id) = opt(is_not(","))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, destination_waypoint_id) = opt(is_not(","))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, destination) = parse_lat_lon(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, range_to_destination) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, bearing_to_destination) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, closing_velocity) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, arrival_circle_entered) = parse_valid_status(i)?;
+    let (i, _) = opt(char(','))(i)?;
+    let (_, faa_mode) = opt(anychar)(i)?;
+    let faa_mode = faa_mode
+        .map(|mode| parse_faa_mode(mode).ok_or_else(|| nom_parse_failure(i)))
+        .transpose()?;
+
+    let data = RmbData {
+        status,
+        cross_track_error,
+        steer_direction,
+        origin_waypoint_id: origin_waypoint_id
+            .map(array_string::<MAX_LEN>)
+            .transpose()?,
+        destination_waypoint_id: destination_waypoint_id
+            .map(array_string::<MAX_LEN>)
+            .transpose()?,
+        destination_latitude: destination.map(|(latitude, _)| latitude.degrees()),
+        destination_longitude: destination.map(|(_, longitude)| longitude.degrees()),
+        range_to_destination,
+        bearing_to_destination,
+        closing_velocity,
+        arrival_circle_entered,
+        faa_mode,
+    };
+    check_lat_lon(data.destination_latitude, data.destination_longitude)?;
+
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_rmb() {
+        let s = parse_nmea_sentence(
+            "$GPRMB,A,0.66,L,003,004,4917.24,N,12309.57,W,001.3,052.5,000.5,V*20",
+        )
+        .unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+
+        let data = parse_rmb(s).unwrap();
+        assert!(data.status);
+        assert_relative_eq!(data.cross_track_error.unwrap(), 0.66);
+        assert_eq!(data.steer_direction, Some(SteerDirection::Left));
+        assert_eq!(data.origin_waypoint_id.as_deref(), Some("003"));
+        assert_eq!(data.destination_waypoint_id.as_deref(), Some("004"));
+        assert_relative_eq!(data.destination_latitude.unwrap(), 49. + 17.24 / 60.);
+        assert_relative_eq!(data.destination_longitude.unwrap(), -(123. + 9.57 / 60.));
+        assert_eq!(
+            data.destination_latitude(),
+            Some(Latitude::new(49. + 17.24 / 60.))
+        );
+        assert_eq!(data.destination_longitude().unwrap().hemisphere(), 'W');
+        assert_relative_eq!(data.range_to_destination.unwrap(), 1.3);
+        assert_relative_eq!(data.bearing_to_destination.unwrap(), 52.5);
+        assert_relative_eq!(data.closing_velocity.unwrap(), 0.5);
+        assert!(!data.arrival_circle_entered);
+        assert_eq!(data.faa_mode, None);
+    }
+
+    #[test]
+    fn test_parse_rmb_nmea_2_3() {
+        let s = parse_nmea_sentence(
+            "$GPRMB,A,4.08,L,EGLL,EGLM,5130.02,N,00046.34,W,004.6,213.9,122.9,A,D*55",
+        )
+        .unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+
+        let data = parse_rmb(s).unwrap();
+        assert_eq!(data.destination_waypoint_id.as_deref(), Some("EGLM"));
+        assert!(data.arrival_circle_entered);
+        assert_eq!(data.faa_mode, Some(FaaMode::Differential));
+    }
+
+    #[test]
+    fn test_parse_rmb_void() {
+        let s = parse_nmea_sentence("$GPRMB,V,,,,,,,,,,,,V,N*04").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+
+        assert_eq!(
+            parse_rmb(s).unwrap(),
+            RmbData {
+                status: false,
+                cross_track_error: None,
+                steer_direction: None,
+                origin_waypoint_id: None,
+                destination_waypoint_id: None,
+                destination_latitude: None,
+                destination_longitude: None,
+                range_to_destination: None,
+                bearing_to_destination: None,
+                closing_velocity: None,
+                arrival_circle_entered: false,
+                faa_mode: Some(FaaMode::DataNotValid),
+            }
+        );
+    }
+}
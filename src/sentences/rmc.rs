@@ -1,4 +1,5 @@
-use chrono::{NaiveDate, NaiveTime};
+#[cfg(feature = "chrono")]
+use chrono::NaiveDateTime;
 use nom::{
     character::complete::{anychar, char, one_of},
     combinator::{cond, map_res, opt},
@@ -10,8 +11,12 @@ use nom::{
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    navigation::Position,
     parse::NmeaSentence,
-    sentences::utils::{parse_date, parse_hms, parse_lat_lon},
+    sentences::{
+        utils::{check_lat_lon, parse_date, parse_hms, parse_lat_lon, Date, Speed, Time},
+        Latitude, Longitude,
+    },
     Error, SentenceType,
 };
 
@@ -80,13 +85,14 @@ pub enum RmcNavigationStatus {
 ///     `A` = autonomous, `D` = differential, `E` = Estimated,
 ///     `M` = Manual input mode, `N` = not valid, `S` = Simulator, `V` = Valid
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct RmcData {
     #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
-    pub fix_time: Option<NaiveTime>,
+    pub fix_time: Option<Time>,
     #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
-    pub fix_date: Option<NaiveDate>,
+    pub fix_date: Option<Date>,
     pub status_of_fix: RmcStatusOfFix,
     pub lat: Option<f64>,
     pub lon: Option<f64>,
@@ -97,6 +103,35 @@ pub struct RmcData {
     pub nav_status: Option<RmcNavigationStatus>,
 }
 
+impl RmcData {
+    /// The latitude of the fix as a [`Latitude`].
+    pub fn latitude(&self) -> Option<Latitude> {
+        self.lat.map(Latitude::new)
+    }
+
+    /// The longitude of the fix as a [`Longitude`].
+    pub fn longitude(&self) -> Option<Longitude> {
+        self.lon.map(Longitude::new)
+    }
+
+    /// Position of the fix, if both latitude and longitude are present.
+    pub fn position(&self) -> Option<Position> {
+        Some(Position::new(self.lat?, self.lon?))
+    }
+
+    /// UTC date time of the fix, if both `fix_date` and `fix_time` are present.
+    #[cfg(feature = "chrono")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+    pub fn fix_date_time(&self) -> Option<NaiveDateTime> {
+        Some(NaiveDateTime::new(self.fix_date?, self.fix_time?))
+    }
+
+    /// Speed over ground as a typed [`Speed`].
+    pub fn ground_speed(&self) -> Option<Speed> {
+        self.speed_over_ground.map(Speed::from_knots)
+    }
+}
+
 fn do_parse_rmc(i: &str) -> IResult<&str, RmcData> {
     // 1.  UTC of position fix, `hh` is hours, `mm` is minutes, `ss.ss` is seconds.
     let (i, fix_time) = opt(parse_hms)(i)?;
@@ -146,8 +181,8 @@ fn do_parse_rmc(i: &str) -> IResult<&str, RmcData> {
             fix_time,
             fix_date,
             status_of_fix,
-            lat: lat_lon.map(|v| v.0),
-            lon: lat_lon.map(|v| v.1),
+            lat: lat_lon.map(|(latitude, _)| latitude.degrees()),
+            lon: lat_lon.map(|(_, longitude)| longitude.degrees()),
             speed_over_ground,
             true_course,
             magnetic_variation,
@@ -200,13 +235,16 @@ pub fn parse_rmc(sentence: NmeaSentence) -> Result<RmcData, Error> {
             found: sentence.message_id,
         })
     } else {
-        Ok(do_parse_rmc(sentence.data)?.1)
+        let data = do_parse_rmc(sentence.data)?.1;
+        check_lat_lon(data.lat, data.lon)?;
+        Ok(data)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "chrono"))]
 mod tests {
     use approx::assert_relative_eq;
+    use chrono::{NaiveDate, NaiveTime};
 
     use super::*;
     use crate::parse::parse_nmea_sentence;
@@ -217,8 +255,8 @@ mod tests {
             "$GPRMC,225446.33,A,4916.45,N,12311.12,W,000.5,054.7,191194,020.3,E,A*2B",
         )
         .unwrap();
-        assert_eq!(s.checksum, s.calc_checksum());
-        assert_eq!(s.checksum, 0x2b);
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(s.checksum, Some(0x2b));
         let rmc_data = parse_rmc(s).unwrap();
         assert_eq!(
             rmc_data.fix_time,
@@ -252,8 +290,8 @@ mod tests {
             "$GPRMC,225446.33,A,4916.45,N,12311.12,W,000.5,054.7,191194,020.3,E*46",
         )
         .unwrap();
-        assert_eq!(s.checksum, s.calc_checksum());
-        assert_eq!(s.checksum, 0x46);
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(s.checksum, Some(0x46));
         let RmcData {
             fix_time,
             status_of_fix,
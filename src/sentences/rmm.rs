@@ -0,0 +1,98 @@
+use arrayvec::ArrayString;
+use nom::{bytes::complete::take_while, combinator::eof};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::utils::array_string;
+use crate::{
+    parse::{NmeaSentence, TEXT_PARAMETER_MAX_LEN},
+    Error, SentenceType,
+};
+
+/// RMM - Map Datum
+///
+/// ```text
+///        1    2
+///        |    |
+/// $--RMM,c--c*hh<CR><LF>
+/// ```
+/// 1. Name of the datum in use for navigation, e.g. `WGS 84`
+/// 2. Checksum
+///
+/// Garmin receivers send it along with the RMC sentence. Use DTM for the
+/// offsets of a local datum.
+///
+/// Example:
+/// ```text
+/// $GPRMM,WGS 84*06
+/// ```
+///
+/// The datum is bounded by `MAX_LEN` characters, [`TEXT_PARAMETER_MAX_LEN`] by default.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RmmData<const MAX_LEN: usize = TEXT_PARAMETER_MAX_LEN> {
+    /// Name of the datum, empty if not reported
+    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
+    pub datum: ArrayString<MAX_LEN>,
+}
+
+/// # Parse RMM message
+///
+/// The datum is bounded by [`TEXT_PARAMETER_MAX_LEN`], use
+/// [`parse_rmm_with_max_len`] for a different bound.
+pub fn parse_rmm(sentence: NmeaSentence) -> Result<RmmData, Error> {
+    parse_rmm_with_max_len(sentence)
+}
+
+/// Parse RMM message with the datum bounded by `MAX_LEN` characters.
+///
+/// # Errors
+///
+/// [`Error::ParameterLength`] with `max_length` set to `MAX_LEN` if the datum is longer.
+pub fn parse_rmm_with_max_len<const MAX_LEN: usize>(
+    sentence: NmeaSentence,
+) -> Result<RmmData<MAX_LEN>, Error> {
+    if sentence.message_id != SentenceType::RMM {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::RMM,
+            found: sentence.message_id,
+        })
+    } else {
+        do_parse_rmm(sentence.data)
+    }
+}
+
+fn do_parse_rmm<const MAX_LEN: usize>(i: &str) -> Result<RmmData<MAX_LEN>, Error<'_>> {
+    let (i, datum) = take_while(|c| c != ',')(i)?;
+    eof(i)?;
+
+    Ok(RmmData {
+        datum: array_string::<MAX_LEN>(datum)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_rmm() {
+        let s = parse_nmea_sentence("$GPRMM,WGS 84*06").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(parse_rmm(s).unwrap().datum.as_str(), "WGS 84");
+
+        let s = parse_nmea_sentence("$GPRMM,*69").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert!(parse_rmm(s).unwrap().datum.is_empty());
+    }
+
+    #[test]
+    fn test_parse_rmm_invalid() {
+        let s = parse_nmea_sentence("$GPRMC,,V,,,,,,,,,,N*53").unwrap();
+        assert!(parse_rmm(s).is_err());
+    }
+}
@@ -35,6 +35,7 @@ pub enum PgrmzFixType {
 ///
 /// Example: `$PGRMZ,2282,f,3*21`
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct PgrmzData {
@@ -87,8 +88,8 @@ mod tests {
     #[test]
     fn test_successful_parse() {
         let s = parse_nmea_sentence("$PGRMZ,2282,f,3*21").unwrap();
-        assert_eq!(s.checksum, s.calc_checksum());
-        assert_eq!(s.checksum, 0x21);
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(s.checksum, Some(0x21));
 
         let data = parse_pgrmz(s).unwrap();
         assert_eq!(data.altitude, 2282);
@@ -0,0 +1,201 @@
+use core::fmt::{self, Write};
+
+use nom::{
+    character::complete::{anychar, char},
+    combinator::{map_res, opt},
+    number::complete::float,
+    IResult,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    encode::{write_opt, write_status, ToNmeaSentence},
+    parse::NmeaSentence,
+    sentences::utils::{number, parse_valid_status},
+    Error, SentenceType,
+};
+
+/// RPM - Revolutions
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_rpm_revolutions>
+///
+/// ```text
+///        1 2 3   4   5 6
+///        | | |   |   | |
+/// $--RPM,a,x,x.x,x.x,A*hh<CR><LF>
+/// ```
+///
+/// 1. Source, S = Shaft, E = Engine
+/// 2. Engine or shaft number
+/// 3. Speed, revolutions per minute, negative for counter-clockwise
+/// 4. Propeller pitch, % of maximum, negative for astern
+/// 5. Status, A = Data valid, V = Data invalid
+/// 6. Checksum
+///
+/// Example:
+/// ```text
+/// $IIRPM,E,2,-1200.5,-25.0,A*54
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RpmData {
+    pub source: Option<RpmSource>,
+    /// Engine or shaft number
+    pub source_number: Option<u8>,
+    /// Speed, revolutions per minute, negative for counter-clockwise
+    pub rpm: Option<f32>,
+    /// Propeller pitch, % of maximum, negative for astern
+    pub pitch: Option<f32>,
+    /// `None` if the status letter is absent
+    pub valid: Option<bool>,
+}
+
+/// Source of the [`RpmData`]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RpmSource {
+    Shaft,
+    Engine,
+}
+
+impl TryFrom<char> for RpmSource {
+    type Error = Error<'static>;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'S' => Ok(RpmSource::Shaft),
+            'E' => Ok(RpmSource::Engine),
+            _ => Err(Error::InvalidIndicator(c)),
+        }
+    }
+}
+
+impl From<RpmSource> for char {
+    fn from(source: RpmSource) -> Self {
+        match source {
+            RpmSource::Shaft => 'S',
+            RpmSource::Engine => 'E',
+        }
+    }
+}
+
+/// # Parse RPM message
+///
+/// See: <https://gpsd.gitlab.io/gpsd/NMEA.html#_rpm_revolutions>
+pub fn parse_rpm(sentence: NmeaSentence) -> Result<RpmData, Error> {
+    if sentence.message_id != SentenceType::RPM {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::RPM,
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_rpm(sentence.data)?.1)
+    }
+}
+
+fn do_parse_rpm(i: &str) -> IResult<&str, RpmData> {
+    // 1. Source, S = Shaft, E = Engine
+    let (i, source) = opt(map_res(anychar, RpmSource::try_from))(i)?;
+    let (i, _) = char(',')(i)?;
+
+    // 2. Engine or shaft number
+    let (i, source_number) = opt(number::<u8>)(i)?;
+    let (i, _) = char(',')(i)?;
+
+    // 3. Speed, revolutions per minute
+    let (i, rpm) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+
+    // 4. Propeller pitch, % of maximum
+    let (i, pitch) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+
+    // 5. Status, can be empty
+    let (i, valid) = opt(parse_valid_status)(i)?;
+
+    Ok((
+        i,
+        RpmData {
+            source,
+            source_number,
+            rpm,
+            pitch,
+            valid,
+        },
+    ))
+}
+
+impl ToNmeaSentence for RpmData {
+    fn sentence_type(&self) -> SentenceType {
+        SentenceType::RPM
+    }
+
+    fn write_data<W: Write>(&self, f: &mut W) -> fmt::Result {
+        if let Some(source) = self.source {
+            f.write_char(source.into())?;
+        }
+        f.write_char(',')?;
+        write_opt(f, &self.source_number)?;
+        f.write_char(',')?;
+        write_opt(f, &self.rpm)?;
+        f.write_char(',')?;
+        write_opt(f, &self.pitch)?;
+        f.write_char(',')?;
+        write_status(f, self.valid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_rpm() {
+        let s = parse_nmea_sentence("$IIRPM,E,2,-1200.5,-25.0,A*54").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_rpm(s).unwrap();
+
+        assert_eq!(data.source, Some(RpmSource::Engine));
+        assert_eq!(data.source_number, Some(2));
+        assert_relative_eq!(data.rpm.unwrap(), -1200.5);
+        assert_relative_eq!(data.pitch.unwrap(), -25.0);
+        assert_eq!(data.valid, Some(true));
+    }
+
+    #[test]
+    fn test_parse_rpm_empty_status() {
+        let s = parse_nmea_sentence("$IIRPM,S,1,31,100,*32").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_rpm(s).unwrap();
+
+        assert_eq!(data.source, Some(RpmSource::Shaft));
+        assert_eq!(data.source_number, Some(1));
+        assert_relative_eq!(data.rpm.unwrap(), 31.0);
+        assert_relative_eq!(data.pitch.unwrap(), 100.0);
+        assert_eq!(data.valid, None);
+    }
+
+    #[test]
+    fn test_parse_rpm_invalid_source() {
+        let s = parse_nmea_sentence("$IIRPM,X,1,31,100,A*78").unwrap();
+        assert!(parse_rpm(s).is_err());
+    }
+
+    #[test]
+    fn test_encode_rpm_round_trip() {
+        for line in ["$IIRPM,S,1,31,100,A*73", "$IIRPM,S,1,31,100,*32"] {
+            let data = parse_rpm(parse_nmea_sentence(line).unwrap()).unwrap();
+            let mut encoded = heapless::String::<82>::new();
+            data.write_sentence("II", &mut encoded).unwrap();
+            assert_eq!(encoded, line);
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use arrayvec::ArrayVec;
+use nom::{
+    character::complete::{char, satisfy},
+    combinator::{eof, opt},
+    sequence::separated_pair,
+    IResult,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::utils::number;
+use crate::{parse::NmeaSentence, Error, SentenceType};
+
+/// Maximum number of frequencies in a single SFI sentence.
+pub const SFI_MAX_FREQUENCIES: usize = 6;
+
+/// Scanned frequency of a [`SfiData`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SfiFrequency {
+    /// Frequency in units of 100 Hz
+    pub frequency: Option<u32>,
+    /// Mode of operation, the raw letter as in [`FsiData`](super::FsiData)
+    pub mode: Option<char>,
+}
+
+/// SFI - Scanning Frequency Information
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_sfi_scanning_frequency_information>
+///
+/// ```text
+///        1   2   3      4 5      6
+///        |   |   |      | |      |
+/// $--SFI,x.x,x.x,xxxxxx,c,xxxxxx,c*hh<CR><LF>
+/// ```
+/// 1. Total number of messages
+/// 2. Message number
+/// 3. Frequency 1, 100 Hz
+/// 4. Mode 1
+/// 5. Frequency 2 to 6 and their mode
+/// 6. Checksum
+///
+/// Each frequency and mode pair present in the sentence is kept in
+/// `frequencies`, including empty ones. More than [`SFI_MAX_FREQUENCIES`]
+/// pairs result in an [`Error::ParameterLength`]. The frequencies of a
+/// scan list spread over several sentences can be joined with
+/// [`concat_sfi_frequencies`].
+///
+/// Example:
+/// ```text
+/// $RASFI,2,1,021950,d,025460,m,041250,s,065215,w,084142,q,124500,|*48
+/// $RASFI,2,2,156800,d,,*21
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SfiData {
+    pub total_messages: u16,
+    pub message_number: u16,
+    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
+    pub frequencies: ArrayVec<SfiFrequency, SFI_MAX_FREQUENCIES>,
+}
+
+/// # Parse SFI message
+///
+/// See: <https://gpsd.gitlab.io/gpsd/NMEA.html#_sfi_scanning_frequency_information>
+pub fn parse_sfi(sentence: NmeaSentence) -> Result<SfiData, Error> {
+    if sentence.message_id != SentenceType::SFI {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::SFI,
+            found: sentence.message_id,
+        })
+    } else {
+        do_parse_sfi(sentence.data)
+    }
+}
+
+fn do_parse_sfi(i: &str) -> Result<SfiData, Error<'_>> {
+    // 1. Total number of messages
+    let (i, total_messages) = number::<u16>(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. Message number
+    let (mut i, message_number) = number::<u16>(i)?;
+
+    // 3. - 14. Frequencies and their mode
+    let mut frequencies = ArrayVec::new();
+    while let (rest, Some(_)) = opt(char(','))(i)? {
+        let (rest, (frequency, mode)) = parse_frequency_and_mode(rest)?;
+        if frequencies.is_full() {
+            return Err(Error::ParameterLength {
+                max_length: SFI_MAX_FREQUENCIES,
+                parameter_length: SFI_MAX_FREQUENCIES + (rest.split(',').count() + 1) / 2,
+            });
+        }
+        frequencies.push(SfiFrequency { frequency, mode });
+        i = rest;
+    }
+    eof(i)?;
+
+    Ok(SfiData {
+        total_messages,
+        message_number,
+        frequencies,
+    })
+}
+
+fn parse_frequency_and_mode(i: &str) -> IResult<&str, (Option<u32>, Option<char>)> {
+    separated_pair(opt(number::<u32>), char(','), opt(satisfy(|c| c != ',')))(i)
+}
+
+/// Concatenates the frequencies of the sentences of a single SFI scan list.
+///
+/// The sentences have to be complete and given in order. Empty frequency and
+/// mode pairs are kept as they are.
+///
+/// # Errors
+///
+/// - [`Error::InvalidFragments`] when sentences are missing or out of order.
+/// - [`Error::ParameterLength`] when the frequencies do not fit in `MAX_LEN`.
+pub fn concat_sfi_frequencies<const MAX_LEN: usize>(
+    sentences: &[SfiData],
+) -> Result<ArrayVec<SfiFrequency, MAX_LEN>, Error<'static>> {
+    let first = sentences.first().ok_or(Error::InvalidFragments)?;
+    if usize::from(first.total_messages) != sentences.len() {
+        return Err(Error::InvalidFragments);
+    }
+
+    let mut frequencies = ArrayVec::new();
+    for (index, sentence) in sentences.iter().enumerate() {
+        if usize::from(sentence.message_number) != index + 1
+            || sentence.total_messages != first.total_messages
+        {
+            return Err(Error::InvalidFragments);
+        }
+
+        frequencies
+            .try_extend_from_slice(&sentence.frequencies)
+            .map_err(|_| Error::ParameterLength {
+                max_length: MAX_LEN,
+                parameter_length: sentences.iter().map(|s| s.frequencies.len()).sum(),
+            })?;
+    }
+
+    Ok(frequencies)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_sfi() {
+        let s = parse_nmea_sentence(
+            "$RASFI,2,1,021950,d,025460,m,041250,s,065215,w,084142,q,124500,|*48",
+        )
+        .unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let sfi = parse_sfi(s).unwrap();
+        assert_eq!(sfi.total_messages, 2);
+        assert_eq!(sfi.message_number, 1);
+        assert_eq!(sfi.frequencies.len(), 6);
+        assert_eq!(
+            sfi.frequencies[0],
+            SfiFrequency {
+                frequency: Some(21950),
+                mode: Some('d'),
+            }
+        );
+        assert_eq!(sfi.frequencies[5].mode, Some('|'));
+
+        let s = parse_nmea_sentence("$RASFI,2,2,156800,d,,*21").unwrap();
+        let sfi = parse_sfi(s).unwrap();
+        assert_eq!(
+            sfi.frequencies.as_slice(),
+            &[
+                SfiFrequency {
+                    frequency: Some(156800),
+                    mode: Some('d'),
+                },
+                SfiFrequency {
+                    frequency: None,
+                    mode: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_sfi_too_many_frequencies() {
+        let s = parse_nmea_sentence(
+            "$RASFI,1,1,021950,d,025460,m,041250,s,065215,w,084142,q,124500,|,156800,d*25",
+        )
+        .unwrap();
+        assert_eq!(
+            parse_sfi(s),
+            Err(Error::ParameterLength {
+                max_length: SFI_MAX_FREQUENCIES,
+                parameter_length: 7
+            })
+        );
+    }
+
+    #[test]
+    fn test_concat_sfi_frequencies() {
+        let sentences = [
+            "$RASFI,2,1,021950,d,025460,m,041250,s,065215,w,084142,q,124500,|*48",
+            "$RASFI,2,2,156800,d,,*21",
+        ]
+        .map(|sentence| parse_sfi(parse_nmea_sentence(sentence).unwrap()).unwrap());
+
+        let frequencies = concat_sfi_frequencies::<12>(&sentences).unwrap();
+        assert_eq!(frequencies.len(), 8);
+        assert_eq!(frequencies[6].frequency, Some(156800));
+
+        assert_eq!(
+            concat_sfi_frequencies::<6>(&sentences),
+            Err(Error::ParameterLength {
+                max_length: 6,
+                parameter_length: 8
+            })
+        );
+        // out of order
+        assert_eq!(
+            concat_sfi_frequencies::<12>(&[sentences[1].clone(), sentences[0].clone()]),
+            Err(Error::InvalidFragments)
+        );
+        // missing sentence
+        assert_eq!(
+            concat_sfi_frequencies::<12>(&sentences[..1]),
+            Err(Error::InvalidFragments)
+        );
+    }
+}
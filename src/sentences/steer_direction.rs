@@ -0,0 +1,50 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Error;
+
+/// Direction to steer (or side of the wind), `L` for left and `R` for right.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SteerDirection {
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for SteerDirection {
+    type Error = Error<'static>;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'L' => Ok(SteerDirection::Left),
+            'R' => Ok(SteerDirection::Right),
+            _ => Err(Error::InvalidIndicator(c)),
+        }
+    }
+}
+
+impl From<SteerDirection> for char {
+    fn from(direction: SteerDirection) -> Self {
+        match direction {
+            SteerDirection::Left => 'L',
+            SteerDirection::Right => 'R',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_steer_direction_from_char() {
+        assert_eq!(SteerDirection::try_from('L'), Ok(SteerDirection::Left));
+        assert_eq!(SteerDirection::try_from('R'), Ok(SteerDirection::Right));
+        assert_eq!(
+            SteerDirection::try_from('X'),
+            Err(Error::InvalidIndicator('X'))
+        );
+        assert_eq!(char::from(SteerDirection::Left), 'L');
+    }
+}
@@ -0,0 +1,92 @@
+use nom::{
+    combinator::{eof, opt},
+    IResult,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::utils::number;
+use crate::{parse::NmeaSentence, Error, SentenceType};
+
+/// STN - Multiple Data ID
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_stn_multiple_data_id>
+///
+/// ```text
+///        1  2
+///        |  |
+/// $--STN,xx*hh<CR><LF>
+/// ```
+/// 1. Talker ID number, 00 - 99
+/// 2. Checksum
+///
+/// The sentence is sent before the data sentences of a talker, so listeners
+/// can tell multiple talkers of the same type apart.
+///
+/// Example:
+/// ```text
+/// $IISTN,03*66
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StnData {
+    /// Number of the talker sending the following sentences, 00 - 99
+    pub talker_id_number: Option<u8>,
+}
+
+/// # Parse STN message
+///
+/// See: <https://gpsd.gitlab.io/gpsd/NMEA.html#_stn_multiple_data_id>
+pub fn parse_stn(sentence: NmeaSentence) -> Result<StnData, Error> {
+    if sentence.message_id != SentenceType::STN {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::STN,
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_stn(sentence.data)?.1)
+    }
+}
+
+fn do_parse_stn(i: &str) -> IResult<&str, StnData> {
+    let (i, talker_id_number) = opt(number::<u8>)(i)?;
+    let (i, _) = eof(i)?;
+
+    Ok((i, StnData { talker_id_number }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_stn() {
+        let s = parse_nmea_sentence("$IISTN,03*66").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(
+            parse_stn(s),
+            Ok(StnData {
+                talker_id_number: Some(3)
+            })
+        );
+
+        let s = parse_nmea_sentence("$IISTN,*65").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(
+            parse_stn(s),
+            Ok(StnData {
+                talker_id_number: None
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_stn_invalid() {
+        let s = parse_nmea_sentence("$IISTN,X1*0C").unwrap();
+        assert!(parse_stn(s).is_err());
+    }
+}
@@ -0,0 +1,155 @@
+use core::{fmt, str::FromStr};
+
+use arrayvec::ArrayString;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Error;
+
+/// Talker ID, the two characters following the `$` of a sentence.
+///
+/// The common talkers have their own variant, any other one is kept as
+/// [`TalkerId::Other`].
+///
+/// # Examples
+///
+/// ```
+/// use nmea::sentences::TalkerId;
+///
+/// let talker: TalkerId = "GL".parse().unwrap();
+/// assert_eq!(talker, TalkerId::Glonass);
+/// assert_eq!("WI".parse::<TalkerId>().unwrap().as_str(), "WI");
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TalkerId {
+    /// `GP` - Global Positioning System (GPS)
+    Gps,
+    /// `GL` - GLONASS
+    Glonass,
+    /// `GA` - Galileo
+    Galileo,
+    /// `GB` or `BD` - BeiDou
+    Beidou,
+    /// `GI` - NavIC
+    NavIC,
+    /// `GQ` or `QZ` - QZSS
+    Qzss,
+    /// `GN` - Combined GNSS, the data comes from multiple constellations
+    Gnss,
+    /// `II` - Integrated instrumentation
+    IntegratedInstrumentation,
+    /// `IN` - Integrated navigation
+    IntegratedNavigation,
+    /// `AI` - Automatic Identification System (AIS)
+    Ais,
+    /// `HC` - Magnetic compass
+    Compass,
+    /// `RA` - Radar
+    Radar,
+    /// `SD` - Depth sounder
+    DepthSounder,
+    /// Any other talker ID
+    Other(#[cfg_attr(feature = "defmt-03", defmt(Display2Format))] ArrayString<2>),
+}
+
+impl TalkerId {
+    /// Returns the two character talker ID.
+    ///
+    /// For the talkers with two IDs, e.g. `GB` and `BD` for BeiDou, the
+    /// NMEA 4.1 one is returned.
+    pub fn as_str(&self) -> &str {
+        match self {
+            TalkerId::Gps => "GP",
+            TalkerId::Glonass => "GL",
+            TalkerId::Galileo => "GA",
+            TalkerId::Beidou => "GB",
+            TalkerId::NavIC => "GI",
+            TalkerId::Qzss => "GQ",
+            TalkerId::Gnss => "GN",
+            TalkerId::IntegratedInstrumentation => "II",
+            TalkerId::IntegratedNavigation => "IN",
+            TalkerId::Ais => "AI",
+            TalkerId::Compass => "HC",
+            TalkerId::Radar => "RA",
+            TalkerId::DepthSounder => "SD",
+            TalkerId::Other(id) => id.as_str(),
+        }
+    }
+}
+
+impl FromStr for TalkerId {
+    type Err = Error<'static>;
+
+    /// Parses a two character ASCII talker ID.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if !s.is_ascii() {
+            return Err(Error::ASCII);
+        }
+
+        Ok(match s {
+            "GP" => TalkerId::Gps,
+            "GL" => TalkerId::Glonass,
+            "GA" => TalkerId::Galileo,
+            "GB" | "BD" => TalkerId::Beidou,
+            "GI" => TalkerId::NavIC,
+            "GQ" | "QZ" => TalkerId::Qzss,
+            "GN" => TalkerId::Gnss,
+            "II" => TalkerId::IntegratedInstrumentation,
+            "IN" => TalkerId::IntegratedNavigation,
+            "AI" => TalkerId::Ais,
+            "HC" => TalkerId::Compass,
+            "RA" => TalkerId::Radar,
+            "SD" => TalkerId::DepthSounder,
+            _ if s.len() == 2 => TalkerId::Other(ArrayString::from(s).unwrap()),
+            _ => {
+                return Err(Error::ParameterLength {
+                    max_length: 2,
+                    parameter_length: s.len(),
+                })
+            }
+        })
+    }
+}
+
+impl fmt::Display for TalkerId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_talker_id_from_str() {
+        assert_eq!("GP".parse(), Ok(TalkerId::Gps));
+        assert_eq!("BD".parse(), Ok(TalkerId::Beidou));
+        assert_eq!("GB".parse(), Ok(TalkerId::Beidou));
+        assert_eq!("II".parse(), Ok(TalkerId::IntegratedInstrumentation));
+        assert_eq!(
+            "WI".parse(),
+            Ok(TalkerId::Other(ArrayString::from("WI").unwrap()))
+        );
+
+        assert_eq!(
+            "G".parse::<TalkerId>(),
+            Err(Error::ParameterLength {
+                max_length: 2,
+                parameter_length: 1
+            })
+        );
+        assert_eq!("Gé".parse::<TalkerId>(), Err(Error::ASCII));
+    }
+
+    #[test]
+    fn test_talker_id_as_str() {
+        for id in ["GP", "GL", "GA", "GB", "GI", "GQ", "GN", "II", "AI", "WI"] {
+            assert_eq!(id.parse::<TalkerId>().unwrap().as_str(), id);
+        }
+        assert_eq!("QZ".parse::<TalkerId>().unwrap().to_string(), "GQ");
+    }
+}
@@ -0,0 +1,132 @@
+use core::fmt::{self, Write};
+
+use nom::{
+    character::complete::{char, one_of},
+    combinator::opt,
+    number::complete::float,
+    IResult,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    encode::{write_opt, ToNmeaSentence},
+    Error, NmeaSentence, SentenceType,
+};
+
+/// THS - True Heading and Status
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_ths_true_heading_and_status>
+///
+/// ```text
+///        1   2 3
+///        |   | |
+/// $--THS,x.x,a*hh<CR><LF>
+/// ```
+/// 1. Heading, degrees True
+/// 2. Mode indicator:
+///     - A = Autonomous
+///     - E = Estimated (dead reckoning)
+///     - M = Manual input
+///     - S = Simulator
+///     - V = Data not valid
+/// 3. Checksum
+///
+/// Example:
+/// ```text
+/// $GPTHS,338.01,A*0E
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThsData {
+    /// Heading, degrees True
+    pub heading: Option<f32>,
+    /// Mode indicator, one of `A`, `E`, `M`, `S` or `V`
+    pub mode: char,
+}
+
+/// # Parse THS message
+///
+/// THS supersedes HDT by adding a mode indicator to the true heading.
+/// Any mode other than `A`, `E`, `M`, `S` or `V` is rejected.
+pub fn parse_ths(sentence: NmeaSentence) -> Result<ThsData, Error> {
+    if sentence.message_id != SentenceType::THS {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::THS,
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_ths(sentence.data)?.1)
+    }
+}
+
+fn do_parse_ths(i: &str) -> IResult<&str, ThsData> {
+    let (i, heading) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, mode) = one_of("AEMSV")(i)?;
+    Ok((i, ThsData { heading, mode }))
+}
+
+impl ToNmeaSentence for ThsData {
+    fn sentence_type(&self) -> SentenceType {
+        SentenceType::THS
+    }
+
+    fn write_data<W: Write>(&self, f: &mut W) -> fmt::Result {
+        write_opt(f, &self.heading)?;
+        write!(f, ",{}", self.mode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_ths() {
+        let s = parse_nmea_sentence("$GPTHS,338.01,A*0E").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_ths(s).unwrap();
+        assert_relative_eq!(data.heading.unwrap(), 338.01);
+        assert_eq!(data.mode, 'A');
+    }
+
+    #[test]
+    fn test_parse_ths_not_valid() {
+        let s = parse_nmea_sentence("$GPTHS,,V*0E").unwrap();
+        let data = parse_ths(s).unwrap();
+        assert_eq!(data.heading, None);
+        assert_eq!(data.mode, 'V');
+    }
+
+    #[test]
+    fn test_encode_ths_round_trip() {
+        for line in ["$GPTHS,338.01,A*0E", "$GPTHS,,V*0E"] {
+            let data = parse_ths(parse_nmea_sentence(line).unwrap()).unwrap();
+            let mut encoded = heapless::String::<82>::new();
+            data.write_sentence("GP", &mut encoded).unwrap();
+            assert_eq!(encoded, line);
+        }
+    }
+
+    #[test]
+    fn test_parse_ths_invalid_mode() {
+        let s = parse_nmea_sentence("$GPTHS,338.01,X*17").unwrap();
+        assert!(matches!(parse_ths(s), Err(Error::ParsingError(_))));
+    }
+
+    #[test]
+    fn test_parse_ths_wrong_sentence() {
+        let s = parse_nmea_sentence("$GPHDT,274.07,T*03").unwrap();
+        assert!(matches!(
+            parse_ths(s),
+            Err(Error::WrongSentenceHeader { .. })
+        ));
+    }
+}
@@ -0,0 +1,204 @@
+use arrayvec::ArrayString;
+use nom::{
+    bytes::complete::is_not,
+    character::complete::{char, one_of},
+    combinator::opt,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{
+    utils::{array_string, check_lat_lon, parse_hms, parse_lat_lon, parse_number_in_range, Time},
+    TtmStatus,
+};
+use crate::{
+    parse::{NmeaSentence, TEXT_PARAMETER_MAX_LEN},
+    sentences::{Latitude, Longitude},
+    Error, SentenceType,
+};
+
+/// TLL - Target Latitude and Longitude
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_tll_target_latitude_and_longitude>
+///
+/// ```text
+///        1  2        3 4         5 6    7         8 9 10
+///        |  |        | |         | |    |         | | |
+/// $--TLL,xx,llll.lll,a,yyyyy.yyy,a,c--c,hhmmss.ss,a,a*hh<CR><LF>
+/// ```
+///
+/// 1. Target Number (0-99)
+/// 2. Target Latitude
+/// 3. N = North, S = South
+/// 4. Target Longitude
+/// 5. E = East, W = West
+/// 6. Target name
+/// 7. UTC of data
+/// 8. Status (L = Lost, Q = Query, T = Tracking)
+/// 9. R = Reference target; set to "R" if target is a reference used to determine own-ship position or velocity, null otherwise.
+/// 10. Checksum
+///
+/// Example:
+/// ```text
+/// $RATLL,01,3646.54,N,00235.37,W,TGT01,124545.00,T,*7A
+/// ```
+///
+/// The target name is bounded by `MAX_LEN` characters, [`TEXT_PARAMETER_MAX_LEN`] by default.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct TllData<const MAX_LEN: usize = TEXT_PARAMETER_MAX_LEN> {
+    /// Target number
+    pub target_number: Option<u8>,
+    /// Target latitude in degrees, positive to the North
+    pub latitude: Option<f64>,
+    /// Target longitude in degrees, positive to the East
+    pub longitude: Option<f64>,
+    /// Target name
+    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
+    pub target_name: Option<ArrayString<MAX_LEN>>,
+    /// UTC of data
+    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
+    pub utc_time: Option<Time>,
+    /// Target status
+    pub status: Option<TtmStatus>,
+    /// Set to true if target is a reference used to determine own-ship position or velocity
+    pub reference_target: bool,
+}
+
+impl<const MAX_LEN: usize> TllData<MAX_LEN> {
+    /// The latitude of the target as a [`Latitude`].
+    pub fn latitude(&self) -> Option<Latitude> {
+        self.latitude.map(Latitude::new)
+    }
+
+    /// The longitude of the target as a [`Longitude`].
+    pub fn longitude(&self) -> Option<Longitude> {
+        self.longitude.map(Longitude::new)
+    }
+}
+
+/// # Parse TLL message
+///
+/// The target name is bounded by [`TEXT_PARAMETER_MAX_LEN`], use
+/// [`parse_tll_with_max_len`] for a different bound.
+pub fn parse_tll(sentence: NmeaSentence) -> Result<TllData, Error> {
+    parse_tll_with_max_len(sentence)
+}
+
+/// Parse TLL message with the target name bounded by `MAX_LEN` characters.
+///
+/// # Errors
+///
+/// [`Error::ParameterLength`] with `max_length` set to `MAX_LEN` if the target name is longer.
+pub fn parse_tll_with_max_len<const MAX_LEN: usize>(
+    sentence: NmeaSentence,
+) -> Result<TllData<MAX_LEN>, Error> {
+    if sentence.message_id != SentenceType::TLL {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::TLL,
+            found: sentence.message_id,
+        })
+    } else {
+        do_parse_tll(sentence.data)
+    }
+}
+
+fn do_parse_tll<const MAX_LEN: usize>(i: &str) -> Result<TllData<MAX_LEN>, Error<'_>> {
+    let (i, target_number) = opt(|i| parse_number_in_range::<u8>(i, 0, 99))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, lat_lon) = parse_lat_lon(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, target_name) = opt(is_not(","))(i)?;
+    let target_name = target_name.map(array_string::<MAX_LEN>).transpose()?;
+    let (i, _) = char(',')(i)?;
+    let (i, utc_time) = opt(parse_hms)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, status) = opt(one_of("LQT"))(i)?;
+    let status = status.map(|status| match status {
+        'L' => TtmStatus::Lost,
+        'Q' => TtmStatus::Query,
+        'T' => TtmStatus::Tracking,
+        _ => unreachable!(),
+    });
+    let (i, _) = char(',')(i)?;
+    let (_i, reference_target) = opt(char('R'))(i)?;
+
+    let data = TllData {
+        target_number,
+        latitude: lat_lon.map(|(latitude, _)| latitude.degrees()),
+        longitude: lat_lon.map(|(_, longitude)| longitude.degrees()),
+        target_name,
+        utc_time,
+        status,
+        reference_target: reference_target.is_some(),
+    };
+    check_lat_lon(data.latitude, data.longitude)?;
+
+    Ok(data)
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use chrono::NaiveTime;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_tll() {
+        let s =
+            parse_nmea_sentence("$RATLL,01,3646.54,N,00235.37,W,TGT01,124545.00,T,*7A").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+
+        let data = parse_tll(s).unwrap();
+        assert_eq!(data.target_number, Some(1));
+        assert_relative_eq!(data.latitude.unwrap(), 36. + 46.54 / 60.);
+        assert_relative_eq!(data.longitude.unwrap(), -(2. + 35.37 / 60.));
+        assert_eq!(data.target_name.as_deref(), Some("TGT01"));
+        assert_eq!(data.utc_time, NaiveTime::from_hms_opt(12, 45, 45));
+        assert_eq!(data.status, Some(TtmStatus::Tracking));
+        assert!(!data.reference_target);
+    }
+
+    #[test]
+    fn test_parse_tll_empty_fields() {
+        let s = parse_nmea_sentence("$RATLL,02,,,,,,,L,R*77").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+
+        assert_eq!(
+            parse_tll(s).unwrap(),
+            TllData {
+                target_number: Some(2),
+                latitude: None,
+                longitude: None,
+                target_name: None,
+                utc_time: None,
+                status: Some(TtmStatus::Lost),
+                reference_target: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_tll_invalid() {
+        let s = parse_nmea_sentence("$RATLL,100,,,,,,,,*5A").unwrap();
+        assert!(parse_tll(s).is_err());
+
+        let s = parse_nmea_sentence(
+            "$RATLL,01,,,,,TARGET_NAME_LONGER_THAN_SIXTY_FOUR_CHARACTERS_XXXXXXXXXXXXXXXXXXX,,,*3E",
+        )
+        .unwrap();
+        assert_eq!(
+            parse_tll(s),
+            Err(Error::ParameterLength {
+                max_length: 64,
+                parameter_length: 65
+            })
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use nom::{character::complete::char, combinator::opt, number::complete::float, IResult};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::utils::{
+    check_lat_lon, number, parse_date, parse_hms, parse_lat_lon, parse_valid_status, Date, Time,
+};
+use crate::{
+    navigation::Position,
+    parse::NmeaSentence,
+    sentences::{Latitude, Longitude},
+    Error, SentenceType,
+};
+
+/// TRF - TRANSIT Fix Data
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_trf_transit_fix_data>
+///
+/// ```text
+///        1         2      3       4 5        6 7   8   9   10  11  12 13
+///        |         |      |       | |        | |   |   |   |   |   |  |
+/// $--TRF,hhmmss.ss,xxxxxx,llll.ll,a,yyyyy.yy,a,x.x,x.x,x.x,x.x,xxx,A*hh<CR><LF>
+/// ```
+/// 1. UTC of the fix
+/// 2. Date, ddmmyy
+/// 3. Latitude
+/// 4. N or S
+/// 5. Longitude
+/// 6. E or W
+/// 7. Elevation angle, degrees
+/// 8. Number of iterations
+/// 9. Number of Doppler intervals
+/// 10. Update distance, nautical miles
+/// 11. Satellite ID
+/// 12. Data validity, A = valid, V = invalid
+/// 13. Checksum
+///
+/// Example:
+/// ```text
+/// $GPTRF,135315.00,030804,4916.45,N,12311.12,W,32.5,4,22,1.2,013,A*27
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrfData {
+    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
+    pub fix_time: Option<Time>,
+    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
+    pub fix_date: Option<Date>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    /// Elevation angle of the satellite, degrees
+    pub elevation_angle: Option<f32>,
+    pub iterations: Option<u16>,
+    pub doppler_intervals: Option<u16>,
+    /// Update distance, nautical miles
+    pub update_distance: Option<f32>,
+    pub satellite_id: Option<u16>,
+    pub valid: bool,
+}
+
+impl TrfData {
+    /// The latitude of the fix as a [`Latitude`].
+    pub fn latitude(&self) -> Option<Latitude> {
+        self.latitude.map(Latitude::new)
+    }
+
+    /// The longitude of the fix as a [`Longitude`].
+    pub fn longitude(&self) -> Option<Longitude> {
+        self.longitude.map(Longitude::new)
+    }
+
+    /// Position of the fix, if both latitude and longitude are present.
+    pub fn position(&self) -> Option<Position> {
+        Some(Position::new(self.latitude?, self.longitude?))
+    }
+}
+
+/// # Parse TRF message
+///
+/// See: <https://gpsd.gitlab.io/gpsd/NMEA.html#_trf_transit_fix_data>
+pub fn parse_trf(sentence: NmeaSentence) -> Result<TrfData, Error> {
+    if sentence.message_id != SentenceType::TRF {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::TRF,
+            found: sentence.message_id,
+        })
+    } else {
+        let data = do_parse_trf(sentence.data)?.1;
+        check_lat_lon(data.latitude, data.longitude)?;
+        Ok(data)
+    }
+}
+
+fn do_parse_trf(i: &str) -> IResult<&str, TrfData> {
+    // 1. UTC of the fix
+    let (i, fix_time) = opt(parse_hms)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. Date
+    let (i, fix_date) = opt(parse_date)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 3. - 6. Latitude and longitude
+    let (i, lat_lon) = parse_lat_lon(i)?;
+    let (i, _) = char(',')(i)?;
+    // 7. Elevation angle
+    let (i, elevation_angle) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 8. Number of iterations
+    let (i, iterations) = opt(number::<u16>)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 9. Number of Doppler intervals
+    let (i, doppler_intervals) = opt(number::<u16>)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 10. Update distance
+    let (i, update_distance) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 11. Satellite ID
+    let (i, satellite_id) = opt(number::<u16>)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 12. Data validity
+    let (i, valid) = parse_valid_status(i)?;
+
+    Ok((
+        i,
+        TrfData {
+            fix_time,
+            fix_date,
+            latitude: lat_lon.map(|(latitude, _)| latitude.degrees()),
+            longitude: lat_lon.map(|(_, longitude)| longitude.degrees()),
+            elevation_angle,
+            iterations,
+            doppler_intervals,
+            update_distance,
+            satellite_id,
+            valid,
+        },
+    ))
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod tests {
+    use approx::assert_relative_eq;
+    use chrono::{NaiveDate, NaiveTime};
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_trf() {
+        let s = parse_nmea_sentence(
+            "$GPTRF,135315.00,030804,4916.45,N,12311.12,W,32.5,4,22,1.2,013,A*27",
+        )
+        .unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let trf = parse_trf(s).unwrap();
+        assert_eq!(trf.fix_time, NaiveTime::from_hms_opt(13, 53, 15));
+        assert_eq!(trf.fix_date, NaiveDate::from_ymd_opt(2004, 8, 3));
+        assert_relative_eq!(trf.latitude.unwrap(), 49. + 16.45 / 60.);
+        assert_relative_eq!(trf.longitude.unwrap(), -(123. + 11.12 / 60.));
+        assert_relative_eq!(trf.elevation_angle.unwrap(), 32.5);
+        assert_eq!(trf.iterations, Some(4));
+        assert_eq!(trf.doppler_intervals, Some(22));
+        assert_relative_eq!(trf.update_distance.unwrap(), 1.2);
+        assert_eq!(trf.satellite_id, Some(13));
+        assert!(trf.valid);
+    }
+
+    #[test]
+    fn test_parse_trf_empty_fields() {
+        let s = parse_nmea_sentence("$GPTRF,,,,,,,,,,,,V*01").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let trf = parse_trf(s).unwrap();
+        assert_eq!(trf.fix_time, None);
+        assert_eq!(trf.fix_date, None);
+        assert_eq!(trf.position(), None);
+        assert_eq!(trf.satellite_id, None);
+        assert!(!trf.valid);
+    }
+
+    #[test]
+    fn test_parse_trf_invalid_latitude() {
+        let s = parse_nmea_sentence(
+            "$GPTRF,135315.00,030804,9116.45,N,12311.12,W,32.5,4,22,1.2,013,A*22",
+        )
+        .unwrap();
+        assert!(matches!(parse_trf(s), Err(Error::InvalidCoordinate(_))));
+    }
+}
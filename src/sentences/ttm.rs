@@ -1,4 +1,3 @@
-use chrono::NaiveTime;
 use nom::{
     bytes::complete::take_until,
     character::complete::{char, one_of},
@@ -10,23 +9,27 @@ use nom::{
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use super::utils::{parse_float_num, parse_hms, parse_number_in_range};
-use crate::{Error, NmeaSentence, SentenceType};
+use super::utils::{parse_float_num, parse_hms, parse_number_in_range, Time};
+use crate::{compact::Compact, Error, NmeaSentence, SentenceType};
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TtmReference {
+    /// `R` - relative to the own ship
     Relative,
-    Theoretical,
+    /// `T` - true, i.e. relative to the true North
+    True,
 }
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct TtmAngle {
-    angle: f32,
-    reference: TtmReference,
+    /// Angle in degrees
+    pub angle: f32,
+    pub reference: TtmReference,
 }
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -87,12 +90,19 @@ pub enum TtmTypeOfAcquisition {
 /// 16. Checksum
 ///
 ///
+/// Every field is optional, the angles are only reported when both the
+/// angle and its reference are present.
+///
 /// Example:
 /// ```text
 /// $RATTM,01,0.2,190.8,T,12.1,109.7,T,0.1,0.5,N,TGT01,T,,100021.00,A*79
 /// ```
+///
+/// [`Display`](core::fmt::Display) and, with `defmt-03`, `defmt::Format`
+/// write the fields in the order of the sentence and the absent ones
+/// [compactly](crate::compact) as `-`.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[derive(Debug, PartialEq)]
 pub struct TtmData {
     /// Target number
@@ -118,11 +128,56 @@ pub struct TtmData {
     /// Set to true if target is a reference used to determine own-ship position or velocity
     pub is_target_reference: bool,
     /// Time of data
-    pub time_of_data: Option<NaiveTime>,
+    pub time_of_data: Option<Time>,
     /// Type of acquisition
     pub type_of_acquisition: Option<TtmTypeOfAcquisition>,
 }
 
+impl core::fmt::Display for TtmData {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "TTM {} {} {:?} {} {:?} {} {} {:?} {} {:?} {} {} {:?}",
+            Compact(&self.target_number),
+            Compact(&self.target_distance),
+            Compact(&self.bearing_from_own_ship),
+            Compact(&self.target_speed),
+            Compact(&self.target_course),
+            Compact(&self.distance_of_cpa),
+            Compact(&self.time_to_cpa),
+            Compact(&self.speed_or_distance_unit),
+            Compact(&self.target_name),
+            Compact(&self.target_status),
+            self.is_target_reference,
+            Compact(&self.time_of_data),
+            Compact(&self.type_of_acquisition),
+        )
+    }
+}
+
+#[cfg(feature = "defmt-03")]
+impl defmt::Format for TtmData {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(
+            f,
+            "TTM {} {} {} {} {} {} {} {} {} {} {=bool} {} {}",
+            Compact(&self.target_number),
+            Compact(&self.target_distance),
+            Compact(&self.bearing_from_own_ship),
+            Compact(&self.target_speed),
+            Compact(&self.target_course),
+            Compact(&self.distance_of_cpa),
+            Compact(&self.time_to_cpa),
+            Compact(&self.speed_or_distance_unit),
+            Compact(&self.target_name),
+            Compact(&self.target_status),
+            self.is_target_reference,
+            Compact(&self.time_of_data.as_ref().map(defmt::Debug2Format)),
+            Compact(&self.type_of_acquisition),
+        )
+    }
+}
+
 /// # Parse TTM message
 pub fn parse_ttm(sentence: NmeaSentence) -> Result<TtmData, Error> {
     if sentence.message_id != SentenceType::TTM {
@@ -173,7 +228,7 @@ fn do_parse_ttm(i: &str) -> IResult<&str, TtmData> {
     } else {
         Some(heapless::String::try_from(target_name).map_err(|_| {
             nom::Err::Failure(nom::error::Error {
-                input: i,
+                input: target_name,
                 code: ErrorKind::Fail,
             })
         })?)
@@ -235,7 +290,7 @@ fn parse_ttm_angle(i: &str) -> IResult<&str, Option<TtmAngle>> {
             reference.map(|reference_char| {
                 let reference = match reference_char {
                     'R' => TtmReference::Relative,
-                    'T' => TtmReference::Theoretical,
+                    'T' => TtmReference::True,
                     _ => unreachable!(),
                 };
 
@@ -245,10 +300,12 @@ fn parse_ttm_angle(i: &str) -> IResult<&str, Option<TtmAngle>> {
     ))
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "chrono"))]
 mod tests {
     use approx::assert_relative_eq;
 
+    use chrono::NaiveTime;
+
     use super::*;
     use crate::parse::parse_nmea_sentence;
 
@@ -258,7 +315,8 @@ mod tests {
             talker_id: "RA",
             message_id: SentenceType::TTM,
             data: "00,0.5,187.5,T,12.0,17.6,T,0.0,1.2,N,TGT00,T,,100023.00,A",
-            checksum: 0x4e,
+            checksum: Some(0x4e),
+            tag_block: None,
         })
         .unwrap();
         assert_eq!(data.target_number.unwrap(), 0);
@@ -266,13 +324,13 @@ mod tests {
 
         let bearing_from_own_ship = data.bearing_from_own_ship.unwrap();
         assert_relative_eq!(bearing_from_own_ship.angle, 187.5,);
-        assert_eq!(bearing_from_own_ship.reference, TtmReference::Theoretical);
+        assert_eq!(bearing_from_own_ship.reference, TtmReference::True);
 
         assert_relative_eq!(data.target_speed.unwrap(), 12.0);
 
         let target_course = data.target_course.unwrap();
         assert_relative_eq!(target_course.angle, 17.6);
-        assert_eq!(target_course.reference, TtmReference::Theoretical);
+        assert_eq!(target_course.reference, TtmReference::True);
 
         assert_relative_eq!(data.distance_of_cpa.unwrap(), 0.0);
         assert_relative_eq!(data.time_to_cpa.unwrap(), 1.2);
@@ -296,7 +354,7 @@ mod tests {
     #[test]
     fn test_parse_ttm_all_optional() {
         let s = parse_nmea_sentence("$RATTM,,,,,,,,,,,,,,,*72").unwrap();
-        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
 
         let data = parse_ttm(s);
         assert_eq!(
@@ -318,4 +376,61 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn test_parse_ttm_relative_reported_target() {
+        let s =
+            parse_nmea_sentence("$RATTM,12,3.2,045.0,R,,270.5,R,1.1,-3.5,K,,Q,R,,R*6E").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+
+        let data = parse_ttm(s).unwrap();
+        assert_eq!(data.target_number, Some(12));
+        assert_eq!(
+            data.bearing_from_own_ship,
+            Some(TtmAngle {
+                angle: 45.0,
+                reference: TtmReference::Relative
+            })
+        );
+        assert_eq!(data.target_speed, None);
+        assert_eq!(
+            data.target_course.unwrap().reference,
+            TtmReference::Relative
+        );
+        assert_relative_eq!(data.time_to_cpa.unwrap(), -3.5);
+        assert_eq!(
+            data.speed_or_distance_unit,
+            Some(TtmDistanceUnit::Kilometer)
+        );
+        assert_eq!(data.target_name, None);
+        assert_eq!(data.target_status, Some(TtmStatus::Query));
+        assert!(data.is_target_reference);
+        assert_eq!(data.time_of_data, None);
+        assert_eq!(
+            data.type_of_acquisition,
+            Some(TtmTypeOfAcquisition::Reported)
+        );
+    }
+
+    #[test]
+    fn test_parse_ttm_invalid() {
+        // target number out of range
+        let s = parse_nmea_sentence("$RATTM,100,,,,,,,,,,,,,,*43").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert!(parse_ttm(s).is_err());
+
+        // target name longer than 32 characters
+        let s = parse_nmea_sentence("$RATTM,01,,,,,,,,,,TARGET_NAME_LONGER_THAN_32_CHARS_,,,,*21")
+            .unwrap();
+        assert!(parse_ttm(s).is_err());
+
+        let s = parse_nmea_sentence("$GPHDT,274.07,T*03").unwrap();
+        assert_eq!(
+            parse_ttm(s),
+            Err(Error::WrongSentenceHeader {
+                expected: SentenceType::TTM,
+                found: SentenceType::HDT,
+            })
+        );
+    }
 }
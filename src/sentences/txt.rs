@@ -4,7 +4,7 @@ use nom::{bytes::complete::take_while, character::complete::char, IResult};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use super::utils::number;
+use super::utils::{array_string, number};
 use crate::{
     parse::{NmeaSentence, TEXT_PARAMETER_MAX_LEN},
     Error, SentenceType,
@@ -18,7 +18,41 @@ use crate::{
 /// 3   02  Text identifier, u-blox GPS receivers specify the severity of the message with this number. 00 = ERROR, 01 = WARNING, 02 = NOTICE, 07 = USER
 /// 4   u-blox AG - www.u-blox.com Any ASCII text
 /// *68        mandatory nmea_checksum
+///
+/// The text is bounded by [`TEXT_PARAMETER_MAX_LEN`], use
+/// [`parse_txt_with_max_len`] for a different bound.
 pub fn parse_txt(s: NmeaSentence) -> Result<TxtData, Error> {
+    parse_txt_with_max_len(s)
+}
+
+/// Parse TXT message with the text bounded by `MAX_LEN` characters.
+///
+/// # Errors
+///
+/// [`Error::ParameterLength`] with `max_length` set to `MAX_LEN` if the
+/// text is longer.
+///
+/// # Examples
+///
+/// ```
+/// use nmea::{parse_nmea_sentence, sentences::parse_txt_with_max_len, Error};
+///
+/// let s = parse_nmea_sentence("$GPTXT,01,01,02,PROTVER 14.00*1E").unwrap();
+/// let txt = parse_txt_with_max_len::<16>(s).unwrap();
+/// assert_eq!(txt.text.as_str(), "PROTVER 14.00");
+///
+/// let s = parse_nmea_sentence("$GPTXT,01,01,02,PROTVER 14.00*1E").unwrap();
+/// assert_eq!(
+///     parse_txt_with_max_len::<8>(s),
+///     Err(Error::ParameterLength {
+///         max_length: 8,
+///         parameter_length: 13
+///     })
+/// );
+/// ```
+pub fn parse_txt_with_max_len<const MAX_LEN: usize>(
+    s: NmeaSentence,
+) -> Result<TxtData<MAX_LEN>, Error> {
     if s.message_id != SentenceType::TXT {
         return Err(Error::WrongSentenceHeader {
             expected: SentenceType::TXT,
@@ -28,16 +62,11 @@ pub fn parse_txt(s: NmeaSentence) -> Result<TxtData, Error> {
 
     let ret = do_parse_txt(s.data).map_err(Error::ParsingError)?.1;
 
-    let text = ArrayString::from(ret.text).map_err(|_e| Error::ParameterLength {
-        max_length: TEXT_PARAMETER_MAX_LEN,
-        parameter_length: ret.text.len(),
-    })?;
-
     Ok(TxtData {
         count: ret.count,
         seq: ret.seq,
         text_ident: ret.text_ident,
-        text,
+        text: array_string::<MAX_LEN>(ret.text)?,
     })
 }
 
@@ -66,15 +95,18 @@ fn do_parse_txt(i: &str) -> IResult<&str, TxtData0<'_>> {
 }
 
 /// TXT - Text
+///
+/// The text is bounded by `MAX_LEN` characters, [`TEXT_PARAMETER_MAX_LEN`] by default.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub struct TxtData {
+pub struct TxtData<const MAX_LEN: usize = TEXT_PARAMETER_MAX_LEN> {
     pub count: u8,
     pub seq: u8,
     pub text_ident: u8,
     #[cfg_attr(feature = "defmt-03", defmt(Display2Format))]
-    pub text: ArrayString<TEXT_PARAMETER_MAX_LEN>,
+    pub text: ArrayString<MAX_LEN>,
 }
 
 struct TxtData0<'a> {
@@ -1,14 +1,16 @@
-use core::str;
+use core::{fmt, str};
 
 use arrayvec::ArrayString;
-use chrono::{Duration, NaiveDate, NaiveTime};
+use cfg_if::cfg_if;
+#[cfg(feature = "chrono")]
+use chrono::{NaiveDate, NaiveTime};
 use nom::{
     branch::alt,
     bytes::complete::{tag, take, take_until},
-    character::complete::{char, digit1, one_of},
-    combinator::{map, map_parser, map_res},
+    character::complete::{char, digit1, hex_digit1, one_of, satisfy},
+    combinator::{map, map_parser, map_res, opt},
     number::complete::{double, float},
-    sequence::tuple,
+    sequence::{preceded, tuple},
     IResult,
 };
 
@@ -16,84 +18,108 @@ use nom::{
 #[allow(unused_imports)]
 use num_traits::float::FloatCore;
 
-use crate::Error;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
-pub fn parse_hms(i: &str) -> IResult<&str, NaiveTime> {
+use crate::{
+    navigation::METERS_PER_NAUTICAL_MILE,
+    sentences::{GnssSystem, Latitude, Longitude},
+    Error,
+};
+
+cfg_if! {
+    if #[cfg(feature = "chrono")] {
+        /// Time of the time fields of the sentences, e.g. `fix_time`.
+        ///
+        /// A [`NaiveTime`] with the `chrono` feature, a [`TimeOfDay`] without it.
+        pub type Time = NaiveTime;
+        /// Date of the date fields of the sentences, e.g. `fix_date`.
+        ///
+        /// A [`NaiveDate`] with the `chrono` feature, a [`DayMonthYear`] without it.
+        pub type Date = NaiveDate;
+        /// Elapsed time of the `hhmmss.ss` duration fields, e.g. the time to go of ZTG.
+        ///
+        /// A [`chrono::Duration`] with the `chrono` feature, a [`TimeOfDay`] without it.
+        pub type Duration = chrono::Duration;
+    } else {
+        /// Time of the time fields of the sentences, e.g. `fix_time`.
+        ///
+        /// A `chrono::NaiveTime` with the `chrono` feature, a [`TimeOfDay`] without it.
+        pub type Time = TimeOfDay;
+        /// Date of the date fields of the sentences, e.g. `fix_date`.
+        ///
+        /// A `chrono::NaiveDate` with the `chrono` feature, a [`DayMonthYear`] without it.
+        pub type Date = DayMonthYear;
+        /// Elapsed time of the `hhmmss.ss` duration fields, e.g. the time to go of ZTG.
+        ///
+        /// A `chrono::Duration` with the `chrono` feature, a [`TimeOfDay`] without it.
+        pub type Duration = TimeOfDay;
+    }
+}
+
+/// Parses values like `125619,` and `125619.5,` to a [`TimeOfDay`]
+///
+/// Unlike [`parse_hms`] this doesn't depend on the `chrono` feature.
+pub fn parse_time_of_day(i: &str) -> IResult<&str, TimeOfDay> {
     map_res(
         tuple((
-            map_res(take(2usize), parse_num::<u32>),
-            map_res(take(2usize), parse_num::<u32>),
+            map_res(take(2usize), parse_num::<u8>),
+            map_res(take(2usize), parse_num::<u8>),
             map_parser(take_until(","), double),
         )),
-        |(hour, minutes, sec)| -> core::result::Result<NaiveTime, &'static str> {
-            if sec.is_sign_negative() {
-                return Err("Invalid time: second is negative");
-            }
+        |(hour, minute, second)| -> core::result::Result<TimeOfDay, &'static str> {
             if hour >= 24 {
                 return Err("Invalid time: hour >= 24");
             }
-            if minutes >= 60 {
+            if minute >= 60 {
                 return Err("Invalid time: min >= 60");
             }
-            if sec >= 60. {
+            if !second.is_finite() {
+                return Err("Invalid time: second is not finite");
+            }
+            if second.is_sign_negative() {
+                return Err("Invalid time: second is negative");
+            }
+            if second >= 60. {
                 return Err("Invalid time: sec >= 60");
             }
-            NaiveTime::from_hms_nano_opt(
+            Ok(TimeOfDay {
                 hour,
-                minutes,
-                sec.trunc() as u32,
-                (sec.fract() * 1_000_000_000f64).round() as u32,
-            )
-            .ok_or("Invalid time")
+                minute,
+                second: second.trunc() as u8,
+                // rounding can't carry over into the next second
+                nanosecond: ((second.fract() * 1_000_000_000f64).round() as u32).min(999_999_999),
+            })
         },
     )(i)
 }
 
-/// The number of milliseconds in a second.
-const MILLISECS_PER_SECOND: u32 = 1000;
-/// The number of milliseconds in a minute.
-const MILLISECS_PER_MINUTE: u32 = 60000;
-/// The number of milliseconds in a hour.
-const MILLISECS_PER_HOUR: u32 = 3600000;
+/// Parses values like `125619,` and `125619.5,` to a [`Time`]
+pub fn parse_hms(i: &str) -> IResult<&str, Time> {
+    cfg_if! {
+        if #[cfg(feature = "chrono")] {
+            map_res(parse_time_of_day, |time| {
+                time.to_naive_time().ok_or("Invalid time")
+            })(i)
+        } else {
+            parse_time_of_day(i)
+        }
+    }
+}
 
-/// Parses values like `125619,` and `125619.5,` to [`Duration`]
+/// Parses values like `125619,` and `125619.5,` to a [`Duration`]
 pub fn parse_duration_hms(i: &str) -> IResult<&str, Duration> {
-    map_res(
-        tuple((
-            map_res(take(2usize), parse_num::<u8>),
-            map_res(take(2usize), parse_num::<u8>),
-            map_parser(take_until(","), float),
-        )),
-        |(hours, minutes, seconds)| -> core::result::Result<Duration, &'static str> {
-            if hours >= 24 {
-                return Err("Invalid time: hours >= 24");
-            }
-            if minutes >= 60 {
-                return Err("Invalid time: minutes >= 60");
-            }
-            if !seconds.is_finite() {
-                return Err("Invalid time: seconds is not finite");
-            }
-            if seconds < 0.0 {
-                return Err("Invalid time: seconds is negative");
-            }
-            if seconds >= 60. {
-                return Err("Invalid time: seconds >= 60");
-            }
-
-            // We don't have to use checked operations as above checks limits number of milliseconds
-            // to value within i64 bounds.
-            Ok(Duration::milliseconds(
-                i64::from(hours) * i64::from(MILLISECS_PER_HOUR)
-                    + i64::from(minutes) * i64::from(MILLISECS_PER_MINUTE)
-                    + (seconds.trunc() as i64) * i64::from(MILLISECS_PER_SECOND)
-                    + (seconds.fract() * 1_000f32).round() as i64,
-            ))
-        },
-    )(i)
+    cfg_if! {
+        if #[cfg(feature = "chrono")] {
+            map(parse_time_of_day, |time| time.to_duration())(i)
+        } else {
+            parse_time_of_day(i)
+        }
+    }
 }
 
-pub fn do_parse_lat_lon(i: &str) -> IResult<&str, (f64, f64)> {
+/// Parses the `ddmm.mmmm,N,dddmm.mmmm,E` pair of latitude and longitude.
+pub fn do_parse_lat_lon(i: &str) -> IResult<&str, (Latitude, Longitude)> {
     let (i, lat_deg) = map_res(take(2usize), parse_num::<u8>)(i)?;
     let (i, lat_min) = double(i)?;
     let (i, _) = char(',')(i)?;
@@ -113,7 +139,7 @@ pub fn do_parse_lat_lon(i: &str) -> IResult<&str, (f64, f64)> {
         lon = -lon;
     }
 
-    Ok((i, (lat, lon)))
+    Ok((i, (Latitude::new(lat), Longitude::new(lon))))
 }
 
 /// Parses the variation between magnetic north and true north.
@@ -134,7 +160,27 @@ pub fn do_parse_magnetic_variation(i: &str) -> IResult<&str, f32> {
     Ok((i, variation_deg))
 }
 
-pub(crate) fn parse_lat_lon(i: &str) -> IResult<&str, Option<(f64, f64)>> {
+/// Checks the range of a parsed latitude and longitude, a `ddmm.mmmm` field
+/// can hold up to 99 degrees of latitude and 999 degrees of longitude.
+///
+/// # Errors
+///
+/// [`Error::InvalidCoordinate`] for a latitude above 90 or a longitude above
+/// 180 degrees in magnitude.
+pub(crate) fn check_lat_lon(
+    latitude: Option<f64>,
+    longitude: Option<f64>,
+) -> Result<(), Error<'static>> {
+    if let Some(latitude) = latitude.filter(|latitude| latitude.abs() > 90.) {
+        return Err(Error::InvalidCoordinate(latitude));
+    }
+    if let Some(longitude) = longitude.filter(|longitude| longitude.abs() > 180.) {
+        return Err(Error::InvalidCoordinate(longitude));
+    }
+    Ok(())
+}
+
+pub(crate) fn parse_lat_lon(i: &str) -> IResult<&str, Option<(Latitude, Longitude)>> {
     alt((map(tag(",,,"), |_| None), map(do_parse_lat_lon, Some)))(i)
 }
 
@@ -145,16 +191,18 @@ pub(crate) fn parse_magnetic_variation(i: &str) -> IResult<&str, Option<f32>> {
     ))(i)
 }
 
-pub(crate) fn parse_date(i: &str) -> IResult<&str, NaiveDate> {
+/// Parses values like `191194,` to a [`DayMonthYear`]
+///
+/// Unlike the parsers returning a [`Date`] this doesn't depend on the `chrono`
+/// feature.
+pub fn parse_day_month_year(i: &str) -> IResult<&str, DayMonthYear> {
     map_res(
         tuple((
             map_res(take(2usize), parse_num::<u8>),
             map_res(take(2usize), parse_num::<u8>),
             map_res(take(2usize), parse_num::<u8>),
         )),
-        |data| -> Result<NaiveDate, &'static str> {
-            let (day, month, year) = (u32::from(data.0), u32::from(data.1), i32::from(data.2));
-
+        |(day, month, year)| -> Result<DayMonthYear, &'static str> {
             // We only receive a 2digit year code in this message, this has the potential
             // to be ambiguous regarding the year. We assume that anything above 83 is 1900's, and
             // anything above 0 is 2000's.
@@ -162,8 +210,8 @@ pub(crate) fn parse_date(i: &str) -> IResult<&str, NaiveDate> {
             // The reason for 83 is that NMEA0183 was released in 1983.
             // Parsing dates from ZDA messages is preferred, since it includes a 4 digit year.
             let year = match year {
-                83..=99 => year + 1900,
-                _ => year + 2000,
+                83..=99 => u16::from(year) + 1900,
+                _ => u16::from(year) + 2000,
             };
 
             if !(1..=12).contains(&month) {
@@ -172,11 +220,23 @@ pub(crate) fn parse_date(i: &str) -> IResult<&str, NaiveDate> {
             if !(1..=31).contains(&day) {
                 return Err("Invalid day < 1 or > 31");
             }
-            NaiveDate::from_ymd_opt(year, month, day).ok_or("Invalid date")
+            Ok(DayMonthYear { day, month, year })
         },
     )(i)
 }
 
+pub(crate) fn parse_date(i: &str) -> IResult<&str, Date> {
+    cfg_if! {
+        if #[cfg(feature = "chrono")] {
+            map_res(parse_day_month_year, |date| {
+                date.to_naive_date().ok_or("Invalid date")
+            })(i)
+        } else {
+            parse_day_month_year(i)
+        }
+    }
+}
+
 pub(crate) fn parse_num<I: str::FromStr>(data: &str) -> Result<I, &'static str> {
     data.parse::<I>().map_err(|_| "parse of number failed")
 }
@@ -205,6 +265,58 @@ where
     })(i)
 }
 
+/// Parses a status field where `A` stands for `true` (active, valid)
+/// and `V` stands for `false` (void, invalid).
+pub(crate) fn parse_valid_status(i: &str) -> IResult<&str, bool> {
+    map(one_of("AV"), |status| status == 'A')(i)
+}
+
+/// Depth in feet, meters and fathoms.
+pub(crate) type Depths = (Option<f64>, Option<f64>, Option<f64>);
+
+/// Parses the `x.x,f,x.x,M,x.x,F` depth fields shared by the depth sounder
+/// sentences.
+///
+/// The unit letters are mandatory, only the values may be empty.
+pub(crate) fn parse_depths(i: &str) -> IResult<&str, Depths> {
+    let (i, feet) = opt(double)(i)?;
+    let (i, _) = preceded(char(','), char('f'))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, meters) = opt(double)(i)?;
+    let (i, _) = preceded(char(','), char('M'))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, fathoms) = opt(double)(i)?;
+    let (i, _) = preceded(char(','), char('F'))(i)?;
+
+    Ok((i, (feet, meters, fathoms)))
+}
+
+/// Meters in a foot
+const METERS_PER_FOOT: f64 = 0.3048;
+/// Meters in a fathom
+const METERS_PER_FATHOM: f64 = 1.8288;
+
+/// Depth in meters, converted from feet or fathoms if the meters are missing.
+pub(crate) fn depth_in_meters((feet, meters, fathoms): Depths) -> Option<f64> {
+    meters
+        .or_else(|| feet.map(|feet| feet * METERS_PER_FOOT))
+        .or_else(|| fathoms.map(|fathoms| fathoms * METERS_PER_FATHOM))
+}
+
+/// Parses a single hexadecimal digit such as the NMEA 4.1 GNSS System ID
+/// and Signal ID fields.
+pub(crate) fn parse_hex_id(i: &str) -> IResult<&str, u8> {
+    map_res(satisfy(|c| c.is_ascii_hexdigit()), |c| {
+        c.to_digit(16).map(|id| id as u8).ok_or("not a hex digit")
+    })(i)
+}
+
+/// Parses a hexadecimal number such as the raw almanac parameters, failing
+/// on non-hex input and on values not fitting into `T`.
+pub(crate) fn hex_number<T: num_traits::Num>(i: &str) -> IResult<&str, T> {
+    map_res(hex_digit1, |digits| T::from_str_radix(digits, 16))(i)
+}
+
 /// Parses a given `&str` slice to an owned `ArrayString` with a given `MAX_LEN`.
 ///
 /// # Errors
@@ -212,26 +324,290 @@ where
 /// If `&str` length > `MAX_LEN` it returns a [`Error::ParameterLength`] error.
 pub(crate) fn array_string<const MAX_LEN: usize>(
     string: &str,
-) -> Result<ArrayString<MAX_LEN>, Error> {
+) -> Result<ArrayString<MAX_LEN>, Error<'_>> {
     ArrayString::from(string).map_err(|_| Error::ParameterLength {
         max_length: MAX_LEN,
         parameter_length: string.len(),
     })
 }
 
+/// Kilometers per hour in one knot.
+const KPH_PER_KNOT: f32 = (METERS_PER_NAUTICAL_MILE / 1000.0) as f32;
+/// Meters per second in one knot.
+const MPS_PER_KNOT: f32 = (METERS_PER_NAUTICAL_MILE / 3600.0) as f32;
+
+/// Converts a speed in knots to meters per second.
+pub fn knots_to_mps(knots: f32) -> f32 {
+    knots * MPS_PER_KNOT
+}
+
+/// Converts a speed in meters per second to knots.
+pub fn mps_to_knots(mps: f32) -> f32 {
+    mps / MPS_PER_KNOT
+}
+
+/// Converts a speed in knots to kilometers per hour.
+pub fn knots_to_kph(knots: f32) -> f32 {
+    knots * KPH_PER_KNOT
+}
+
+/// Converts a speed in kilometers per hour to knots.
+pub fn kph_to_knots(kph: f32) -> f32 {
+    kph / KPH_PER_KNOT
+}
+
+/// Derives the constellation of a satellite from its PRN, for sentences
+/// without the NMEA 4.1 System ID.
+///
+/// The ranges follow the NMEA 0183 satellite numbering with the extensions
+/// used by u-blox and gpsd:
+///
+/// | PRN         | System  |
+/// |-------------|---------|
+/// | `1 - 32`    | GPS     |
+/// | `33 - 64`   | SBAS, `None` |
+/// | `65 - 96`   | GLONASS |
+/// | `193 - 200` | QZSS    |
+/// | `201 - 237` | BeiDou  |
+/// | `301 - 336` | Galileo |
+/// | `401 - 437` | BeiDou  |
+///
+/// All the other PRNs result in `None`, NavIC has no range of its own.
+pub fn constellation_from_prn(prn: u32) -> Option<GnssSystem> {
+    match prn {
+        1..=32 => Some(GnssSystem::Gps),
+        65..=96 => Some(GnssSystem::Glonass),
+        193..=200 => Some(GnssSystem::Qzss),
+        201..=237 | 401..=437 => Some(GnssSystem::BeiDou),
+        301..=336 => Some(GnssSystem::Galileo),
+        _ => None,
+    }
+}
+
+/// A speed, stored in knots.
+///
+/// # Examples
+///
+/// ```
+/// use nmea::sentences::Speed;
+///
+/// let speed = Speed::from_kph(18.52);
+/// assert!((speed.as_knots() - 10.0).abs() < 1e-5);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Speed {
+    knots: f32,
+}
+
+impl Speed {
+    pub const fn from_knots(knots: f32) -> Self {
+        Self { knots }
+    }
+
+    pub fn from_mps(mps: f32) -> Self {
+        Self::from_knots(mps_to_knots(mps))
+    }
+
+    pub fn from_kph(kph: f32) -> Self {
+        Self::from_knots(kph_to_knots(kph))
+    }
+
+    pub const fn as_knots(self) -> f32 {
+        self.knots
+    }
+
+    pub fn as_mps(self) -> f32 {
+        knots_to_mps(self.knots)
+    }
+
+    pub fn as_kph(self) -> f32 {
+        knots_to_kph(self.knots)
+    }
+}
+
+/// The number of nanoseconds in a millisecond.
+const NANOSECS_PER_MILLISEC: u32 = 1_000_000;
+
+/// Time of the `hhmmss.ss` fields, the [`Time`] and [`Duration`] of the
+/// sentence fields without the `chrono` feature.
+///
+/// [`parse_time_of_day`] validates the ranges, so a parsed time always has
+/// an hour below 24, a minute below 60 and a second below 60. The fraction
+/// of the second is kept in nanoseconds, like `chrono` does.
+///
+/// It's displayed like a `chrono::NaiveTime`, e.g. `09:27:50.500`.
+///
+/// # Examples
+///
+/// ```
+/// use nmea::sentences::utils::parse_time_of_day;
+///
+/// let (_, time) = parse_time_of_day("092750.5,").unwrap();
+/// assert_eq!((time.hour, time.minute, time.second), (9, 27, 50));
+/// assert_eq!(time.nanosecond, 500_000_000);
+/// assert_eq!(time.to_string(), "09:27:50.500");
+/// # #[cfg(feature = "chrono")]
+/// assert_eq!(
+///     time.to_naive_time(),
+///     chrono::NaiveTime::from_hms_milli_opt(9, 27, 50, 500)
+/// );
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct TimeOfDay {
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    pub nanosecond: u32,
+}
+
+impl TimeOfDay {
+    /// Returns the milliseconds since midnight, the fraction of the second
+    /// is rounded to milliseconds.
+    pub fn milliseconds(&self) -> u32 {
+        ((u32::from(self.hour) * 60 + u32::from(self.minute)) * 60 + u32::from(self.second)) * 1000
+            + (self.nanosecond + NANOSECS_PER_MILLISEC / 2) / NANOSECS_PER_MILLISEC
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TimeOfDay {
+    /// Converts to a [`NaiveTime`].
+    pub fn to_naive_time(&self) -> Option<NaiveTime> {
+        NaiveTime::from_hms_nano_opt(
+            u32::from(self.hour),
+            u32::from(self.minute),
+            u32::from(self.second),
+            self.nanosecond,
+        )
+    }
+
+    /// Converts to the `chrono::Duration` since midnight with millisecond
+    /// precision.
+    pub fn to_duration(&self) -> chrono::Duration {
+        chrono::Duration::milliseconds(i64::from(self.milliseconds()))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<NaiveTime> for TimeOfDay {
+    fn from(time: NaiveTime) -> Self {
+        use chrono::Timelike;
+
+        Self {
+            hour: time.hour() as u8,
+            minute: time.minute() as u8,
+            second: time.second() as u8,
+            nanosecond: time.nanosecond(),
+        }
+    }
+}
+
+impl fmt::Display for TimeOfDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)?;
+        // the shortest of milli-, micro- and nanoseconds, like chrono
+        match self.nanosecond {
+            0 => Ok(()),
+            nanos if nanos % NANOSECS_PER_MILLISEC == 0 => {
+                write!(f, ".{:03}", nanos / NANOSECS_PER_MILLISEC)
+            }
+            nanos if nanos % 1000 == 0 => write!(f, ".{:06}", nanos / 1000),
+            nanos => write!(f, ".{:09}", nanos),
+        }
+    }
+}
+
+/// Date of the `ddmmyy` fields, the [`Date`] of the sentence fields without
+/// the `chrono` feature.
+///
+/// The two digit year is expanded like in the sentence structs, years from
+/// 83 on are in the 1900s and the others in the 2000s.
+///
+/// It's displayed like a `chrono::NaiveDate`, e.g. `1994-11-19`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct DayMonthYear {
+    pub day: u8,
+    pub month: u8,
+    pub year: u16,
+}
+
+#[cfg(feature = "chrono")]
+impl DayMonthYear {
+    /// Converts to a [`NaiveDate`], `None` for days like the 31st of April.
+    pub fn to_naive_date(&self) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(
+            i32::from(self.year),
+            u32::from(self.month),
+            u32::from(self.day),
+        )
+    }
+}
+
+impl fmt::Display for DayMonthYear {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
 
     use super::*;
 
+    #[test]
+    fn test_constellation_from_prn() {
+        let boundaries = [
+            (0, None),
+            (1, Some(GnssSystem::Gps)),
+            (32, Some(GnssSystem::Gps)),
+            (33, None),
+            (64, None),
+            (65, Some(GnssSystem::Glonass)),
+            (96, Some(GnssSystem::Glonass)),
+            (97, None),
+            (192, None),
+            (193, Some(GnssSystem::Qzss)),
+            (200, Some(GnssSystem::Qzss)),
+            (201, Some(GnssSystem::BeiDou)),
+            (237, Some(GnssSystem::BeiDou)),
+            (238, None),
+            (300, None),
+            (301, Some(GnssSystem::Galileo)),
+            (336, Some(GnssSystem::Galileo)),
+            (337, None),
+            (400, None),
+            (401, Some(GnssSystem::BeiDou)),
+            (437, Some(GnssSystem::BeiDou)),
+            (438, None),
+        ];
+        for (prn, system) in boundaries {
+            assert_eq!(constellation_from_prn(prn), system, "PRN {prn}");
+        }
+    }
+
     #[test]
     fn test_do_parse_lat_lon() {
         let (_, lat_lon) = do_parse_lat_lon("4807.038,N,01131.324,E").unwrap();
-        assert_relative_eq!(lat_lon.0, 48. + 7.038 / 60.);
-        assert_relative_eq!(lat_lon.1, 11. + 31.324 / 60.);
+        assert_relative_eq!(lat_lon.0.degrees(), 48. + 7.038 / 60.);
+        assert_relative_eq!(lat_lon.1.degrees(), 11. + 31.324 / 60.);
+
+        let (_, (lat, lon)) = do_parse_lat_lon("0000.000,S,00000.000,W").unwrap();
+        assert_eq!(lat.hemisphere(), 'N');
+        assert_eq!(lon.hemisphere(), 'E');
+        assert_eq!(lat.degrees(), 0.);
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn test_parse_hms() {
         use chrono::Timelike;
@@ -247,6 +623,22 @@ mod tests {
         assert_eq!(time.nanosecond(), 500_000_000);
     }
 
+    #[cfg(not(feature = "chrono"))]
+    #[test]
+    fn test_parse_hms_without_chrono() {
+        let (_, time) = parse_hms("125619.5,").unwrap();
+        assert_eq!(time, parse_time_of_day("125619.5,").unwrap().1);
+        assert_eq!(time.to_string(), "12:56:19.500");
+
+        let (_, time) = parse_duration_hms("001023.75,").unwrap();
+        assert_eq!(time.milliseconds(), (10 * 60 + 23) * 1000 + 750);
+
+        let (_, date) = parse_date("310424").unwrap();
+        assert_eq!(date, parse_day_month_year("310424").unwrap().1);
+        assert_eq!(date.to_string(), "2024-04-31");
+    }
+
+    #[cfg(feature = "chrono")]
     #[test]
     fn test_parse_duration_hms() {
         let (_, time) = parse_duration_hms("125619,").unwrap();
@@ -267,6 +659,68 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_time_of_day() {
+        let (_, time) = parse_time_of_day("125619.25,").unwrap();
+        assert_eq!(
+            time,
+            TimeOfDay {
+                hour: 12,
+                minute: 56,
+                second: 19,
+                nanosecond: 250_000_000
+            }
+        );
+        assert_eq!(time.milliseconds(), (12 * 3600 + 56 * 60 + 19) * 1000 + 250);
+        assert_eq!(time.to_string(), "12:56:19.250");
+        #[cfg(feature = "chrono")]
+        {
+            assert_eq!(
+                time.to_naive_time(),
+                NaiveTime::from_hms_milli_opt(12, 56, 19, 250)
+            );
+            assert_eq!(
+                time.to_duration(),
+                parse_duration_hms("125619.25,").unwrap().1
+            );
+            assert_eq!(TimeOfDay::from(time.to_naive_time().unwrap()), time);
+        }
+
+        for invalid in [
+            "245619,", "126019,", "125660,", "1256-1,", "1256NaN,", "1256inf,",
+        ] {
+            assert!(parse_time_of_day(invalid).is_err(), "{invalid}");
+            assert!(parse_hms(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_parse_day_month_year() {
+        let (_, date) = parse_day_month_year("191194").unwrap();
+        assert_eq!(
+            date,
+            DayMonthYear {
+                day: 19,
+                month: 11,
+                year: 1994
+            }
+        );
+        assert_eq!(date.to_string(), "1994-11-19");
+        #[cfg(feature = "chrono")]
+        {
+            assert_eq!(date.to_naive_date(), NaiveDate::from_ymd_opt(1994, 11, 19));
+
+            // passes the range checks but isn't a valid date
+            let (_, date) = parse_day_month_year("310424").unwrap();
+            assert_eq!(date.to_naive_date(), None);
+            assert!(parse_date("310424").is_err());
+        }
+
+        assert!(parse_day_month_year("001194").is_err());
+        assert!(parse_day_month_year("191394").is_err());
+    }
+
+    #[cfg(feature = "chrono")]
     #[test]
     fn test_parse_date() {
         let (_, date) = parse_date("180283").unwrap();
@@ -315,4 +769,28 @@ mod tests {
         let result = parse_magnetic_variation("12,Q");
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_parse_valid_status() {
+        assert_eq!(parse_valid_status("A,"), Ok((",", true)));
+        assert_eq!(parse_valid_status("V"), Ok(("", false)));
+        assert!(parse_valid_status("X").is_err());
+        assert!(parse_valid_status("").is_err());
+    }
+
+    #[test]
+    fn test_speed_conversions() {
+        assert_relative_eq!(knots_to_kph(10.0), 18.52);
+        assert_relative_eq!(kph_to_knots(18.52), 10.0);
+        assert_relative_eq!(knots_to_mps(1.0), 0.514_444, epsilon = 1e-6);
+
+        let speed = Speed::from_mps(5.144_444);
+        assert_relative_eq!(speed.as_knots(), 10.0, epsilon = 1e-5);
+        assert_relative_eq!(speed.as_kph(), 18.52, epsilon = 1e-4);
+        assert_relative_eq!(
+            Speed::from_kph(speed.as_kph()).as_mps(),
+            5.144_444,
+            epsilon = 1e-5
+        );
+    }
 }
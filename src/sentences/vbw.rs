@@ -0,0 +1,161 @@
+use nom::{
+    character::complete::char, combinator::opt, number::complete::float, sequence::preceded,
+    IResult,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::utils::parse_valid_status;
+use crate::{parse::NmeaSentence, Error, SentenceType};
+
+/// VBW - Dual Ground/Water Speed
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_vbw_dual_groundwater_speed>
+///
+/// ```text
+///        1   2   3 4   5   6 7   8 9   10
+///        |   |   | |   |   | |   | |   |
+/// $--VBW,x.x,x.x,A,x.x,x.x,A,x.x,A,x.x,A*hh<CR><LF>
+/// ```
+///
+/// 1. Longitudinal water speed, "-" means astern, knots
+/// 2. Transverse water speed, "-" means port, knots
+/// 3. Status, A = Data Valid
+/// 4. Longitudinal ground speed, "-" means astern, knots
+/// 5. Transverse ground speed, "-" means port, knots
+/// 6. Status, A = Data Valid
+/// 7. Stern traverse water speed, knots (NMEA 3 and above)
+/// 8. Status, stern traverse water speed A = Valid (NMEA 3 and above)
+/// 9. Stern traverse ground speed, knots (NMEA 3 and above)
+/// 10. Status, stern ground speed A = Valid (NMEA 3 and above)
+///
+/// Example:
+/// ```text
+/// $IIVBW,12.3,-0.07,A,11.78,0.12,A,0.15,A,0.20,V*44
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VbwData {
+    /// Longitudinal water speed in knots, negative means astern
+    pub longitudinal_water_speed: Option<f32>,
+    /// Transverse water speed in knots, negative means port
+    pub transverse_water_speed: Option<f32>,
+    pub water_speed_valid: bool,
+    /// Longitudinal ground speed in knots, negative means astern
+    pub longitudinal_ground_speed: Option<f32>,
+    /// Transverse ground speed in knots, negative means port
+    pub transverse_ground_speed: Option<f32>,
+    pub ground_speed_valid: bool,
+    /// Stern transverse water speed in knots (NMEA 3 and above)
+    pub stern_transverse_water_speed: Option<f32>,
+    pub stern_water_speed_valid: Option<bool>,
+    /// Stern transverse ground speed in knots (NMEA 3 and above)
+    pub stern_transverse_ground_speed: Option<f32>,
+    pub stern_ground_speed_valid: Option<bool>,
+}
+
+/// # Parse VBW message
+pub fn parse_vbw(sentence: NmeaSentence) -> Result<VbwData, Error> {
+    if sentence.message_id != SentenceType::VBW {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::VBW,
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_vbw(sentence.data)?.1)
+    }
+}
+
+/// Parses the optional NMEA 3 speed with its status, e.g. `,0.15,A`.
+fn parse_stern_speed(i: &str) -> IResult<&str, (Option<f32>, Option<bool>)> {
+    let (i, speed) = opt(preceded(char(','), opt(float)))(i)?;
+    let (i, valid) = opt(preceded(char(','), opt(parse_valid_status)))(i)?;
+    Ok((i, (speed.flatten(), valid.flatten())))
+}
+
+fn do_parse_vbw(i: &str) -> IResult<&str, VbwData> {
+    let (i, longitudinal_water_speed) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, transverse_water_speed) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, water_speed_valid) = parse_valid_status(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, longitudinal_ground_speed) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, transverse_ground_speed) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, ground_speed_valid) = parse_valid_status(i)?;
+    let (i, (stern_transverse_water_speed, stern_water_speed_valid)) = parse_stern_speed(i)?;
+    let (i, (stern_transverse_ground_speed, stern_ground_speed_valid)) = parse_stern_speed(i)?;
+
+    Ok((
+        i,
+        VbwData {
+            longitudinal_water_speed,
+            transverse_water_speed,
+            water_speed_valid,
+            longitudinal_ground_speed,
+            transverse_ground_speed,
+            ground_speed_valid,
+            stern_transverse_water_speed,
+            stern_water_speed_valid,
+            stern_transverse_ground_speed,
+            stern_ground_speed_valid,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_vbw() {
+        let s = parse_nmea_sentence("$IIVBW,12.3,0.07,A,11.78,0.12,A*78").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+
+        let data = parse_vbw(s).unwrap();
+        assert_relative_eq!(data.longitudinal_water_speed.unwrap(), 12.3);
+        assert_relative_eq!(data.transverse_water_speed.unwrap(), 0.07);
+        assert!(data.water_speed_valid);
+        assert_relative_eq!(data.longitudinal_ground_speed.unwrap(), 11.78);
+        assert_relative_eq!(data.transverse_ground_speed.unwrap(), 0.12);
+        assert!(data.ground_speed_valid);
+        assert_eq!(data.stern_transverse_water_speed, None);
+        assert_eq!(data.stern_water_speed_valid, None);
+        assert_eq!(data.stern_transverse_ground_speed, None);
+        assert_eq!(data.stern_ground_speed_valid, None);
+    }
+
+    #[test]
+    fn test_parse_vbw_nmea_3() {
+        let s = parse_nmea_sentence("$IIVBW,12.3,-0.07,A,11.78,0.12,A,0.15,A,0.20,V*44").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+
+        let data = parse_vbw(s).unwrap();
+        assert_relative_eq!(data.transverse_water_speed.unwrap(), -0.07);
+        assert_relative_eq!(data.stern_transverse_water_speed.unwrap(), 0.15);
+        assert_eq!(data.stern_water_speed_valid, Some(true));
+        assert_relative_eq!(data.stern_transverse_ground_speed.unwrap(), 0.20);
+        assert_eq!(data.stern_ground_speed_valid, Some(false));
+    }
+
+    #[test]
+    fn test_parse_vbw_invalid() {
+        let s = parse_nmea_sentence("$IIVBW,,,V,,,V*43").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_vbw(s).unwrap();
+        assert!(!data.water_speed_valid);
+        assert!(!data.ground_speed_valid);
+        assert_eq!(data.longitudinal_water_speed, None);
+
+        let s = parse_nmea_sentence("$IIVBW,12.3,0.07,X,11.78,0.12,A*61").unwrap();
+        assert!(parse_vbw(s).is_err());
+    }
+}
@@ -0,0 +1,386 @@
+use arrayvec::ArrayString;
+use nom::{
+    bytes::complete::is_not,
+    character::complete::{char, one_of},
+    combinator::opt,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::utils::{array_string, number};
+use crate::{
+    parse::{NmeaSentence, SENTENCE_MAX_LEN},
+    Error, SentenceType,
+};
+
+/// Maximum length of the armored payload of a single VDM/VDO sentence.
+///
+/// It's the longest payload which still fits in [`SENTENCE_MAX_LEN`]
+/// next to the shortest envelope: `!AIVDM,1,1,,A,` and `,0*hh`.
+pub const AIS_PAYLOAD_MAX_LEN: usize = SENTENCE_MAX_LEN - "!AIVDM,1,1,,A,".len() - ",0*hh".len();
+
+/// VDM - AIS VHF Data-Link Message
+/// VDO - AIS VHF Data-Link Own-Vessel Report
+///
+/// <https://gpsd.gitlab.io/gpsd/AIVDM.html#_aivdmaivdo_sentence_layer>
+///
+/// ```text
+///        1 2 3 4 5    6 7
+///        | | | | |    | |
+/// !--VDM,x,x,x,a,s--s,x*hh<CR><LF>
+/// ```
+///
+/// 1. Count of fragments in the currently accumulating message
+/// 2. Fragment number of this sentence, starting from 1
+/// 3. Sequential message ID for multi-sentence messages, empty for single fragment messages
+/// 4. Radio channel code, `A` or `B` (`1` and `2` are used by some receivers)
+/// 5. Data payload, armored in 6-bit ASCII
+/// 6. Number of fill bits, 0 to 5
+/// 7. Checksum
+///
+/// Only the envelope is parsed, the payload is kept armored. Payloads of
+/// multi-fragment messages can be joined with [`concat_vdm_payloads`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VdmData {
+    pub fragment_count: u8,
+    pub fragment_number: u8,
+    pub message_id: Option<u8>,
+    pub channel: Option<char>,
+    #[cfg_attr(feature = "defmt-03", defmt(Display2Format))]
+    pub payload: ArrayString<AIS_PAYLOAD_MAX_LEN>,
+    pub fill_bits: u8,
+}
+
+/// # Parse VDM or VDO message
+///
+/// Both sentences share the same envelope, VDO is used for the
+/// reports of the own vessel.
+pub fn parse_vdm(sentence: NmeaSentence) -> Result<VdmData, Error> {
+    if sentence.message_id != SentenceType::VDM && sentence.message_id != SentenceType::VDO {
+        Err(Error::WrongSentenceHeaders {
+            expected: &[SentenceType::VDM, SentenceType::VDO],
+            found: sentence.message_id,
+        })
+    } else {
+        do_parse_vdm(sentence.data)
+    }
+}
+
+fn do_parse_vdm(i: &str) -> Result<VdmData, Error<'_>> {
+    let (i, fragment_count) = number::<u8>(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, fragment_number) = number::<u8>(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, message_id) = opt(number::<u8>)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, channel) = opt(one_of("AB12"))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, payload) = is_not(",")(i)?;
+    let (i, _) = char(',')(i)?;
+    let (_i, fill_bits) = one_of("012345")(i)?;
+
+    Ok(VdmData {
+        fragment_count,
+        fragment_number,
+        message_id,
+        channel,
+        payload: array_string::<AIS_PAYLOAD_MAX_LEN>(payload)?,
+        fill_bits: fill_bits as u8 - b'0',
+    })
+}
+
+/// Concatenates the armored payloads of the fragments of a single VDM/VDO message.
+///
+/// The fragments have to be complete and given in order. The returned fill bits
+/// are the ones of the last fragment as only it can carry fill bits.
+///
+/// # Errors
+///
+/// - [`Error::InvalidFragments`] when fragments are missing, out of order
+///   or belong to different messages.
+/// - [`Error::ParameterLength`] when the concatenated payload does not fit in `MAX_LEN`.
+pub fn concat_vdm_payloads<const MAX_LEN: usize>(
+    fragments: &[VdmData],
+) -> Result<(ArrayString<MAX_LEN>, u8), Error<'static>> {
+    let first = fragments.first().ok_or(Error::InvalidFragments)?;
+    if usize::from(first.fragment_count) != fragments.len() {
+        return Err(Error::InvalidFragments);
+    }
+
+    let mut payload = ArrayString::new();
+    for (index, fragment) in fragments.iter().enumerate() {
+        if usize::from(fragment.fragment_number) != index + 1
+            || fragment.fragment_count != first.fragment_count
+            || fragment.message_id != first.message_id
+        {
+            return Err(Error::InvalidFragments);
+        }
+
+        payload
+            .try_push_str(&fragment.payload)
+            .map_err(|_| Error::ParameterLength {
+                max_length: MAX_LEN,
+                parameter_length: fragments.iter().map(|f| f.payload.len()).sum(),
+            })?;
+    }
+
+    Ok((payload, fragments[fragments.len() - 1].fill_bits))
+}
+
+/// Raw bits of a de-armored AIS payload, see [`dearmor_ais_payload`].
+///
+/// The bits are stored MSB first, `N` is the capacity of the buffer in bytes.
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AisBits<const N: usize> {
+    bytes: [u8; N],
+    bit_len: usize,
+}
+
+impl<const N: usize> AisBits<N> {
+    /// Number of payload bits, without the fill bits.
+    pub fn bit_len(&self) -> usize {
+        self.bit_len
+    }
+
+    /// The bytes holding the bits, the unused bits of the last byte are zero.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..(self.bit_len + 7) / 8]
+    }
+
+    /// Returns the bit at `index` or `None` if it's out of range.
+    pub fn bit(&self, index: usize) -> Option<bool> {
+        if index >= self.bit_len {
+            return None;
+        }
+        Some(self.bytes[index / 8] & (0x80 >> (index % 8)) != 0)
+    }
+
+    /// Reads `len` bits starting at `start` as an unsigned big-endian integer,
+    /// which is how the fields of the AIS messages are laid out.
+    ///
+    /// Returns `None` if `len` is larger than 32 or the range is out of bounds.
+    pub fn unsigned(&self, start: usize, len: usize) -> Option<u32> {
+        if len > 32 || start + len > self.bit_len {
+            return None;
+        }
+        (start..start + len).try_fold(0u32, |value, index| {
+            Some(value << 1 | u32::from(self.bit(index)?))
+        })
+    }
+}
+
+/// Converts the 6-bit ASCII armored `payload` of VDM/VDO sentences into raw bits.
+///
+/// Every character is turned into 6 bits by subtracting 48 from its ASCII
+/// code, and another 8 if the result is above 40. The trailing `fill_bits`
+/// are dropped. `N` is the capacity of the returned buffer in bytes.
+///
+/// # Errors
+///
+/// - [`Error::InvalidAisCharacter`] when the payload contains a character
+///   outside of the `0`-`W` and `` ` ``-`w` ranges.
+/// - [`Error::InvalidFillBits`] when `fill_bits` is above 5 or more than the payload bits.
+/// - [`Error::ParameterLength`] when the bits do not fit in `N` bytes.
+///
+/// # Examples
+///
+/// ```
+/// use nmea::sentences::vdm::{dearmor_ais_payload, AisBits};
+///
+/// let bits: AisBits<64> = dearmor_ais_payload("13aEOK?P00PD2wVMdLDRhgvL289?", 0).unwrap();
+/// assert_eq!(bits.bit_len(), 168);
+/// // message type
+/// assert_eq!(bits.unsigned(0, 6), Some(1));
+/// ```
+pub fn dearmor_ais_payload<const N: usize>(
+    payload: &str,
+    fill_bits: u8,
+) -> Result<AisBits<N>, Error<'_>> {
+    let total_bits = payload.len() * 6;
+    if fill_bits > 5 || usize::from(fill_bits) > total_bits {
+        return Err(Error::InvalidFillBits(fill_bits));
+    }
+    let bit_len = total_bits - usize::from(fill_bits);
+    if (bit_len + 7) / 8 > N {
+        return Err(Error::ParameterLength {
+            max_length: N,
+            parameter_length: (bit_len + 7) / 8,
+        });
+    }
+
+    let mut bits = AisBits {
+        bytes: [0; N],
+        bit_len,
+    };
+    for (index, c) in payload.chars().enumerate() {
+        let value = match c {
+            '0'..='W' => c as u8 - 48,
+            '`'..='w' => c as u8 - 56,
+            _ => return Err(Error::InvalidAisCharacter(c)),
+        };
+        for bit in 0..6 {
+            let position = index * 6 + bit;
+            if position < bit_len && value & (0x20 >> bit) != 0 {
+                bits.bytes[position / 8] |= 0x80 >> (position % 8);
+            }
+        }
+    }
+
+    Ok(bits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_vdm() {
+        let s = parse_nmea_sentence("!AIVDM,1,1,,A,13aEOK?P00PD2wVMdLDRhgvL289?,0*26").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(s.talker_id, "AI");
+
+        let vdm = parse_vdm(s).unwrap();
+        assert_eq!(
+            vdm,
+            VdmData {
+                fragment_count: 1,
+                fragment_number: 1,
+                message_id: None,
+                channel: Some('A'),
+                payload: ArrayString::from("13aEOK?P00PD2wVMdLDRhgvL289?").unwrap(),
+                fill_bits: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_vdo_without_channel() {
+        let s = parse_nmea_sentence("!AIVDO,1,1,,,B5NJ;PP005l4ot5Isbl03wsUkP06,0*35").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+
+        let vdo = parse_vdm(s).unwrap();
+        assert_eq!(vdo.channel, None);
+        assert_eq!(&vdo.payload, "B5NJ;PP005l4ot5Isbl03wsUkP06");
+    }
+
+    #[test]
+    fn test_parse_vdm_invalid() {
+        // missing payload
+        let s = parse_nmea_sentence("!AIVDM,1,1,,A,,0*26").unwrap();
+        assert!(parse_vdm(s).is_err());
+
+        // invalid fill bits
+        let s = parse_nmea_sentence("!AIVDM,1,1,,A,13aEOK?P00PD2wVMdLDRhgvL289?,6*20").unwrap();
+        assert!(parse_vdm(s).is_err());
+
+        let s = parse_nmea_sentence("$GPHDT,274.07,T*03").unwrap();
+        assert_eq!(
+            parse_vdm(s),
+            Err(Error::WrongSentenceHeaders {
+                expected: &[SentenceType::VDM, SentenceType::VDO],
+                found: SentenceType::HDT,
+            })
+        );
+
+        // both sentence types are accepted
+        let s = parse_nmea_sentence("!AIVDO,1,1,,,B5NJ;PP005l4ot5Isbl03wsUkP06,0*35").unwrap();
+        assert!(parse_vdm(s).is_ok());
+    }
+
+    #[test]
+    fn test_concat_vdm_payloads() {
+        let fragments = [
+            "!AIVDM,2,1,3,B,55P5TL01VIaAL@7WKO@mBplU@<PDhh000000001S;AJ::4A80?4i@E53,0*3E",
+            "!AIVDM,2,2,3,B,1@0000000000000,2*55",
+        ]
+        .map(|sentence| parse_vdm(parse_nmea_sentence(sentence).unwrap()).unwrap());
+
+        assert_eq!(fragments[0].message_id, Some(3));
+        assert_eq!(fragments[1].fragment_number, 2);
+
+        let (payload, fill_bits) = concat_vdm_payloads::<128>(&fragments).unwrap();
+        assert_eq!(
+            &payload,
+            "55P5TL01VIaAL@7WKO@mBplU@<PDhh000000001S;AJ::4A80?4i@E531@0000000000000"
+        );
+        assert_eq!(fill_bits, 2);
+
+        assert_eq!(
+            concat_vdm_payloads::<32>(&fragments),
+            Err(Error::ParameterLength {
+                max_length: 32,
+                parameter_length: 71
+            })
+        );
+        // out of order
+        assert_eq!(
+            concat_vdm_payloads::<128>(&[fragments[1], fragments[0]]),
+            Err(Error::InvalidFragments)
+        );
+        // missing fragment
+        assert_eq!(
+            concat_vdm_payloads::<128>(&fragments[..1]),
+            Err(Error::InvalidFragments)
+        );
+        assert_eq!(
+            concat_vdm_payloads::<128>(&[]),
+            Err(Error::InvalidFragments)
+        );
+    }
+
+    #[test]
+    fn test_dearmor_ais_payload() {
+        // the boundaries of both ranges
+        let bits = dearmor_ais_payload::<3>("0W`w", 0).unwrap();
+        assert_eq!(bits.bit_len(), 24);
+        assert_eq!(bits.as_bytes(), &[0b0000_0010, 0b0111_1010, 0b0011_1111]);
+
+        // position report of MMSI 244670316
+        let bits = dearmor_ais_payload::<21>("13aEOK?P00PD2wVMdLDRhgvL289?", 0).unwrap();
+        assert_eq!(bits.bit_len(), 168);
+        assert_eq!(bits.unsigned(0, 6), Some(1));
+        assert_eq!(bits.unsigned(8, 30), Some(244670316));
+        assert_eq!(bits.unsigned(160, 9), None);
+        assert_eq!(bits.unsigned(0, 33), None);
+
+        // fill bits are dropped and zeroed
+        let bits = dearmor_ais_payload::<2>("1@", 2).unwrap();
+        assert_eq!(bits.bit_len(), 10);
+        assert_eq!(bits.as_bytes(), &[0b0000_0101, 0]);
+        assert_eq!(bits.bit(9), Some(false));
+        assert_eq!(bits.bit(10), None);
+    }
+
+    #[test]
+    fn test_dearmor_ais_payload_invalid() {
+        assert_eq!(
+            dearmor_ais_payload::<8>("13aX", 0),
+            Err(Error::InvalidAisCharacter('X'))
+        );
+        assert_eq!(
+            dearmor_ais_payload::<8>("13a/", 0),
+            Err(Error::InvalidAisCharacter('/'))
+        );
+        assert_eq!(
+            dearmor_ais_payload::<8>("13a", 6),
+            Err(Error::InvalidFillBits(6))
+        );
+        assert_eq!(
+            dearmor_ais_payload::<8>("", 2),
+            Err(Error::InvalidFillBits(2))
+        );
+        assert_eq!(
+            dearmor_ais_payload::<2>("13aE", 0),
+            Err(Error::ParameterLength {
+                max_length: 2,
+                parameter_length: 3
+            })
+        );
+    }
+}
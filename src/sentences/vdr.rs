@@ -0,0 +1,127 @@
+use nom::{
+    character::complete::char,
+    combinator::{eof, opt},
+    number::complete::float,
+    IResult,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    sentences::{hsc::reference, Reference},
+    Error, NmeaSentence, SentenceType,
+};
+
+/// VDR - Set and Drift
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_vdr_set_and_drift>
+///
+/// ```text
+///        1   2 3   4 5   6 7
+///        |   | |   | |   | |
+/// $--VDR,x.x,T,x.x,M,x.x,N*hh<CR><LF>
+/// ```
+/// 1. Direction of the current, degrees True
+/// 2. T = True
+/// 3. Direction of the current, degrees Magnetic
+/// 4. M = Magnetic
+/// 5. Current speed, knots
+/// 6. N = Knots
+/// 7. Checksum
+///
+/// Example:
+/// ```text
+/// $IIVDR,10.1,T,12.3,M,1.2,N*3A
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VdrData {
+    /// Direction of the current (set), degrees True
+    pub direction_true: Option<f32>,
+    /// Direction of the current (set), degrees Magnetic
+    pub direction_magnetic: Option<f32>,
+    /// Speed of the current (drift), knots
+    pub current_speed: Option<f32>,
+}
+
+/// # Parse VDR message
+///
+/// The reference letters have to be `T`, `M` and `N` respectively when present.
+pub fn parse_vdr(sentence: NmeaSentence) -> Result<VdrData, Error> {
+    if sentence.message_id != SentenceType::VDR {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::VDR,
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_vdr(sentence.data)?.1)
+    }
+}
+
+fn do_parse_vdr(i: &str) -> IResult<&str, VdrData> {
+    let (i, direction_true) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = reference(Reference::True)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, direction_magnetic) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = reference(Reference::Magnetic)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, current_speed) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('N'))(i)?;
+    let (i, _) = eof(i)?;
+
+    Ok((
+        i,
+        VdrData {
+            direction_true,
+            direction_magnetic,
+            current_speed,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_vdr() {
+        let s = parse_nmea_sentence("$IIVDR,10.1,T,12.3,M,1.2,N*3A").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_vdr(s).unwrap();
+        assert_relative_eq!(data.direction_true.unwrap(), 10.1);
+        assert_relative_eq!(data.direction_magnetic.unwrap(), 12.3);
+        assert_relative_eq!(data.current_speed.unwrap(), 1.2);
+    }
+
+    #[test]
+    fn test_parse_vdr_empty_fields() {
+        let s = parse_nmea_sentence("$IIVDR,,T,,M,,N*17").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(
+            parse_vdr(s),
+            Ok(VdrData {
+                direction_true: None,
+                direction_magnetic: None,
+                current_speed: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_vdr_wrong_reference() {
+        let s = parse_nmea_sentence("$IIVDR,10.1,M,12.3,M,1.2,N*23").unwrap();
+        assert!(matches!(parse_vdr(s), Err(Error::ParsingError(_))));
+
+        let s = parse_nmea_sentence("$IIVDR,10.1,T,12.3,M,1.2,K*3F").unwrap();
+        assert!(matches!(parse_vdr(s), Err(Error::ParsingError(_))));
+    }
+}
@@ -10,7 +10,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{Error, NmeaSentence, SentenceType};
 
-use super::utils::parse_float_num;
+use super::utils::{parse_float_num, Speed};
 
 /// VHW - Water speed and heading
 ///
@@ -35,6 +35,7 @@ use super::utils::parse_float_num;
 /// > [[GLOBALSAT](https://gpsd.gitlab.io/gpsd/NMEA.html#GLOBALSAT)] describes a different format in which the first three fields are water-temperature measurements.
 /// > It’s not clear which is correct.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[derive(Clone, PartialEq, Debug)]
 pub struct VhwData {
@@ -48,6 +49,19 @@ pub struct VhwData {
     pub relative_speed_kmph: Option<f64>,
 }
 
+impl VhwData {
+    /// Speed relative to the water as a typed [`Speed`], converted from km/hr
+    /// if only that is present.
+    pub fn water_speed(&self) -> Option<Speed> {
+        self.relative_speed_knots
+            .map(|knots| Speed::from_knots(knots as f32))
+            .or_else(|| {
+                self.relative_speed_kmph
+                    .map(|kph| Speed::from_kph(kph as f32))
+            })
+    }
+}
+
 /// # Parse VHW message
 ///
 /// ```text
@@ -130,7 +144,8 @@ mod tests {
             message_id: SentenceType::AAM,
             data: "",
             talker_id: "GP",
-            checksum: 0,
+            checksum: Some(0),
+            tag_block: None,
         };
         assert_eq!(
             Err(Error::WrongSentenceHeader {
@@ -147,7 +162,8 @@ mod tests {
             message_id: SentenceType::VHW,
             talker_id: "GP",
             data: "100.5,T,105.5,M,10.5,N,19.4,K",
-            checksum: 0x4f,
+            checksum: Some(0x4f),
+            tag_block: None,
         };
         let vhw_data = parse_vhw(s).unwrap();
         assert_relative_eq!(vhw_data.heading_true.unwrap(), 100.5);
@@ -156,14 +172,15 @@ mod tests {
         assert_relative_eq!(vhw_data.relative_speed_kmph.unwrap(), 19.4);
 
         let s = parse_nmea_sentence("$GPVHW,100.5,T,105.5,M,10.5,N,19.4,K*4F").unwrap();
-        assert_eq!(s.checksum, s.calc_checksum());
-        assert_eq!(s.checksum, 0x4F);
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(s.checksum, Some(0x4F));
 
         let vhw_data = parse_vhw(s).unwrap();
         assert_relative_eq!(vhw_data.heading_true.unwrap(), 100.5);
         assert_relative_eq!(vhw_data.heading_magnetic.unwrap(), 105.5);
         assert_relative_eq!(vhw_data.relative_speed_knots.unwrap(), 10.5);
         assert_relative_eq!(vhw_data.relative_speed_kmph.unwrap(), 19.4);
+        assert_relative_eq!(vhw_data.water_speed().unwrap().as_knots(), 10.5);
     }
 
     #[test]
@@ -173,7 +190,8 @@ mod tests {
             message_id: SentenceType::VHW,
             talker_id: "GP",
             data: ",T,,M,,N,,K",
-            checksum: 0,
+            checksum: Some(0),
+            tag_block: None,
         };
         assert_eq!(
             parse_vhw(s),
@@ -190,7 +208,8 @@ mod tests {
             message_id: SentenceType::VHW,
             talker_id: "GP",
             data: ",T,,M,10.5,N,20.0,K",
-            checksum: 0,
+            checksum: Some(0),
+            tag_block: None,
         };
         assert_eq!(
             parse_vhw(s),
@@ -207,7 +226,8 @@ mod tests {
             message_id: SentenceType::VHW,
             talker_id: "GP",
             data: ",,,,,,,",
-            checksum: 0,
+            checksum: Some(0),
+            tag_block: None,
         };
         assert_eq!(
             parse_vhw(s),
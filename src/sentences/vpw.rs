@@ -0,0 +1,119 @@
+use nom::{
+    character::complete::char,
+    combinator::{eof, opt},
+    number::complete::float,
+    IResult,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{parse::NmeaSentence, Error, SentenceType};
+
+/// VPW - Speed - Measured Parallel to Wind
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_vpw_speed_measured_parallel_to_wind>
+///
+/// ```text
+///        1   2 3   4 5
+///        |   | |   | |
+/// $--VPW,x.x,N,x.x,M*hh<CR><LF>
+/// ```
+/// 1. Speed, knots, negative values mean downwind
+/// 2. N = Knots
+/// 3. Speed, meters per second, negative values mean downwind
+/// 4. M = Meters per second
+/// 5. Checksum
+///
+/// Example:
+/// ```text
+/// $IIVPW,4.5,N,2.3,M*52
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VpwData {
+    /// Speed parallel to the wind, knots, negative when downwind
+    pub speed_knots: Option<f32>,
+    /// Speed parallel to the wind, meters/second, negative when downwind
+    pub speed_mps: Option<f32>,
+}
+
+/// # Parse VPW message
+///
+/// The unit letters are validated, a sentence with a wrong unit letter is rejected.
+pub fn parse_vpw(sentence: NmeaSentence) -> Result<VpwData, Error> {
+    if sentence.message_id != SentenceType::VPW {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::VPW,
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_vpw(sentence.data)?.1)
+    }
+}
+
+fn do_parse_vpw(i: &str) -> IResult<&str, VpwData> {
+    let (i, speed_knots) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('N'))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, speed_mps) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('M'))(i)?;
+    let (i, _) = eof(i)?;
+
+    Ok((
+        i,
+        VpwData {
+            speed_knots,
+            speed_mps,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_vpw() {
+        let s = parse_nmea_sentence("$IIVPW,4.5,N,2.3,M*52").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_vpw(s).unwrap();
+        assert_relative_eq!(data.speed_knots.unwrap(), 4.5);
+        assert_relative_eq!(data.speed_mps.unwrap(), 2.3);
+    }
+
+    #[test]
+    fn test_parse_vpw_downwind() {
+        let s = parse_nmea_sentence("$IIVPW,-2.1,N,-1.1,M*51").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_vpw(s).unwrap();
+        assert_relative_eq!(data.speed_knots.unwrap(), -2.1);
+        assert_relative_eq!(data.speed_mps.unwrap(), -1.1);
+    }
+
+    #[test]
+    fn test_parse_vpw_empty_fields() {
+        let s = parse_nmea_sentence("$IIVPW,,N,,M*52").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(
+            parse_vpw(s),
+            Ok(VpwData {
+                speed_knots: None,
+                speed_mps: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_vpw_invalid_unit() {
+        let s = parse_nmea_sentence("$IIVPW,4.5,K,2.3,M*57").unwrap();
+        assert!(parse_vpw(s).is_err());
+    }
+}
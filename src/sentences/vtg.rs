@@ -3,7 +3,11 @@ use nom::{character::complete::char, combinator::opt, number::complete::float, I
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::{parse::NmeaSentence, Error, SentenceType};
+use crate::{
+    parse::NmeaSentence,
+    sentences::utils::{kph_to_knots, Speed},
+    Error, SentenceType,
+};
 
 /// VTG - Track made good and Ground speed
 ///
@@ -21,6 +25,7 @@ use crate::{parse::NmeaSentence, Error, SentenceType};
 ///  $--VTG,x.x,T,x.x,M,x.x,N,x.x,K,m*hh<CR><LF>
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct VtgData {
@@ -28,6 +33,13 @@ pub struct VtgData {
     pub speed_over_ground: Option<f32>,
 }
 
+impl VtgData {
+    /// Speed over ground as a typed [`Speed`].
+    pub fn ground_speed(&self) -> Option<Speed> {
+        self.speed_over_ground.map(Speed::from_knots)
+    }
+}
+
 fn do_parse_vtg(i: &str) -> IResult<&str, VtgData> {
     let (i, true_course) = opt(float)(i)?;
     let (i, _) = char(',')(i)?;
@@ -50,7 +62,7 @@ fn do_parse_vtg(i: &str) -> IResult<&str, VtgData> {
             true_course,
             speed_over_ground: match (knots_ground_speed, kph_ground_speed) {
                 (Some(val), _) => Some(val),
-                (_, Some(val)) => Some(val / 1.852),
+                (_, Some(val)) => Some(kph_to_knots(val)),
                 (None, None) => None,
             },
         },
@@ -106,9 +118,9 @@ mod tests {
     use super::*;
     use crate::{parse::parse_nmea_sentence, Error};
 
-    fn run_parse_vtg(line: &str) -> Result<VtgData, Error> {
+    fn run_parse_vtg(line: &str) -> Result<VtgData, Error<'_>> {
         let s = parse_nmea_sentence(line).expect("VTG sentence initial parse failed");
-        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
         parse_vtg(s)
     }
 
@@ -0,0 +1,141 @@
+use nom::{
+    character::complete::{anychar, char},
+    combinator::{map_res, opt},
+    number::complete::float,
+    IResult,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::SteerDirection;
+use crate::{parse::NmeaSentence, Error, SentenceType};
+
+/// VWR - Relative Wind Speed and Angle
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_vwr_relative_wind_speed_and_angle>
+///
+/// ```text
+///        1   2 3   4 5   6 7   8 9
+///        |   | |   | |   | |   | |
+/// $--VWR,x.x,a,x.x,N,x.x,M,x.x,K*hh<CR><LF>
+/// ```
+///
+/// 1. Wind direction magnitude in degrees, 0 to 180
+/// 2. Wind direction Left/Right of bow
+/// 3. Speed
+/// 4. N = Knots
+/// 5. Speed
+/// 6. M = Meters Per Second
+/// 7. Speed
+/// 8. K = Kilometers Per Hour
+/// 9. Checksum
+///
+/// Example:
+/// ```text
+/// $IIVWR,045.0,L,12.6,N,6.5,M,23.3,K*52
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VwrData {
+    /// Wind angle relative to the bow in degrees, 0 to 180
+    pub wind_angle: Option<f32>,
+    /// Side of the bow the wind comes from
+    pub left_right: Option<SteerDirection>,
+    /// Wind speed, knots
+    pub speed_knots: Option<f32>,
+    /// Wind speed, meters/second
+    pub speed_mps: Option<f32>,
+    /// Wind speed, kilometers/hour
+    pub speed_kph: Option<f32>,
+}
+
+/// # Parse VWR message
+pub fn parse_vwr(sentence: NmeaSentence) -> Result<VwrData, Error> {
+    if sentence.message_id != SentenceType::VWR {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::VWR,
+            found: sentence.message_id,
+        })
+    } else {
+        Ok(do_parse_vwr(sentence.data)?.1)
+    }
+}
+
+/// Parses the fields of a VWR sentence.
+///
+/// VWT shares the exact same layout and is parsed by this function too.
+pub(crate) fn do_parse_vwr(i: &str) -> IResult<&str, VwrData> {
+    let (i, wind_angle) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, left_right) = opt(map_res(anychar, SteerDirection::try_from))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, speed_knots) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('N'))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, speed_mps) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('M'))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, speed_kph) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('K'))(i)?;
+
+    Ok((
+        i,
+        VwrData {
+            wind_angle,
+            left_right,
+            speed_knots,
+            speed_mps,
+            speed_kph,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_vwr() {
+        let s = parse_nmea_sentence("$IIVWR,045.0,L,12.6,N,6.5,M,23.3,K*52").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+
+        let data = parse_vwr(s).unwrap();
+        assert_relative_eq!(data.wind_angle.unwrap(), 45.0);
+        assert_eq!(data.left_right, Some(SteerDirection::Left));
+        assert_relative_eq!(data.speed_knots.unwrap(), 12.6);
+        assert_relative_eq!(data.speed_mps.unwrap(), 6.5);
+        assert_relative_eq!(data.speed_kph.unwrap(), 23.3);
+    }
+
+    #[test]
+    fn test_parse_vwr_angle_only() {
+        let s = parse_nmea_sentence("$IIVWR,120.5,R,,N,,M,,K*61").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+
+        assert_eq!(
+            parse_vwr(s),
+            Ok(VwrData {
+                wind_angle: Some(120.5),
+                left_right: Some(SteerDirection::Right),
+                speed_knots: None,
+                speed_mps: None,
+                speed_kph: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_vwr_invalid_side() {
+        let s = parse_nmea_sentence("$IIVWR,045.0,X,12.6,N,6.5,M,23.3,K*46").unwrap();
+        assert!(parse_vwr(s).is_err());
+    }
+}
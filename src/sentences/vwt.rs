@@ -0,0 +1,109 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    parse::NmeaSentence,
+    sentences::{vwr::do_parse_vwr, SteerDirection, VwrData},
+    Error, SentenceType,
+};
+
+/// VWT - True Wind Speed and Angle
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_vwt_true_wind_speed_and_angle>
+///
+/// ```text
+///        1   2 3   4 5   6 7   8 9
+///        |   | |   | |   | |   | |
+/// $--VWT,x.x,a,x.x,N,x.x,M,x.x,K*hh<CR><LF>
+/// ```
+///
+/// The fields are the same as in [`VwrData`], but the wind is the true wind
+/// instead of the wind relative to the moving vessel.
+///
+/// Example:
+/// ```text
+/// $IIVWT,030.5,R,10.1,N,5.2,M,18.7,K*40
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VwtData {
+    /// True wind angle relative to the bow in degrees, 0 to 180
+    pub wind_angle: Option<f32>,
+    /// Side of the bow the wind comes from
+    pub left_right: Option<SteerDirection>,
+    /// Wind speed, knots
+    pub speed_knots: Option<f32>,
+    /// Wind speed, meters/second
+    pub speed_mps: Option<f32>,
+    /// Wind speed, kilometers/hour
+    pub speed_kph: Option<f32>,
+}
+
+/// # Parse VWT message
+///
+/// See: <https://gpsd.gitlab.io/gpsd/NMEA.html#_vwt_true_wind_speed_and_angle>
+pub fn parse_vwt(sentence: NmeaSentence) -> Result<VwtData, Error> {
+    if sentence.message_id != SentenceType::VWT {
+        return Err(Error::WrongSentenceHeader {
+            expected: SentenceType::VWT,
+            found: sentence.message_id,
+        });
+    }
+
+    let (
+        _,
+        VwrData {
+            wind_angle,
+            left_right,
+            speed_knots,
+            speed_mps,
+            speed_kph,
+        },
+    ) = do_parse_vwr(sentence.data)?;
+
+    Ok(VwtData {
+        wind_angle,
+        left_right,
+        speed_knots,
+        speed_mps,
+        speed_kph,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_vwt() {
+        let s = parse_nmea_sentence("$IIVWT,030.5,R,10.1,N,5.2,M,18.7,K*40").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+
+        let data = parse_vwt(s).unwrap();
+        assert_relative_eq!(data.wind_angle.unwrap(), 30.5);
+        assert_eq!(data.left_right, Some(SteerDirection::Right));
+        assert_relative_eq!(data.speed_knots.unwrap(), 10.1);
+        assert_relative_eq!(data.speed_mps.unwrap(), 5.2);
+        assert_relative_eq!(data.speed_kph.unwrap(), 18.7);
+    }
+
+    #[test]
+    fn test_parse_vwt_invalid() {
+        let s = parse_nmea_sentence("$IIVWT,030.5,X,10.1,N,5.2,M,18.7,K*4A").unwrap();
+        assert!(parse_vwt(s).is_err());
+
+        let s = parse_nmea_sentence("$IIVWR,045.0,L,12.6,N,6.5,M,23.3,K*52").unwrap();
+        assert_eq!(
+            parse_vwt(s),
+            Err(Error::WrongSentenceHeader {
+                expected: SentenceType::VWT,
+                found: SentenceType::VWR,
+            })
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use arrayvec::ArrayString;
+use nom::{
+    bytes::complete::is_not, character::complete::char, combinator::opt, number::complete::float,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::utils::array_string;
+use crate::{
+    parse::{NmeaSentence, TEXT_PARAMETER_MAX_LEN},
+    Error, SentenceType,
+};
+
+/// WCV - Waypoint Closure Velocity
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_wcv_waypoint_closure_velocity>
+///
+/// ```text
+///        1   2 3    4
+///        |   | |    |
+/// $--WCV,x.x,N,c--c*hh<CR><LF>
+/// ```
+/// 1. Velocity
+/// 2. N = knots
+/// 3. Waypoint ID
+/// 4. Checksum
+///
+/// NMEA 2.3 and later append a FAA mode indicator, which is ignored.
+///
+/// Example:
+/// ```text
+/// $GPWCV,2.5,N,DEST*18
+/// ```
+///
+/// The waypoint ID is bounded by `MAX_LEN` characters, [`TEXT_PARAMETER_MAX_LEN`] by default.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, PartialEq)]
+pub struct WcvData<const MAX_LEN: usize = TEXT_PARAMETER_MAX_LEN> {
+    /// Velocity towards the waypoint, knots
+    pub velocity: Option<f32>,
+    /// Waypoint ID
+    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
+    pub waypoint_id: Option<ArrayString<MAX_LEN>>,
+}
+
+fn do_parse_wcv<const MAX_LEN: usize>(i: &str) -> Result<WcvData<MAX_LEN>, Error<'_>> {
+    // 1. Velocity
+    let (i, velocity) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    // 2. N = knots
+    let (i, _) = opt(char('N'))(i)?;
+    let (i, _) = char(',')(i)?;
+    // 3. Waypoint ID
+    let (_i, waypoint_id) = opt(is_not(","))(i)?;
+    let waypoint_id = waypoint_id.map(array_string::<MAX_LEN>).transpose()?;
+
+    Ok(WcvData {
+        velocity,
+        waypoint_id,
+    })
+}
+
+/// # Parse WCV message
+///
+/// See: <https://gpsd.gitlab.io/gpsd/NMEA.html#_wcv_waypoint_closure_velocity>
+///
+/// The waypoint ID is bounded by [`TEXT_PARAMETER_MAX_LEN`], use
+/// [`parse_wcv_with_max_len`] for a different bound.
+pub fn parse_wcv(sentence: NmeaSentence) -> Result<WcvData, Error> {
+    parse_wcv_with_max_len(sentence)
+}
+
+/// Parse WCV message with the waypoint ID bounded by `MAX_LEN` characters.
+///
+/// # Errors
+///
+/// [`Error::ParameterLength`] with `max_length` set to `MAX_LEN` if the waypoint ID is longer.
+pub fn parse_wcv_with_max_len<const MAX_LEN: usize>(
+    sentence: NmeaSentence,
+) -> Result<WcvData<MAX_LEN>, Error> {
+    if sentence.message_id != SentenceType::WCV {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::WCV,
+            found: sentence.message_id,
+        })
+    } else {
+        do_parse_wcv(sentence.data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_wcv() {
+        let s = parse_nmea_sentence("$GPWCV,2.5,N,DEST*18").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_wcv(s).unwrap();
+        assert_relative_eq!(data.velocity.unwrap(), 2.5);
+        assert_eq!(data.waypoint_id.as_deref(), Some("DEST"));
+
+        // NMEA 2.3 FAA mode indicator
+        let s = parse_nmea_sentence("$GPWCV,2.5,N,DEST,A*75").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        let data = parse_wcv(s).unwrap();
+        assert_eq!(data.waypoint_id.as_deref(), Some("DEST"));
+    }
+
+    #[test]
+    fn test_parse_wcv_empty_fields() {
+        let s = parse_nmea_sentence("$GPWCV,,N,*37").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(
+            parse_wcv(s),
+            Ok(WcvData {
+                velocity: None,
+                waypoint_id: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_wcv_invalid_unit() {
+        let s = parse_nmea_sentence("$GPWCV,2.5,K,DEST*1D").unwrap();
+        assert!(matches!(parse_wcv(s), Err(Error::ParsingError(_))));
+    }
+}
@@ -32,50 +32,66 @@ use crate::{
 /// 4. K = Kilometers
 /// 5. Waypoint ID, Destination
 /// 6. Waypoint ID, Origin
+///
+/// The waypoint IDs are bounded by `MAX_LEN` characters, [`TEXT_PARAMETER_MAX_LEN`] by default.
 #[derive(Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
-pub struct WncData {
+pub struct WncData<const MAX_LEN: usize = TEXT_PARAMETER_MAX_LEN> {
     /// Distance, Nautical Miles
-    pub distance_nautical_miles: Option<f32>,
+    pub distance_nm: Option<f32>,
     /// Distance, Kilometers
-    pub distance_kilometers: Option<f32>,
+    pub distance_km: Option<f32>,
     /// Waypoint ID, Destination
     #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
-    pub waypoint_id_destination: Option<ArrayString<TEXT_PARAMETER_MAX_LEN>>,
+    pub to_waypoint_id: Option<ArrayString<MAX_LEN>>,
     /// Waypoint ID, Origin
     #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
-    pub waypoint_id_origin: Option<ArrayString<TEXT_PARAMETER_MAX_LEN>>,
+    pub from_waypoint_id: Option<ArrayString<MAX_LEN>>,
 }
 
-pub fn do_parse_wnc(i: &str) -> Result<WncData, Error> {
-    let (i, distance_nautical_miles) = opt(float)(i)?;
+pub fn do_parse_wnc<const MAX_LEN: usize>(i: &str) -> Result<WncData<MAX_LEN>, Error<'_>> {
+    let (i, distance_nm) = opt(float)(i)?;
     let (i, _) = char(',')(i)?;
     let (i, _) = opt(char('N'))(i)?;
     let (i, _) = char(',')(i)?;
-    let (i, distance_kilometers) = opt(float)(i)?;
+    let (i, distance_km) = opt(float)(i)?;
     let (i, _) = char(',')(i)?;
     let (i, _) = opt(char('K'))(i)?;
     let (i, _) = char(',')(i)?;
-    let (i, waypoint_id_destination) = opt(is_not(","))(i)?;
-    let waypoint_id_destination = waypoint_id_destination
-        .map(array_string::<TEXT_PARAMETER_MAX_LEN>)
-        .transpose()?;
+    let (i, to_waypoint_id) = opt(is_not(","))(i)?;
+    let to_waypoint_id = to_waypoint_id.map(array_string::<MAX_LEN>).transpose()?;
     let (i, _) = char(',')(i)?;
-    let (_i, waypoint_id_origin) = opt(is_not(","))(i)?;
-    let waypoint_id_origin = waypoint_id_origin
-        .map(array_string::<TEXT_PARAMETER_MAX_LEN>)
-        .transpose()?;
+    let (_i, from_waypoint_id) = opt(is_not(","))(i)?;
+    let from_waypoint_id = from_waypoint_id.map(array_string::<MAX_LEN>).transpose()?;
 
     Ok(WncData {
-        distance_nautical_miles,
-        distance_kilometers,
-        waypoint_id_destination,
-        waypoint_id_origin,
+        distance_nm,
+        distance_km,
+        to_waypoint_id,
+        from_waypoint_id,
     })
 }
 
+/// # Parse WNC message
+///
+/// See: <https://gpsd.gitlab.io/gpsd/NMEA.html#_wnc_distance_waypoint_to_waypoint>
+///
+/// The waypoint IDs are bounded by [`TEXT_PARAMETER_MAX_LEN`], use
+/// [`parse_wnc_with_max_len`] for a different bound.
 pub fn parse_wnc(sentence: NmeaSentence) -> Result<WncData, Error> {
+    parse_wnc_with_max_len(sentence)
+}
+
+/// Parse WNC message with the waypoint IDs bounded by `MAX_LEN` characters.
+///
+/// # Errors
+///
+/// [`Error::ParameterLength`] with `max_length` set to `MAX_LEN` if one of them is longer.
+pub fn parse_wnc_with_max_len<const MAX_LEN: usize>(
+    sentence: NmeaSentence,
+) -> Result<WncData<MAX_LEN>, Error> {
     if sentence.message_id != SentenceType::WNC {
         Err(Error::WrongSentenceHeader {
             expected: SentenceType::WNC,
@@ -92,22 +108,35 @@ mod tests {
     use crate::{parse::parse_nmea_sentence, Error};
     use approx::assert_relative_eq;
 
-    fn run_parse_wnc(line: &str) -> Result<WncData, Error> {
+    fn run_parse_wnc(line: &str) -> Result<WncData, Error<'_>> {
         let s = parse_nmea_sentence(line).expect("WNC sentence initial parse failed");
-        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
         parse_wnc(s)
     }
 
     #[test]
     fn test_parse_wnc() {
         let sentence = parse_nmea_sentence("$GPWNC,200.00,N,370.40,K,Dest,Origin*58").unwrap();
-        assert_eq!(sentence.checksum, sentence.calc_checksum());
-        assert_eq!(sentence.checksum, 0x58);
+        assert_eq!(sentence.checksum, Some(sentence.calc_checksum()));
+        assert_eq!(sentence.checksum, Some(0x58));
 
         let data = run_parse_wnc("$GPWNC,200.00,N,370.40,K,Dest,Origin*58").unwrap();
-        assert_relative_eq!(data.distance_nautical_miles.unwrap(), 200.00);
-        assert_relative_eq!(data.distance_kilometers.unwrap(), 370.40);
-        assert_eq!(data.waypoint_id_destination.as_deref(), Some("Dest"));
-        assert_eq!(data.waypoint_id_origin.as_deref(), Some("Origin"));
+        assert_relative_eq!(data.distance_nm.unwrap(), 200.00);
+        assert_relative_eq!(data.distance_km.unwrap(), 370.40);
+        assert_eq!(data.to_waypoint_id.as_deref(), Some("Dest"));
+        assert_eq!(data.from_waypoint_id.as_deref(), Some("Origin"));
+    }
+
+    #[test]
+    fn test_parse_wnc_empty_fields() {
+        assert_eq!(
+            run_parse_wnc("$GPWNC,,N,,K,,*48"),
+            Ok(WncData {
+                distance_nm: None,
+                distance_km: None,
+                to_waypoint_id: None,
+                from_waypoint_id: None,
+            })
+        );
     }
 }
@@ -0,0 +1,227 @@
+use arrayvec::{ArrayString, ArrayVec};
+use nom::{
+    bytes::complete::is_not,
+    character::complete::{anychar, char},
+    combinator::opt,
+    number::complete::float,
+    IResult,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::utils::array_string;
+use crate::{parse::NmeaSentence, Error, SentenceType};
+
+/// Maximum number of measurements in a single XDR sentence.
+pub const XDR_MAX_MEASUREMENTS: usize = 8;
+
+/// Number of fields of a single XDR measurement.
+const XDR_MEASUREMENT_FIELDS: usize = 4;
+/// Maximum number of fields of a XDR sentence.
+const XDR_MAX_FIELDS: usize = XDR_MAX_MEASUREMENTS * XDR_MEASUREMENT_FIELDS;
+
+/// Maximum length of the transducer name of an XDR measurement.
+pub const XDR_ID_MAX_LEN: usize = 20;
+
+/// XDR - Transducer Measurements
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_xdr_transducer_measurement>
+///
+/// ```text
+///        1 2   3 4            n
+///        | |   | |            |
+/// $--XDR,a,x.x,a,c--c, ..... *hh<CR><LF>
+/// ```
+///
+/// 1. Transducer Type
+/// 2. Measurement Data
+/// 3. Units of measurement
+/// 4. Name of transducer
+///
+/// There may be any number of quadruplets like this, each describing a sensor.
+/// At most [`XDR_MAX_MEASUREMENTS`] are parsed, more result in an
+/// [`Error::TooManyFields`]. An incomplete quadruplet results in an
+/// [`Error::TooFewFields`].
+///
+/// Example:
+/// ```text
+/// $IIXDR,C,19.52,C,TempAir,P,1.02481,B,Barometer*7E
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct XdrData {
+    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
+    pub measurements: ArrayVec<XdrMeasurement, XDR_MAX_MEASUREMENTS>,
+}
+
+/// A single measurement of an [`XdrData`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct XdrMeasurement {
+    /// Transducer type, e.g. `C` for temperature or `P` for pressure
+    pub transducer_type: char,
+    pub value: Option<f32>,
+    /// Units of the value, e.g. `C` for degrees Celsius or `B` for bars
+    pub units: Option<char>,
+    /// Name of the transducer
+    #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
+    pub id: Option<ArrayString<XDR_ID_MAX_LEN>>,
+}
+
+/// # Parse XDR message
+pub fn parse_xdr(sentence: NmeaSentence) -> Result<XdrData, Error> {
+    if sentence.message_id != SentenceType::XDR {
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::XDR,
+            found: sentence.message_id,
+        })
+    } else {
+        do_parse_xdr(sentence.data)
+    }
+}
+
+fn do_parse_xdr(i: &str) -> Result<XdrData, Error<'_>> {
+    let fields = i.split(',').count();
+    if fields > XDR_MAX_FIELDS {
+        return Err(Error::TooManyFields {
+            sentence: SentenceType::XDR,
+            max: XDR_MAX_FIELDS,
+        });
+    }
+    if fields % XDR_MEASUREMENT_FIELDS != 0 {
+        return Err(Error::TooFewFields {
+            sentence: SentenceType::XDR,
+            expected: fields + XDR_MEASUREMENT_FIELDS - fields % XDR_MEASUREMENT_FIELDS,
+        });
+    }
+
+    let mut measurements = ArrayVec::new();
+    let mut i = i;
+    loop {
+        let (rest, (transducer_type, value, units, id)) = parse_xdr_measurement(i)?;
+        // can't overflow, the number of fields is checked above
+        measurements.push(XdrMeasurement {
+            transducer_type,
+            value,
+            units,
+            id: id.map(array_string::<XDR_ID_MAX_LEN>).transpose()?,
+        });
+
+        match opt(char(','))(rest)? {
+            (rest, Some(_)) => i = rest,
+            (_, None) => break,
+        }
+    }
+
+    Ok(XdrData { measurements })
+}
+
+type RawXdrMeasurement<'a> = (char, Option<f32>, Option<char>, Option<&'a str>);
+
+fn parse_xdr_measurement(i: &str) -> IResult<&str, RawXdrMeasurement<'_>> {
+    let (i, transducer_type) = anychar(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, value) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, units) = opt(is_not(","))(i)?;
+    let units = units.and_then(|units| units.chars().next());
+    let (i, _) = char(',')(i)?;
+    let (i, id) = opt(is_not(","))(i)?;
+
+    Ok((i, (transducer_type, value, units, id)))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::parse::parse_nmea_sentence;
+
+    #[test]
+    fn test_parse_xdr() {
+        let s = parse_nmea_sentence("$IIXDR,C,19.52,C,TempAir,P,1.02481,B,Barometer*7E").unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+
+        let data = parse_xdr(s).unwrap();
+        assert_eq!(data.measurements.len(), 2);
+
+        let temperature = &data.measurements[0];
+        assert_eq!(temperature.transducer_type, 'C');
+        assert_relative_eq!(temperature.value.unwrap(), 19.52);
+        assert_eq!(temperature.units, Some('C'));
+        assert_eq!(temperature.id.as_deref(), Some("TempAir"));
+
+        let pressure = &data.measurements[1];
+        assert_eq!(pressure.transducer_type, 'P');
+        assert_relative_eq!(pressure.value.unwrap(), 1.02481);
+        assert_eq!(pressure.units, Some('B'));
+        assert_eq!(pressure.id.as_deref(), Some("Barometer"));
+    }
+
+    #[test]
+    fn test_parse_xdr_without_units_and_values() {
+        let s = parse_nmea_sentence(
+            "$HCXDR,A,171,D,PITCH,A,-37,D,ROLL,G,367,,MAGX,G,2420,,MAGY,G,-8984,,MAGZ*41",
+        )
+        .unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+
+        let data = parse_xdr(s).unwrap();
+        assert_eq!(data.measurements.len(), 5);
+        assert_relative_eq!(data.measurements[1].value.unwrap(), -37.0);
+        assert_eq!(data.measurements[4].units, None);
+        assert_eq!(data.measurements[4].id.as_deref(), Some("MAGZ"));
+
+        let s = parse_nmea_sentence("$IIXDR,C,,C,*4E").unwrap();
+        assert_eq!(
+            parse_xdr(s).unwrap().measurements.as_slice(),
+            &[XdrMeasurement {
+                transducer_type: 'C',
+                value: None,
+                units: Some('C'),
+                id: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_xdr_too_many_measurements() {
+        let s = parse_nmea_sentence(
+            "$IIXDR,C,1,C,A,C,2,C,B,C,3,C,C,C,4,C,D,C,5,C,E,C,6,C,F,C,7,C,G,C,8,C,H,C,9,C,I*3E",
+        )
+        .unwrap();
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+
+        assert_eq!(
+            parse_xdr(s),
+            Err(Error::TooManyFields {
+                sentence: SentenceType::XDR,
+                max: 32
+            })
+        );
+
+        let s = parse_nmea_sentence("$IIXDR,C,19.52,C,TempAir,P,1.02481*69").unwrap();
+        assert_eq!(
+            parse_xdr(s),
+            Err(Error::TooFewFields {
+                sentence: SentenceType::XDR,
+                expected: 8
+            })
+        );
+
+        let s = parse_nmea_sentence("$IIXDR,C,19.52,C,TRANSDUCER_NAME_TOO_LONG*76").unwrap();
+        assert_eq!(
+            parse_xdr(s),
+            Err(Error::ParameterLength {
+                max_length: XDR_ID_MAX_LEN,
+                parameter_length: 24
+            })
+        );
+    }
+}
@@ -1,4 +1,5 @@
-use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime};
 use nom::{
     bytes::complete::take,
     character::complete::char,
@@ -9,7 +10,11 @@ use nom::{
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::{parse::NmeaSentence, sentences::utils::parse_hms, Error, SentenceType};
+use crate::{
+    parse::NmeaSentence,
+    sentences::utils::{parse_hms, Time},
+    Error, SentenceType,
+};
 
 use super::utils::{parse_num, parse_number_in_range};
 
@@ -31,11 +36,12 @@ use super::utils::{parse_num, parse_number_in_range};
 /// 6. Local zone minutes description, 00 to 59, apply same sign as local hours
 /// 7. Checksum
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ZdaData {
     #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
-    pub utc_time: Option<NaiveTime>,
+    pub utc_time: Option<Time>,
     pub day: Option<u8>,
     pub month: Option<u8>,
     pub year: Option<u16>,
@@ -46,6 +52,8 @@ pub struct ZdaData {
 impl ZdaData {
     /// Get UTC date by `day`, `month` and `year` fields.
     /// Returns `None` if any field is `None`.
+    #[cfg(feature = "chrono")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
     pub fn utc_date(&self) -> Option<NaiveDate> {
         if let Some(((day, month), year)) = self.day.zip(self.month).zip(self.year) {
             NaiveDate::from_ymd_opt(year.into(), month.into(), day.into())
@@ -56,6 +64,8 @@ impl ZdaData {
 
     /// Get UTC date time by `utc_time`, `day`, `month`, and `year` fields.
     /// Returns `None` if any field is `None`.
+    #[cfg(feature = "chrono")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
     pub fn utc_date_time(&self) -> Option<NaiveDateTime> {
         self.utc_time.and_then(|utc_time| {
             self.utc_date()
@@ -65,6 +75,8 @@ impl ZdaData {
 
     /// Get `chrono::FixedOffset` by `local_zone_hours` and `local_zone_minutes` fields.
     /// Return `Some` if either `local_zone_hours` or `local_zone_minutes` is `Some`.
+    #[cfg(feature = "chrono")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
     pub fn offset(&self) -> Option<FixedOffset> {
         let hours = self.local_zone_hours.map(i32::from);
         let minutes = self.local_zone_minutes.map(i32::from);
@@ -78,6 +90,8 @@ impl ZdaData {
 
     /// Caluculate local datetime
     /// Returns `None` if any field is `None`.
+    #[cfg(feature = "chrono")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
     pub fn local_date_time(&self) -> Option<DateTime<FixedOffset>> {
         self.utc_date_time()
             .zip(self.offset())
@@ -146,17 +160,17 @@ fn do_parse_zda(i: &str) -> IResult<&str, ZdaData> {
     ))
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "chrono"))]
 mod tests {
-    use chrono::TimeZone;
+    use chrono::{NaiveTime, TimeZone};
 
     use super::*;
     use crate::parse_nmea_sentence;
 
     fn assert_zda_sentence(sentence: &str, checksum: u8, expected: ZdaData) {
         let s = parse_nmea_sentence(sentence).unwrap();
-        assert_eq!(s.checksum, s.calc_checksum());
-        assert_eq!(s.checksum, checksum);
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(s.checksum, Some(checksum));
         let zda_data = parse_zda(s).unwrap();
         assert_eq!(zda_data, expected);
     }
@@ -222,7 +236,8 @@ mod tests {
             message_id: SentenceType::AAM,
             data: "",
             talker_id: "GP",
-            checksum: 0,
+            checksum: Some(0),
+            tag_block: None,
         };
         assert_eq!(
             Err(Error::WrongSentenceHeader {
@@ -236,8 +251,8 @@ mod tests {
     #[test]
     fn test_parse_zda_datetime() {
         let s = parse_nmea_sentence("$GPZDA,160012.71,11,03,2004,-1,00*7D").unwrap();
-        assert_eq!(s.checksum, s.calc_checksum());
-        assert_eq!(s.checksum, 0x7d);
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
+        assert_eq!(s.checksum, Some(0x7d));
         let zda_data = parse_zda(s).unwrap();
         assert_eq!(
             zda_data.utc_date(),
@@ -252,12 +267,12 @@ mod tests {
         );
         assert_eq!(
             zda_data.offset(),
-            Some(FixedOffset::east_opt(-1 * 60 * 60).unwrap())
+            Some(FixedOffset::east_opt(-60 * 60).unwrap())
         );
         assert_eq!(
             zda_data.local_date_time(),
             Some(
-                FixedOffset::east_opt(-1 * 60 * 60)
+                FixedOffset::east_opt(-60 * 60)
                     .unwrap()
                     .from_local_datetime(&NaiveDateTime::new(
                         NaiveDate::from_ymd_opt(2004, 3, 11).unwrap(),
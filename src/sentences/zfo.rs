@@ -1,20 +1,22 @@
 use arrayvec::ArrayString;
-use chrono::{Duration, NaiveTime};
 use nom::{bytes::complete::is_not, character::complete::char, combinator::opt};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-#[cfg(feature = "serde")]
+#[cfg(all(feature = "serde", feature = "chrono"))]
 use serde_with::As;
 
 use crate::{
     parse::{NmeaSentence, TEXT_PARAMETER_MAX_LEN},
-    sentences::utils::{array_string, parse_duration_hms, parse_hms},
+    sentences::utils::{array_string, parse_duration_hms, parse_hms, Duration, Time},
     Error, SentenceType,
 };
 
 /// ZFO - UTC & Time from origin Waypoint
+///
+/// <https://gpsd.gitlab.io/gpsd/NMEA.html#_zfo_utc_time_from_origin_waypoint>
+///
 ///```text
 ///        1         2         3    4
 ///        |         |         |    |
@@ -25,48 +27,76 @@ use crate::{
 /// 2. Elapsed Time
 /// 3. Origin Waypoint ID
 /// 4. Checksum
+///
+/// The layout is the same as [`ZtgData`](super::ZtgData), with the elapsed
+/// time from the origin waypoint in `elapsed_time` where ZTG has the time to
+/// go in `fix_duration`.
+///
+/// Example:
+/// ```text
+/// $GPZFO,145832.12,042359.17,WPT*3E
+/// ```
+///
+/// The waypoint ID is bounded by `MAX_LEN` characters, [`TEXT_PARAMETER_MAX_LEN`] by default.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[derive(Debug, PartialEq, Eq)]
-pub struct ZfoData {
+pub struct ZfoData<const MAX_LEN: usize = TEXT_PARAMETER_MAX_LEN> {
     #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
-    pub fix_time: Option<NaiveTime>,
+    pub fix_time: Option<Time>,
+    /// Elapsed time from the origin waypoint
     #[cfg_attr(
-        feature = "serde",
+        all(feature = "serde", feature = "chrono"),
         serde(with = "As::<Option<serde_with::DurationSecondsWithFrac<f64>>>")
     )]
     #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
-    pub fix_duration: Option<Duration>,
+    pub elapsed_time: Option<Duration>,
+    /// Origin waypoint ID
     #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
-    pub waypoint_id: Option<ArrayString<TEXT_PARAMETER_MAX_LEN>>,
+    pub origin_waypoint_id: Option<ArrayString<MAX_LEN>>,
 }
 
-fn do_parse_zfo(i: &str) -> Result<ZfoData, Error> {
+fn do_parse_zfo<const MAX_LEN: usize>(i: &str) -> Result<ZfoData<MAX_LEN>, Error<'_>> {
     // 1. UTC Time or observation
     let (i, fix_time) = opt(parse_hms)(i)?;
     let (i, _) = char(',')(i)?;
-    // 2. Duration
-    let (i, fix_duration) = opt(parse_duration_hms)(i)?;
+    // 2. Elapsed Time
+    let (i, elapsed_time) = opt(parse_duration_hms)(i)?;
     let (i, _) = char(',')(i)?;
 
-    // 12. Waypoint ID
-    let (_i, waypoint_id) = opt(is_not(",*"))(i)?;
+    // 3. Origin Waypoint ID
+    let (_i, origin_waypoint_id) = opt(is_not(",*"))(i)?;
 
-    let waypoint_id = waypoint_id
-        .map(array_string::<TEXT_PARAMETER_MAX_LEN>)
+    let origin_waypoint_id = origin_waypoint_id
+        .map(array_string::<MAX_LEN>)
         .transpose()?;
 
     Ok(ZfoData {
         fix_time,
-        fix_duration,
-        waypoint_id,
+        elapsed_time,
+        origin_waypoint_id,
     })
 }
 
 /// # Parse ZFO message
 ///
 /// See: <https://gpsd.gitlab.io/gpsd/NMEA.html#_zfo_utc_time_from_origin_waypoint>
+///
+/// The waypoint ID is bounded by [`TEXT_PARAMETER_MAX_LEN`], use
+/// [`parse_zfo_with_max_len`] for a different bound.
 pub fn parse_zfo(sentence: NmeaSentence) -> Result<ZfoData, Error> {
+    parse_zfo_with_max_len(sentence)
+}
+
+/// Parse ZFO message with the waypoint ID bounded by `MAX_LEN` characters.
+///
+/// # Errors
+///
+/// [`Error::ParameterLength`] with `max_length` set to `MAX_LEN` if the waypoint ID is longer.
+pub fn parse_zfo_with_max_len<const MAX_LEN: usize>(
+    sentence: NmeaSentence,
+) -> Result<ZfoData<MAX_LEN>, Error> {
     if sentence.message_id != SentenceType::ZFO {
         Err(Error::WrongSentenceHeader {
             expected: SentenceType::ZFO,
@@ -77,14 +107,16 @@ pub fn parse_zfo(sentence: NmeaSentence) -> Result<ZfoData, Error> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "chrono"))]
 mod tests {
+    use chrono::{Duration, NaiveTime};
+
     use super::*;
     use crate::{parse::parse_nmea_sentence, Error};
 
-    fn run_parse_zfo(line: &str) -> Result<ZfoData, Error> {
+    fn run_parse_zfo(line: &str) -> Result<ZfoData, Error<'_>> {
         let s = parse_nmea_sentence(line).expect("ZFO sentence initial parse failed");
-        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
         parse_zfo(s)
     }
 
@@ -92,35 +124,35 @@ mod tests {
     fn test_parse_zfo() {
         assert_eq!(
             ZfoData {
-                fix_duration: Some(
+                elapsed_time: Some(
                     Duration::hours(4)
                         + Duration::minutes(23)
                         + Duration::seconds(59)
                         + Duration::milliseconds(170)
                 ),
                 fix_time: NaiveTime::from_hms_milli_opt(14, 58, 32, 120),
-                waypoint_id: Some(ArrayString::from("WPT").unwrap()),
+                origin_waypoint_id: Some(ArrayString::from("WPT").unwrap()),
             },
             run_parse_zfo("$GPZFO,145832.12,042359.17,WPT*3E").unwrap()
         );
         assert_eq!(
             ZfoData {
-                fix_duration: None,
+                elapsed_time: None,
                 fix_time: None,
-                waypoint_id: None,
+                origin_waypoint_id: None,
             },
             run_parse_zfo("$GPZFO,,,*68").unwrap()
         );
         assert_eq!(
             ZfoData {
-                fix_duration: Some(
+                elapsed_time: Some(
                     Duration::hours(4)
                         + Duration::minutes(23)
                         + Duration::seconds(59)
                         + Duration::milliseconds(170)
                 ),
                 fix_time: None,
-                waypoint_id: None,
+                origin_waypoint_id: None,
             },
             run_parse_zfo("$GPZFO,,042359.17,*49").unwrap()
         );
@@ -1,16 +1,18 @@
+use core::fmt::{self, Write};
+
 use arrayvec::ArrayString;
-use chrono::{Duration, NaiveTime};
 use nom::{bytes::complete::is_not, character::complete::char, combinator::opt};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-#[cfg(feature = "serde")]
+#[cfg(all(feature = "serde", feature = "chrono"))]
 use serde_with::As;
 
 use crate::{
+    encode::{write_duration_hms, write_hms, write_opt, ToNmeaSentence},
     parse::{NmeaSentence, TEXT_PARAMETER_MAX_LEN},
-    sentences::utils::{parse_duration_hms, parse_hms},
+    sentences::utils::{parse_duration_hms, parse_hms, Duration, Time},
     Error, SentenceType,
 };
 
@@ -27,23 +29,26 @@ use super::utils::array_string;
 /// 2. Time Remaining
 /// 3. Destination Waypoint ID
 /// 4. Checksum
+///
+/// The waypoint ID is bounded by `MAX_LEN` characters, [`TEXT_PARAMETER_MAX_LEN`] by default.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[derive(Debug, PartialEq, Eq)]
-pub struct ZtgData {
+pub struct ZtgData<const MAX_LEN: usize = TEXT_PARAMETER_MAX_LEN> {
     #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
-    pub fix_time: Option<NaiveTime>,
+    pub fix_time: Option<Time>,
     #[cfg_attr(
-        feature = "serde",
+        all(feature = "serde", feature = "chrono"),
         serde(with = "As::<Option<serde_with::DurationSecondsWithFrac<f64>>>")
     )]
     #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
     pub fix_duration: Option<Duration>,
     #[cfg_attr(feature = "defmt-03", defmt(Debug2Format))]
-    pub waypoint_id: Option<ArrayString<TEXT_PARAMETER_MAX_LEN>>,
+    pub waypoint_id: Option<ArrayString<MAX_LEN>>,
 }
 
-fn do_parse_ztg(i: &str) -> Result<ZtgData, Error> {
+fn do_parse_ztg<const MAX_LEN: usize>(i: &str) -> Result<ZtgData<MAX_LEN>, Error<'_>> {
     // 1. UTC Time or observation
     let (i, fix_time) = opt(parse_hms)(i)?;
     let (i, _) = char(',')(i)?;
@@ -54,9 +59,7 @@ fn do_parse_ztg(i: &str) -> Result<ZtgData, Error> {
     // 12. Waypoint ID
     let (_i, waypoint_id) = opt(is_not(",*"))(i)?;
 
-    let waypoint_id = waypoint_id
-        .map(array_string::<TEXT_PARAMETER_MAX_LEN>)
-        .transpose()?;
+    let waypoint_id = waypoint_id.map(array_string::<MAX_LEN>).transpose()?;
 
     Ok(ZtgData {
         fix_time,
@@ -68,7 +71,21 @@ fn do_parse_ztg(i: &str) -> Result<ZtgData, Error> {
 /// # Parse ZTG message
 ///
 /// See: <https://gpsd.gitlab.io/gpsd/NMEA.html#_ztg_utc_time_to_destination_waypoint>
+///
+/// The waypoint ID is bounded by [`TEXT_PARAMETER_MAX_LEN`], use
+/// [`parse_ztg_with_max_len`] for a different bound.
 pub fn parse_ztg(sentence: NmeaSentence) -> Result<ZtgData, Error> {
+    parse_ztg_with_max_len(sentence)
+}
+
+/// Parse ZTG message with the waypoint ID bounded by `MAX_LEN` characters.
+///
+/// # Errors
+///
+/// [`Error::ParameterLength`] with `max_length` set to `MAX_LEN` if the waypoint ID is longer.
+pub fn parse_ztg_with_max_len<const MAX_LEN: usize>(
+    sentence: NmeaSentence,
+) -> Result<ZtgData<MAX_LEN>, Error> {
     if sentence.message_id != SentenceType::ZTG {
         Err(Error::WrongSentenceHeader {
             expected: SentenceType::ZTG,
@@ -79,14 +96,30 @@ pub fn parse_ztg(sentence: NmeaSentence) -> Result<ZtgData, Error> {
     }
 }
 
-#[cfg(test)]
+impl<const MAX_LEN: usize> ToNmeaSentence for ZtgData<MAX_LEN> {
+    fn sentence_type(&self) -> SentenceType {
+        SentenceType::ZTG
+    }
+
+    fn write_data<W: Write>(&self, f: &mut W) -> fmt::Result {
+        write_hms(f, &self.fix_time)?;
+        f.write_char(',')?;
+        write_duration_hms(f, &self.fix_duration)?;
+        f.write_char(',')?;
+        write_opt(f, &self.waypoint_id)
+    }
+}
+
+#[cfg(all(test, feature = "chrono"))]
 mod tests {
+    use chrono::{Duration, NaiveTime};
+
     use super::*;
     use crate::{parse::parse_nmea_sentence, Error};
 
-    fn run_parse_ztg(line: &str) -> Result<ZtgData, Error> {
+    fn run_parse_ztg(line: &str) -> Result<ZtgData, Error<'_>> {
         let s = parse_nmea_sentence(line).expect("ZTG sentence initial parse failed");
-        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(s.checksum, Some(s.calc_checksum()));
         parse_ztg(s)
     }
 
@@ -127,6 +160,68 @@ mod tests {
             run_parse_ztg("$GPZTG,,042359.17,*53").unwrap()
         );
     }
+
+    #[test]
+    fn test_encode_ztg_round_trip() {
+        for line in [
+            "$GPZTG,145832.12,042359.17,WPT*24",
+            "$GPZTG,,,*72",
+            "$GPZTG,,042359.17,*53",
+        ] {
+            let data = run_parse_ztg(line).unwrap();
+            let mut encoded = heapless::String::<82>::new();
+            data.write_sentence("GP", &mut encoded).unwrap();
+            assert_eq!(encoded, line);
+        }
+    }
+
+    #[test]
+    fn test_encode_ztg_invalid_duration() {
+        for fix_duration in [
+            Duration::milliseconds(-10),
+            Duration::hours(24),
+            Duration::hours(24) - Duration::milliseconds(4),
+        ] {
+            let data: ZtgData = ZtgData {
+                fix_time: None,
+                fix_duration: Some(fix_duration),
+                waypoint_id: None,
+            };
+            let mut encoded = heapless::String::<82>::new();
+            assert_eq!(
+                data.write_sentence("GP", &mut encoded),
+                Err(Error::InvalidFieldValue(SentenceType::ZTG))
+            );
+            assert!(encoded.is_empty());
+        }
+
+        let data: ZtgData = ZtgData {
+            fix_time: None,
+            fix_duration: Some(Duration::hours(24) - Duration::milliseconds(10)),
+            waypoint_id: None,
+        };
+        let mut encoded = heapless::String::<82>::new();
+        data.write_sentence("GP", &mut encoded).unwrap();
+        assert_eq!(encoded, "$GPZTG,,235959.99,*5D");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_ztg_round_trip() {
+        for line in [
+            "$GPZTG,145832.12,042359.17,WPT*24",
+            "$GPZTG,,,*72",
+            "$GPZTG,,042359.17,*53",
+        ] {
+            let data = run_parse_ztg(line).unwrap();
+
+            let serialized = serde_json::to_string(&data).unwrap();
+            let deserialized: ZtgData = serde_json::from_str(&serialized).unwrap();
+
+            assert_eq!(data, deserialized);
+        }
+    }
+
     #[test]
     fn test_parse_ztg_with_too_long_waypoint() {
         assert_eq!(
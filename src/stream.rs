@@ -0,0 +1,262 @@
+//! Framing of NMEA sentences from a byte stream.
+//!
+//! Serial ports and sockets deliver the data in arbitrary chunks which split
+//! the sentences at random places. [`SentenceBuffer`] reassembles them: push
+//! every chunk as it arrives and iterate over the complete sentences found so
+//! far, the incomplete remainder stays buffered until the next chunk.
+//!
+//! The buffer has a fixed capacity and does not allocate, so it's usable on
+//! `no_std` targets.
+
+use heapless::{String, Vec};
+
+use crate::Error;
+
+/// Maximum length of a NMEA 0183 sentence, including the `$` and the `\r\n`.
+pub const NMEA_LINE_MAX_LEN: usize = 82;
+
+/// Reassembles NMEA sentences from arbitrary byte chunks.
+///
+/// A sentence starts with `$` (or `!` for the encapsulation sentences, e.g.
+/// AIS) and ends with `\n` (optionally preceded by `\r`). Bytes before the
+/// start of a sentence are discarded. A line longer than `N` bytes (without
+/// its terminator) is discarded and yields an [`Error::SentenceLength`] with
+/// its full length once it ends, either with a `\n` or the start of the next
+/// sentence.
+///
+/// The yielded sentences are stripped of the `\r\n` terminator and can be
+/// passed directly to [`parse_str`](crate::parse_str).
+///
+/// # Examples
+///
+/// ```
+/// use nmea::stream::SentenceBuffer;
+///
+/// let mut buffer = SentenceBuffer::<82>::new();
+/// assert_eq!(buffer.push(b"garbage$GPHDT,274").count(), 0);
+///
+/// let mut sentences = buffer.push(b".07,T*03\r\n$GPHDT");
+/// assert_eq!(sentences.next().unwrap().unwrap(), "$GPHDT,274.07,T*03");
+/// assert!(sentences.next().is_none());
+/// ```
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Default)]
+pub struct SentenceBuffer<const N: usize = NMEA_LINE_MAX_LEN> {
+    line: Vec<u8, N>,
+    in_sentence: bool,
+    /// Length of the current line, also counting the bytes which didn't fit
+    received: usize,
+    /// A `\r` which is only part of the line if no `\n` follows
+    pending_cr: bool,
+}
+
+impl<const N: usize> SentenceBuffer<N> {
+    pub fn new() -> Self {
+        Self {
+            line: Vec::new(),
+            in_sentence: false,
+            received: 0,
+            pending_cr: false,
+        }
+    }
+
+    /// Pushes a chunk of bytes, returning an iterator over the sentences
+    /// completed by it.
+    ///
+    /// The whole chunk is consumed even if the iterator is dropped early,
+    /// the sentences which weren't iterated over are discarded in that case.
+    pub fn push<'a>(&'a mut self, bytes: &'a [u8]) -> Sentences<'a, N> {
+        Sentences {
+            buffer: self,
+            bytes: bytes.iter(),
+        }
+    }
+
+    /// Returns the number of buffered bytes of the incomplete sentence.
+    pub fn len(&self) -> usize {
+        self.line.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.line.is_empty()
+    }
+
+    /// Discards the buffered incomplete sentence.
+    pub fn clear(&mut self) {
+        self.line.clear();
+        self.in_sentence = false;
+        self.received = 0;
+        self.pending_cr = false;
+    }
+
+    fn push_byte(&mut self, byte: u8) {
+        self.received += 1;
+        // once full, the rest of the line is only counted
+        let _ = self.line.push(byte);
+    }
+
+    /// Feeds a single byte, returning the completed sentence or overflow error.
+    pub(crate) fn feed(&mut self, byte: u8) -> Option<Result<String<N>, Error<'static>>> {
+        match byte {
+            b'$' | b'!' => {
+                // a new sentence starts, an unterminated one is garbage but an
+                // overflowing one is still reported
+                let overflow = self.overflow();
+                self.clear();
+                self.in_sentence = true;
+                self.push_byte(byte);
+                overflow.map(Err)
+            }
+            b'\n' if self.in_sentence => {
+                let overflow = self.overflow();
+                let line = core::mem::take(&mut self.line);
+                self.clear();
+                Some(match overflow {
+                    Some(error) => Err(error),
+                    None => String::from_utf8(line).map_err(|_| Error::Utf8Decoding),
+                })
+            }
+            _ if !self.in_sentence => None,
+            b'\r' => {
+                if self.pending_cr {
+                    self.push_byte(b'\r');
+                }
+                self.pending_cr = true;
+                None
+            }
+            _ => {
+                if self.pending_cr {
+                    self.pending_cr = false;
+                    self.push_byte(b'\r');
+                }
+                self.push_byte(byte);
+                None
+            }
+        }
+    }
+
+    /// Returns the error for the current line if it didn't fit into the buffer.
+    fn overflow(&self) -> Option<Error<'static>> {
+        (self.received > N).then_some(Error::SentenceLength(self.received))
+    }
+}
+
+/// Iterator over the sentences completed by a chunk, see [`SentenceBuffer::push`].
+#[derive(Debug)]
+pub struct Sentences<'a, const N: usize> {
+    buffer: &'a mut SentenceBuffer<N>,
+    bytes: core::slice::Iter<'a, u8>,
+}
+
+impl<'a, const N: usize> Iterator for Sentences<'a, N> {
+    type Item = Result<String<N>, Error<'static>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for &byte in self.bytes.by_ref() {
+            if let Some(sentence) = self.buffer.feed(byte) {
+                return Some(sentence);
+            }
+        }
+        None
+    }
+}
+
+impl<'a, const N: usize> Drop for Sentences<'a, N> {
+    fn drop(&mut self) {
+        // keep the remainder of the chunk buffered
+        for _ in self.by_ref() {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GGA: &str = "$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76";
+    const VTG: &str = "$GPVTG,071.9,T,061.7,M,000.48,N,0000.88,K,A*10";
+
+    #[test]
+    fn test_split_chunks() {
+        let mut stream = std::vec::Vec::new();
+        for sentence in [GGA, VTG, GGA] {
+            stream.extend_from_slice(sentence.as_bytes());
+            stream.extend_from_slice(b"\r\n");
+        }
+
+        for chunk_size in [1, 5, 17, 80, stream.len()] {
+            let mut buffer = SentenceBuffer::<82>::new();
+            let mut sentences = std::vec::Vec::new();
+            for chunk in stream.chunks(chunk_size) {
+                for sentence in buffer.push(chunk) {
+                    sentences.push(sentence.unwrap());
+                }
+            }
+            assert_eq!(sentences, [GGA, VTG, GGA], "chunk size {chunk_size}");
+            assert!(buffer.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_garbage_and_terminators() {
+        let mut buffer = SentenceBuffer::<82>::new();
+        let sentences: std::vec::Vec<_> = buffer
+            .push(b"\x00\xffnoise\n$GPVTG,0\r\n!AIVDM,1,1,,A,1,0*00\n$GPHDT,27$GPHDT,274.07,T*03\r\n$GP")
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(
+            sentences,
+            ["$GPVTG,0", "!AIVDM,1,1,,A,1,0*00", "$GPHDT,274.07,T*03"]
+        );
+        assert_eq!(buffer.len(), 3);
+
+        buffer.clear();
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.push(b"T,274.07,T*03\r\n").count(), 0);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let mut buffer = SentenceBuffer::<16>::new();
+        let mut sentences = buffer.push(b"$GPHDT,274.07,T*03\r\n$GPHDT,1,T*00\r\n");
+        assert_eq!(sentences.next(), Some(Err(Error::SentenceLength(18))));
+        assert_eq!(sentences.next().unwrap().unwrap(), "$GPHDT,1,T*00");
+        assert_eq!(sentences.next(), None);
+        drop(sentences);
+
+        // the full length is counted, over chunks and up to the next sentence
+        let mut line = std::vec![b'$'];
+        line.resize(300, b'A');
+        assert_eq!(buffer.push(&line[..100]).count(), 0);
+        assert_eq!(buffer.push(&line[100..]).count(), 0);
+        let sentences: std::vec::Vec<_> = buffer.push(b"\r\r$GPHDT,1,T*00\r\n").collect();
+        assert_eq!(
+            sentences,
+            [
+                Err(Error::SentenceLength(301)),
+                Ok(String::try_from("$GPHDT,1,T*00").unwrap())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_full_line_with_crlf() {
+        let mut buffer = SentenceBuffer::<18>::new();
+        let sentences: std::vec::Vec<_> = buffer.push(b"$GPHDT,274.07,T*03\r\n").collect();
+        assert_eq!(
+            sentences,
+            [Ok(String::try_from("$GPHDT,274.07,T*03").unwrap())]
+        );
+    }
+
+    #[test]
+    fn test_dropped_iterator_keeps_remainder() {
+        let mut buffer = SentenceBuffer::<82>::new();
+        drop(buffer.push(b"$GPHDT,1,T*00\r\n$GPHDT,27"));
+        assert_eq!(buffer.len(), 9);
+        let sentences: std::vec::Vec<_> = buffer.push(b"4.07,T*03\r\n").collect();
+        assert_eq!(
+            sentences,
+            [Ok(String::try_from("$GPHDT,274.07,T*03").unwrap())]
+        );
+    }
+}
@@ -0,0 +1,159 @@
+//! NMEA 0183 TAG blocks (Transport Annotation and Grouping), the
+//! `\s:source,c:timestamp*hh\` prefix of the sentences routed over networks,
+//! e.g. the AIS feeds.
+
+use nom::error::{Error as NomError, ErrorKind};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{parse::xor_checksum, Error};
+
+/// The parsed fields of a TAG block, `None` for the ones it doesn't contain.
+///
+/// ```text
+/// \g:1-2-73874,n:157036,s:r003669945,c:1241544035*4A\!AIVDM,...
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TagBlock<'a> {
+    /// `s:` - Source station
+    pub source: Option<&'a str>,
+    /// `d:` - Destination station
+    pub destination: Option<&'a str>,
+    /// `c:` - UNIX timestamp in seconds
+    pub timestamp: Option<u64>,
+    /// `n:` - Line count
+    pub line_count: Option<u32>,
+    /// `r:` - Relative time
+    pub relative_time: Option<u32>,
+    /// `g:` - Sentence grouping
+    pub group: Option<TagGroup>,
+    /// `t:` - Text string
+    pub text: Option<&'a str>,
+}
+
+/// `g:` - Sentence grouping of a TAG block, `<sentence>-<total>-<id>`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TagGroup {
+    /// Number of the sentence in the group, starting with 1
+    pub sentence: u8,
+    /// Total number of sentences in the group
+    pub total: u8,
+    /// Identifier of the group
+    pub id: u32,
+}
+
+fn parsing_error(i: &str, kind: ErrorKind) -> Error<'_> {
+    Error::ParsingError(nom::Err::Error(NomError::new(i, kind)))
+}
+
+fn parse_field<T: core::str::FromStr>(value: &str) -> Result<T, Error<'_>> {
+    value
+        .parse()
+        .map_err(|_| parsing_error(value, ErrorKind::Digit))
+}
+
+fn parse_group(value: &str) -> Result<TagGroup, Error<'_>> {
+    let mut parts = value.splitn(3, '-');
+    let mut next = || parts.next().ok_or(parsing_error(value, ErrorKind::Char));
+
+    Ok(TagGroup {
+        sentence: parse_field(next()?)?,
+        total: parse_field(next()?)?,
+        id: parse_field(next()?)?,
+    })
+}
+
+/// Strips a leading TAG block off `i`, returning it parsed and the remaining input.
+///
+/// The checksum of the TAG block is mandatory and verified.
+pub(crate) fn parse_tag_block(i: &str) -> Result<(Option<TagBlock<'_>>, &str), Error<'_>> {
+    let Some(block) = i.strip_prefix('\\') else {
+        return Ok((None, i));
+    };
+    let (block, rest) = block
+        .split_once('\\')
+        .ok_or(parsing_error(block, ErrorKind::Char))?;
+    let (fields, found) = block
+        .rsplit_once('*')
+        .ok_or(parsing_error(block, ErrorKind::Char))?;
+    let found = u8::from_str_radix(found, 16)
+        .ok()
+        .filter(|_| found.len() == 2)
+        .ok_or(parsing_error(found, ErrorKind::HexDigit))?;
+
+    let calculated = xor_checksum(fields.as_bytes().iter());
+    if calculated != found {
+        return Err(Error::ChecksumMismatch { calculated, found });
+    }
+
+    let mut tag_block = TagBlock::default();
+    for field in fields.split(',') {
+        let (code, value) = field
+            .split_once(':')
+            .ok_or(parsing_error(field, ErrorKind::Char))?;
+        match code {
+            "s" => tag_block.source = Some(value),
+            "d" => tag_block.destination = Some(value),
+            "c" => tag_block.timestamp = Some(parse_field(value)?),
+            "n" => tag_block.line_count = Some(parse_field(value)?),
+            "r" => tag_block.relative_time = Some(parse_field(value)?),
+            "g" => tag_block.group = Some(parse_group(value)?),
+            "t" => tag_block.text = Some(value),
+            // unknown parameter codes are skipped
+            _ => {}
+        }
+    }
+
+    Ok((Some(tag_block), rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tag_block() {
+        let (tag_block, rest) =
+            parse_tag_block("\\g:1-2-73874,n:157036,s:r003669945,c:1241544035*4A\\!AIVDM,1")
+                .unwrap();
+        assert_eq!(rest, "!AIVDM,1");
+        assert_eq!(
+            tag_block,
+            Some(TagBlock {
+                source: Some("r003669945"),
+                timestamp: Some(1241544035),
+                line_count: Some(157036),
+                group: Some(TagGroup {
+                    sentence: 1,
+                    total: 2,
+                    id: 73874
+                }),
+                ..Default::default()
+            })
+        );
+
+        assert_eq!(parse_tag_block("$GPHDT,1"), Ok((None, "$GPHDT,1")));
+    }
+
+    #[test]
+    fn test_parse_tag_block_invalid() {
+        assert_eq!(
+            parse_tag_block("\\s:r003669945,c:1241544035*00\\$GPHDT,1"),
+            Err(Error::ChecksumMismatch {
+                calculated: 0x79,
+                found: 0x00
+            })
+        );
+        // missing checksum
+        assert!(parse_tag_block("\\s:r003669945\\$GPHDT,1").is_err());
+        // unterminated
+        assert!(parse_tag_block("\\s:r003669945*1F$GPHDT,1").is_err());
+        // invalid timestamp
+        assert!(parse_tag_block("\\c:12a*3B\\$GPHDT,1").is_err());
+    }
+}
@@ -0,0 +1,191 @@
+//! Full timestamps for sentences carrying only the time of day.
+//!
+//! GGA, GLL and GNS report the time of the fix but no date, the date comes
+//! with RMC or ZDA. [`TimeContext`] remembers the last seen date and combines
+//! it with the time of the following sentences.
+
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
+
+use crate::ParseResult;
+
+/// Remembers the date of the last RMC or ZDA sentence to turn the time of
+/// day of GGA, GLL and GNS sentences into a full [`NaiveDateTime`].
+///
+/// # Staleness
+///
+/// The date and the time come from different sentences and possibly from
+/// different fixes. Around midnight the remembered date may already be the
+/// one of the previous day, so a time more than 12 hours before the time of
+/// the remembered date is moved to the next day and a time more than 12
+/// hours after it to the previous day. This only holds while RMC or ZDA
+/// sentences keep arriving: once they stop the remembered date goes stale and
+/// timestamps more than 12 hours after the last date are off by a day.
+/// Use [`TimeContext::age`] to check how old the remembered date is.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use nmea::{parse_str, time_context::TimeContext};
+///
+/// let mut context = TimeContext::new();
+///
+/// # #[cfg(all(feature = "GGA", feature = "RMC"))]
+/// # {
+/// // features `GGA` and `RMC` should be enabled to parse these sentences.
+/// // the time of day alone can't be upgraded
+/// let gga = parse_str("$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76")
+///     .unwrap();
+/// assert_eq!(context.update(&gga), None);
+///
+/// let rmc = parse_str("$GPRMC,225446,A,4916.45,N,12311.12,W,000.5,054.7,191194,020.3,E*68")
+///     .unwrap();
+/// context.update(&rmc);
+///
+/// let gga = parse_str("$GPGGA,225447.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*7D")
+///     .unwrap();
+/// assert_eq!(
+///     context.update(&gga),
+///     NaiveDate::from_ymd_opt(1994, 11, 19).and_then(|d| d.and_hms_opt(22, 54, 47))
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TimeContext {
+    /// The last seen date and the time of the sentence it came with.
+    last: Option<NaiveDateTime>,
+}
+
+impl TimeContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The last seen date.
+    pub fn date(&self) -> Option<NaiveDate> {
+        self.last.map(|last| last.date())
+    }
+
+    /// Remembers the date of an RMC or ZDA sentence and returns the full
+    /// timestamp of the sentence.
+    ///
+    /// For GGA, GLL and GNS sentences the time of the fix is combined with
+    /// the last seen date, see [`TimeContext::fix_datetime`]. Returns `None`
+    /// for other sentences and for sentences without a time.
+    pub fn update(&mut self, result: &ParseResult) -> Option<NaiveDateTime> {
+        match result {
+            ParseResult::RMC(rmc) => Some(self.update_date(rmc.fix_date?, rmc.fix_time?)),
+            ParseResult::ZDA(zda) => Some(self.update_date(zda.utc_date()?, zda.utc_time?)),
+            ParseResult::GGA(gga) => self.fix_datetime(gga.fix_time?),
+            ParseResult::GLL(gll) => self.fix_datetime(gll.fix_time),
+            ParseResult::GNS(gns) => self.fix_datetime(gns.fix_time?),
+            _ => None,
+        }
+    }
+
+    /// Remembers a date together with the time of day it was reported with,
+    /// returning both combined.
+    pub fn update_date(&mut self, date: NaiveDate, time: NaiveTime) -> NaiveDateTime {
+        let date_time = NaiveDateTime::new(date, time);
+        self.last = Some(date_time);
+        date_time
+    }
+
+    /// Combines a time of day with the last seen date.
+    ///
+    /// Returns `None` until a date has been seen. See the
+    /// [staleness](TimeContext#staleness) notes for the handling of midnight.
+    pub fn fix_datetime(&self, time: NaiveTime) -> Option<NaiveDateTime> {
+        let last = self.last?;
+        let date_time = NaiveDateTime::new(last.date(), time);
+        let half_day = Duration::hours(12);
+        let difference = date_time - last;
+        if difference < -half_day {
+            date_time.checked_add_signed(Duration::days(1))
+        } else if difference > half_day {
+            date_time.checked_sub_signed(Duration::days(1))
+        } else {
+            Some(date_time)
+        }
+    }
+
+    /// Time elapsed between the last seen date and `date_time`, e.g. the
+    /// result of [`TimeContext::fix_datetime`].
+    pub fn age(&self, date_time: NaiveDateTime) -> Option<Duration> {
+        self.last.map(|last| date_time - last)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date_time(day: u32, hour: u32, min: u32, sec: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 3, day)
+            .and_then(|date| date.and_hms_opt(hour, min, sec))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_fix_datetime_without_date() {
+        let context = TimeContext::new();
+        assert_eq!(context.date(), None);
+        assert_eq!(
+            context.fix_datetime(NaiveTime::from_hms_opt(12, 0, 0).unwrap()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_fix_datetime_around_midnight() {
+        let mut context = TimeContext::new();
+        context.update_date(
+            NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+            NaiveTime::from_hms_opt(23, 59, 59).unwrap(),
+        );
+
+        // a GGA of the next day before the next RMC
+        let next_day = context.fix_datetime(NaiveTime::from_hms_opt(0, 0, 1).unwrap());
+        assert_eq!(next_day, Some(date_time(2, 0, 0, 1)));
+        assert_eq!(context.age(next_day.unwrap()), Some(Duration::seconds(2)));
+
+        context.update_date(
+            NaiveDate::from_ymd_opt(2024, 3, 2).unwrap(),
+            NaiveTime::from_hms_opt(0, 0, 1).unwrap(),
+        );
+        assert_eq!(context.date(), NaiveDate::from_ymd_opt(2024, 3, 2));
+
+        // a late GGA of the previous day
+        assert_eq!(
+            context.fix_datetime(NaiveTime::from_hms_opt(23, 59, 59).unwrap()),
+            Some(date_time(1, 23, 59, 59))
+        );
+        assert_eq!(
+            context.fix_datetime(NaiveTime::from_hms_opt(11, 0, 0).unwrap()),
+            Some(date_time(2, 11, 0, 0))
+        );
+    }
+
+    #[cfg(all(feature = "ZDA", feature = "GLL", feature = "HDT"))]
+    #[test]
+    fn test_update_from_sentences() {
+        let mut context = TimeContext::new();
+        let zda = crate::parse_str("$GPZDA,160012.71,11,03,2004,-1,00*7D").unwrap();
+        assert_eq!(
+            context.update(&zda),
+            NaiveDate::from_ymd_opt(2004, 3, 11).and_then(|d| d.and_hms_milli_opt(16, 0, 12, 710))
+        );
+
+        let gll =
+            crate::parse_str("$GPGLL,5107.0013414,N,11402.3279144,W,205412.00,A,A*73").unwrap();
+        assert_eq!(
+            context.update(&gll),
+            NaiveDate::from_ymd_opt(2004, 3, 11).and_then(|d| d.and_hms_opt(20, 54, 12))
+        );
+        // the date isn't updated by GLL
+        assert_eq!(context.date(), NaiveDate::from_ymd_opt(2004, 3, 11));
+
+        let hdt = crate::parse_str("$GPHDT,274.07,T*03").unwrap();
+        assert_eq!(context.update(&hdt), None);
+    }
+}
@@ -0,0 +1,278 @@
+//! Accumulation and simplification of position tracks.
+//!
+//! Logging every fix of a receiver quickly bloats the storage, the
+//! [`Trajectory`] can be simplified with the Douglas-Peucker algorithm
+//! before it's stored, keeping only the positions needed to describe the
+//! track within a given tolerance. With the `kml` feature the track can be
+//! exported as a KML document.
+
+#[cfg(feature = "kml")]
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::navigation::Position;
+
+/// A track of positions, e.g. the ones of consecutive GGA or RMC sentences.
+///
+/// # Examples
+///
+/// ```
+/// use nmea::{navigation::Position, trajectory::Trajectory};
+///
+/// let mut trajectory = Trajectory::new();
+/// trajectory.push(Position::new(0.0, 0.0));
+/// trajectory.push(Position::new(0.00001, 0.5));
+/// trajectory.push(Position::new(0.0, 1.0));
+///
+/// assert_eq!(trajectory.simplify(10.0).len(), 2);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Trajectory {
+    positions: Vec<Position>,
+    /// Altitude in meters of each position, if known
+    altitudes: Vec<Option<f32>>,
+}
+
+impl Trajectory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a position to the end of the track.
+    pub fn push(&mut self, position: Position) {
+        self.positions.push(position);
+        self.altitudes.push(None);
+    }
+
+    /// Appends a position with its altitude in meters, e.g. the one of a GGA sentence.
+    pub fn push_with_altitude(&mut self, position: Position, altitude: Option<f32>) {
+        self.positions.push(position);
+        self.altitudes.push(altitude);
+    }
+
+    pub fn positions(&self) -> &[Position] {
+        &self.positions
+    }
+
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// Exports the track as a minimal KML document with a single `<LineString>`.
+    ///
+    /// The coordinates are written in the KML `longitude,latitude,altitude`
+    /// order with a precision of about a centimeter, unknown altitudes are
+    /// written as `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nmea::{navigation::Position, trajectory::Trajectory};
+    ///
+    /// let mut trajectory = Trajectory::new();
+    /// trajectory.push_with_altitude(Position::new(53.36, -6.5), Some(61.7));
+    ///
+    /// assert!(trajectory.to_kml().contains("<coordinates>-6.5000000,53.3600000,61.7</coordinates>"));
+    /// ```
+    #[cfg(feature = "kml")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "kml")))]
+    pub fn to_kml(&self) -> String {
+        use core::fmt::Write;
+
+        let mut kml = String::from(concat!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+            "\n",
+            r#"<kml xmlns="http://www.opengis.net/kml/2.2">"#,
+            "\n<Document>\n<Placemark>\n<LineString>\n<coordinates>"
+        ));
+        for (index, (position, altitude)) in self.positions.iter().zip(&self.altitudes).enumerate()
+        {
+            if index > 0 {
+                kml.push(' ');
+            }
+            // writing into a `String` never fails
+            let _ = write!(
+                kml,
+                "{:.7},{:.7},{:.1}",
+                position.longitude,
+                position.latitude,
+                altitude.unwrap_or(0.0)
+            );
+        }
+        kml.push_str("</coordinates>\n</LineString>\n</Placemark>\n</Document>\n</kml>\n");
+
+        kml
+    }
+
+    /// Simplifies the track with the Douglas-Peucker algorithm.
+    ///
+    /// Returns the positions whose removal would move the track by more than
+    /// `epsilon_meters`. The first and the last positions are always kept.
+    pub fn simplify(&self, epsilon_meters: f32) -> Vec<Position> {
+        let len = self.positions.len();
+        if len < 3 {
+            return self.positions.clone();
+        }
+
+        let epsilon = f64::from(epsilon_meters);
+        let mut keep = alloc::vec![false; len];
+        keep[0] = true;
+        keep[len - 1] = true;
+
+        // ranges of (start, end) indices which still have to be simplified
+        let mut ranges = alloc::vec![(0, len - 1)];
+        while let Some((start, end)) = ranges.pop() {
+            let (farthest, distance) = (start + 1..end)
+                .map(|index| {
+                    let distance = segment_distance(
+                        &self.positions[index],
+                        &self.positions[start],
+                        &self.positions[end],
+                    );
+                    (index, distance)
+                })
+                .fold((start, 0.0), |farthest, current| {
+                    if current.1 > farthest.1 {
+                        current
+                    } else {
+                        farthest
+                    }
+                });
+
+            if distance > epsilon {
+                keep[farthest] = true;
+                ranges.push((start, farthest));
+                ranges.push((farthest, end));
+            }
+        }
+
+        self.positions
+            .iter()
+            .zip(keep)
+            .filter_map(|(position, keep)| keep.then_some(*position))
+            .collect()
+    }
+}
+
+impl Extend<Position> for Trajectory {
+    fn extend<T: IntoIterator<Item = Position>>(&mut self, iter: T) {
+        for position in iter {
+            self.push(position);
+        }
+    }
+}
+
+impl FromIterator<Position> for Trajectory {
+    fn from_iter<T: IntoIterator<Item = Position>>(iter: T) -> Self {
+        let mut trajectory = Self::new();
+        trajectory.extend(iter);
+        trajectory
+    }
+}
+
+/// Distance in meters from `position` to the great-circle segment going from
+/// `start` to `end`.
+fn segment_distance(position: &Position, start: &Position, end: &Position) -> f64 {
+    let along_track = position.along_track_distance(start, end);
+    if along_track <= 0.0 {
+        position.distance_to(start)
+    } else if along_track >= start.distance_to(end) {
+        position.distance_to(end)
+    } else {
+        position.cross_track_distance(start, end).abs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse::parse_nmea_sentence, sentences::parse_rmc};
+
+    #[test]
+    fn test_simplify_noisy_straight_line() {
+        // 0.0001 degree of latitude is ~11 meters
+        let noisy_midpoint = Position::new(0.0001, 0.005);
+        let trajectory: Trajectory = [
+            Position::new(0.0, 0.0),
+            noisy_midpoint,
+            Position::new(0.0, 0.01),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            trajectory.simplify(15.0),
+            [Position::new(0.0, 0.0), Position::new(0.0, 0.01)]
+        );
+        assert_eq!(trajectory.simplify(5.0), trajectory.positions());
+    }
+
+    #[test]
+    fn test_simplify_keeps_corners() {
+        let mut trajectory = Trajectory::new();
+        trajectory.extend([
+            Position::new(0.0, 0.0),
+            Position::new(0.0, 0.005),
+            Position::new(0.0, 0.01),
+            Position::new(0.005, 0.01),
+            Position::new(0.01, 0.01),
+        ]);
+
+        assert_eq!(
+            trajectory.simplify(1.0),
+            [
+                Position::new(0.0, 0.0),
+                Position::new(0.0, 0.01),
+                Position::new(0.01, 0.01),
+            ]
+        );
+    }
+
+    #[cfg(feature = "kml")]
+    #[test]
+    fn test_to_kml() {
+        use crate::sentences::parse_gga;
+
+        let mut trajectory = Trajectory::new();
+        let s = parse_nmea_sentence(
+            "$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76",
+        )
+        .unwrap();
+        let gga = parse_gga(s).unwrap();
+        trajectory.push_with_altitude(gga.position().unwrap(), gga.altitude);
+        trajectory.push(Position::new(53.5, -6.25));
+
+        assert_eq!(
+            trajectory.to_kml(),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<kml xmlns="http://www.opengis.net/kml/2.2">
+<Document>
+<Placemark>
+<LineString>
+<coordinates>-6.5056200,53.3613367,61.7 -6.2500000,53.5000000,0.0</coordinates>
+</LineString>
+</Placemark>
+</Document>
+</kml>
+"#
+        );
+    }
+
+    #[test]
+    fn test_simplify_short_tracks() {
+        let mut trajectory = Trajectory::new();
+        assert!(trajectory.simplify(1.0).is_empty());
+
+        let s = parse_nmea_sentence(
+            "$GPRMC,225446.33,A,4916.45,N,12311.12,W,000.5,054.7,191194,020.3,E,A*2B",
+        )
+        .unwrap();
+        trajectory.push(parse_rmc(s).unwrap().position().unwrap());
+        assert_eq!(trajectory.len(), 1);
+        assert_eq!(trajectory.simplify(1.0), trajectory.positions());
+    }
+}
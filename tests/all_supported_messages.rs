@@ -7,46 +7,132 @@ fn test_all_supported_messages() {
     let sentences = [
         // AAM
         (SentenceType::AAM, "$GPAAM,A,A,0.10,N,WPTNME*32"),
+        // ACK
+        (SentenceType::ACK, "$IIACK,001*54"),
         // ALM
         (SentenceType::ALM, "$GPALM,1,1,15,1159,00,441D,4E,16BE,FD5E,A10C9F,4A2DA4,686E81,58CBE1,0A4,001*77"),
+        // ALR
+        (SentenceType::ALR, "$IIALR,020000,001,A,V,Bilge pump alarm1*48"),
         // APA
         (SentenceType::APA, "$GPAPA,A,A,0.10,R,N,V,V,011,M,DEST,011,M*42"),
         // BWC
         (SentenceType::BWC, "$GPBWC,220516,5130.02,N,00046.34,W,213.8,T,218.0,M,0004.6,N,EGLM*21"),
+        // BWR
+        (SentenceType::BWR, "$GPBWR,220516,5130.02,N,00046.34,W,213.8,T,218.0,M,0004.6,N,EGLM*30"),
         // BWW
         (SentenceType::BWW, "$GPBWW,213.8,T,218.0,M,TOWPT,FROMWPT*42"),
+        // CUR
+        (SentenceType::CUR, "$VWCUR,A,1,2,5.5,123.4,T,1.2,4.0,45.0,T,W*60"),
+        // DBS
+        (SentenceType::DBS, "$SDDBS,1330.5,f,0405.5,M,0221.6,F*36"),
+        // DSC
+        (SentenceType::DSC, "$CDDSC,20,3380400790,00,21,26,1394807410,2231,,,B,E*78"),
+        // DSE
+        (SentenceType::DSE, "$CDDSE,1,1,A,3380400790,00,46504437*15"),
+        // EPV
+        (SentenceType::EPV, "$ECEPV,C,AI,211239680,102,10*24"),
+        // FSI
+        (SentenceType::FSI, "$GPFSI,021950,025460,d,5*10"),
         // GGA
         (SentenceType::GGA, "$GPGGA,133605.0,5521.75946,N,03731.93769,E,0,00,,,M,,M,,*4F"),
+        // GLC
+        (SentenceType::GLC, "$GPGLC,9960,13000.34,A,27870.12,A,42100.58,B,59120.00,C,,,,*7D"),
         // GLL
         (SentenceType::GLL, "$GPGLL,5107.0013414,N,11402.3279144,W,205412.00,A,A*73"),
+        // GMP
+        (SentenceType::GMP, "$GNGMP,123519.00,UTM,33U,5457812.345,402567.890,AA,08,1.2,105.3,47.1,2.5,0138*63"),
         // GNS
         (SentenceType::GNS, "$GPGNS,224749.00,3333.4268304,N,11153.3538273,W,D,19,0.6,406.110,-26.294,6.0,0138,S,*46"),
+        // GRS
+        (SentenceType::GRS, "$GPGRS,220320.0,0,-0.8,-0.2,-0.1,-0.2,0.8,0.6,,,,,,*79"),
         // GSA
         (SentenceType::GSA, "$GPGSA,A,3,23,31,22,16,03,07,,,,,,,1.8,1.1,1.4*3E"),
         // GST
         (SentenceType::GST, "$GPGST,182141.000,15.5,15.3,7.2,21.8,0.9,0.5,0.8*54"),
         // GSV
         (SentenceType::GSV, "$GPGSV,3,1,12,01,49,196,41,03,71,278,32,06,02,323,27,11,21,196,39*72"),
+        // GTD
+        (SentenceType::GTD, "$GPGTD,15234.5,42123.8,57123.9,,*43"),
+        // HBT
+        (SentenceType::HBT, "$GPHBT,30.0,A,7*0E"),
+        // HDM
+        (SentenceType::HDM, "$HCHDM,172.5,M*28"),
         // HDT
         (SentenceType::HDT, "$GPHDT,274.07,T*03"),
+        // HSC
+        (SentenceType::HSC, "$FTHSC,40.12,T,39.11,M*5E"),
+        // HTC
+        (SentenceType::HTC, "$IIHTC,V,10.0,L,H,R,35.0,10.0,0.5,20.0,275.0,0.1,270.0,T*22"),
+        // HTD
+        (SentenceType::HTD, "$IIHTD,V,10.0,L,H,R,35.0,10.0,0.5,20.0,275.0,0.1,270.0,T,A,A,V,274.5*59"),
         // MDA
-        (SentenceType::MDA, "$WIMWV,041.1,R,01.0,N,A*16"),
+        (SentenceType::MDA, "$WIMDA,29.7544,I,1.0076,B,35.5,C,,,42.1,,20.6,C,116.4,T,107.7,M,1.2,N,0.6,M*66"),
+        // MHU
+        (SentenceType::MHU, "$IIMHU,42.1,30.6,20.6,C*0B"),
+        // MMB
+        (SentenceType::MMB, "$IIMMB,29.9870,I,1.0154,B*75"),
+        // MTA
+        (SentenceType::MTA, "$IIMTA,13.1,C*06"),
+        // MWD
+        (SentenceType::MWD, "$WIMWD,184.0,T,190.5,M,12.4,N,6.4,M*6F"),
+        // MWH
+        (SentenceType::MWH, "$WIMWH,6.6,f,2.0,M*65"),
         // MWV
-        (SentenceType::MWV, "$WIMDA,29.7544,I,1.0076,B,35.5,C,,,42.1,,20.6,C,116.4,T,107.7,M,1.2,N,0.6,M*66"),
+        (SentenceType::MWV, "$WIMWV,041.1,R,01.0,N,A*16"),
+        // NRX
+        (SentenceType::NRX, "$CRNRX,007,001,00,IE69,1,135600,27,06,2001,241,3,A,==========================*09"),
+        // OSD
+        (SentenceType::OSD, "$RAOSD,35.1,A,36.0,P,10.2,P,15.3,0.1,N*41"),
+        // RMA
+        (SentenceType::RMA, "$GPRMA,A,4917.24,S,12309.57,W,1000.0,2000.0,123.4,321.0,10,E,A*38"),
+        // RMB
+        (SentenceType::RMB, "$GPRMB,A,0.66,L,003,004,4917.24,N,12309.57,W,001.3,052.5,000.5,V*20"),
         // RMC
         (SentenceType::RMC, "$GPRMC,225446.33,A,4916.45,N,12311.12,W,000.5,054.7,191194,020.3,E,A*2B"),
+        // RMM
+        (SentenceType::RMM, "$GPRMM,WGS 84*06"),
         // RMZ
         (SentenceType::RMZ, "$PGRMZ,2282,f,3*21"),
+        // RPM
+        (SentenceType::RPM, "$IIRPM,S,1,31,100,A*73"),
+        // SFI
+        (SentenceType::SFI, "$RASFI,2,1,021950,d,025460,m,041250,s,065215,w,084142,q,124500,|*48"),
+        // STN
+        (SentenceType::STN, "$IISTN,03*66"),
+        // THS
+        (SentenceType::THS, "$GPTHS,338.01,A*0E"),
+        // TLL
+        (SentenceType::TLL, "$RATLL,01,3646.54,N,00235.37,W,TGT01,124545.00,T,*7A"),
+        // TRF
+        (SentenceType::TRF, "$GPTRF,135315.00,030804,4916.45,N,12311.12,W,32.5,4,22,1.2,013,A*27"),
         // TTM
         (SentenceType::TTM, "$RATTM,01,0.2,190.8,T,12.1,109.7,T,0.1,0.5,N,TGT01,T,,100021.00,A*79"),
         // TXT
         (SentenceType::TXT, "$GNTXT,01,01,02,u-blox AG - www.u-blox.com*4E"),
+        // VBW
+        (SentenceType::VBW, "$IIVBW,12.3,-0.07,A,11.78,0.12,A,0.15,A,0.20,V*44"),
+        // VDM
+        (SentenceType::VDM, "!AIVDM,1,1,,A,13aEOK?P00PD2wVMdLDRhgvL289?,0*26"),
+        // VDO
+        (SentenceType::VDO, "!AIVDO,1,1,,,B5NJ;PP005l4ot5Isbl03wsUkP06,0*35"),
+        // VDR
+        (SentenceType::VDR, "$IIVDR,10.1,T,12.3,M,1.2,N*3A"),
         // VHW
         (SentenceType::VHW, "$GPVHW,100.5,T,105.5,M,10.5,N,19.4,K*4F"),
+        // VPW
+        (SentenceType::VPW, "$IIVPW,4.5,N,2.3,M*52"),
         // VTG
         (SentenceType::VTG, "$GPVTG,360.0,T,348.7,M,000.0,N,000.0,K*43"),
+        // VWR
+        (SentenceType::VWR, "$IIVWR,045.0,L,12.6,N,6.5,M,23.3,K*52"),
+        // VWT
+        (SentenceType::VWT, "$IIVWT,030.5,R,10.1,N,5.2,M,18.7,K*40"),
+        // WCV
+        (SentenceType::WCV, "$GPWCV,2.5,N,DEST*18"),
         // WNC
         (SentenceType::WNC, "$GPWNC,200.00,N,370.40,K,Dest,Origin*58"),
+        // XDR
+        (SentenceType::XDR, "$IIXDR,C,19.52,C,TempAir,P,1.02481,B,Barometer*7E"),
         // ZDA
         (SentenceType::ZDA, "$GPZDA,160012.71,11,03,2004,-1,00*7D"),
         // ZFO
@@ -64,6 +150,15 @@ fn test_all_supported_messages() {
             .map(|sentence| parse_str(sentence).map_err(|result| (sentence, result)))
             .collect::<Vec<_>>();
 
+        for parse_result in parse_results.iter().flatten() {
+            let sentence_type = SentenceType::from(parse_result);
+            assert!(
+                !sentence_type.data_struct_name().is_empty(),
+                "{} should have a data struct name",
+                sentence_type
+            );
+        }
+
         let errors = parse_results
             .into_iter()
             .filter_map(|result| result.err())
@@ -0,0 +1,101 @@
+//! Formats values through `defmt` with a logger capturing the encoded bytes.
+
+use std::sync::Mutex;
+
+use nmea::{
+    compact::Compact,
+    sentences::{ApaData, SteerDirection},
+    Error, SentenceType,
+};
+
+static ENCODED: Mutex<Vec<u8>> = Mutex::new(Vec::new());
+
+defmt::timestamp!("");
+
+#[defmt::global_logger]
+struct Logger;
+
+unsafe impl defmt::Logger for Logger {
+    fn acquire() {}
+
+    unsafe fn flush() {}
+
+    unsafe fn release() {}
+
+    unsafe fn write(bytes: &[u8]) {
+        ENCODED.lock().unwrap().extend_from_slice(bytes);
+    }
+}
+
+/// Encodes `value` with `defmt`, returning the written bytes.
+fn encode<T: defmt::Format>(value: &T) -> Vec<u8> {
+    // the logger is global, the tests must not interleave
+    static LOCK: Mutex<()> = Mutex::new(());
+    let _guard = LOCK.lock().unwrap();
+
+    ENCODED.lock().unwrap().clear();
+    defmt::println!("{}", value);
+    core::mem::take(&mut *ENCODED.lock().unwrap())
+}
+
+#[test]
+fn test_error_defmt_format() {
+    let wrong_header = encode(&Error::WrongSentenceHeader {
+        expected: SentenceType::GGA,
+        found: SentenceType::RMC,
+    });
+    assert!(!wrong_header.is_empty());
+
+    // the sentence types are part of the encoding
+    let other_header = encode(&Error::WrongSentenceHeader {
+        expected: SentenceType::GGA,
+        found: SentenceType::GLL,
+    });
+    assert_eq!(wrong_header.len(), other_header.len());
+    assert_ne!(wrong_header, other_header);
+
+    let parameter_length = encode(&Error::ParameterLength {
+        max_length: 64,
+        parameter_length: 70,
+    });
+    assert_ne!(wrong_header, parameter_length);
+    assert_eq!(
+        parameter_length,
+        encode(&Error::ParameterLength {
+            max_length: 64,
+            parameter_length: 70,
+        })
+    );
+}
+
+#[test]
+fn test_compact_apa_defmt_format() {
+    // the frame of `Compact(&None)` without its leading "{}" string index
+    let absent = encode(&Compact(&None::<f32>))[2..].to_vec();
+    assert_eq!(absent, encode(&Compact(&None::<SteerDirection>))[2..]);
+
+    let mut apa: ApaData = ApaData {
+        status_warning: None,
+        status_cycle_warning: None,
+        cross_track_error_magnitude: None,
+        steer_direction: None,
+        cross_track_units: None,
+        status_arrived: None,
+        status_passed: None,
+        bearing_origin_destination: None,
+        magnetic_true: None,
+        waypoint_id: None,
+    };
+
+    // the "{}" and APA format string indices, every field as `-` and the end
+    // of the nested format
+    let frame = encode(&apa);
+    assert_eq!(frame.len(), 6 + 10 * absent.len() + 2);
+    assert_eq!(frame[6..frame.len() - 2], absent.repeat(10));
+
+    apa.steer_direction = Some(SteerDirection::Left);
+    let frame = encode(&apa);
+    assert_eq!(frame[6..6 + 3 * absent.len()], absent.repeat(3));
+    assert_ne!(frame[6 + 3 * absent.len()..][..absent.len()], absent);
+    assert!(frame.ends_with(&[absent.repeat(6), vec![0, 0]].concat()));
+}
@@ -136,6 +136,55 @@ fn test_gsv() {
     );
 }
 
+#[test]
+fn test_gsv_snr_statistics() {
+    let mut nmea = Nmea::default();
+    assert_eq!(nmea.average_snr(), None);
+    assert_eq!(nmea.median_snr(), None);
+    assert_eq!(nmea.satellites_above(0), 0);
+
+    // no satellite reports an SNR
+    nmea.parse("$GPGSV,1,1,02,36,,,,16,09,020,*42").unwrap();
+    assert_eq!(nmea.satellites().len(), 2);
+    assert_eq!(nmea.average_snr(), None);
+    assert_eq!(nmea.median_snr(), None);
+    assert_eq!(nmea.satellites_above(0), 0);
+
+    nmea.parse("$GPGSV,3,1,11,10,63,137,17,07,61,098,15,05,59,290,20,08,54,157,30*70")
+        .unwrap();
+    nmea.parse("$GPGSV,3,2,11,02,39,223,19,13,28,070,17,26,23,252,,04,14,186,14*79")
+        .unwrap();
+    nmea.parse("$GPGSV,3,3,11,29,09,301,24,16,09,020,,36,,,*76")
+        .unwrap();
+
+    // 11 satellites, 3 of them without SNR
+    assert_eq!(nmea.satellites().len(), 11);
+    assert_relative_eq!(nmea.average_snr().unwrap(), 156. / 8.);
+    assert_relative_eq!(nmea.median_snr().unwrap(), (17. + 19.) / 2.);
+    assert_eq!(nmea.satellites_above(0), 8);
+    assert_eq!(nmea.satellites_above(20), 3);
+    assert_eq!(nmea.satellites_above(31), 0);
+}
+
+#[test]
+fn test_gsv_geometry_spread() {
+    let mut nmea = Nmea::default();
+    assert_eq!(nmea.geometry_spread(0.), None);
+
+    // four satellites evenly spread around the horizon
+    nmea.parse("$GPGSV,1,1,04,01,45,000,30,02,45,090,30,03,45,180,30,04,45,270,30*7C")
+        .unwrap();
+    assert_relative_eq!(nmea.geometry_spread(10.).unwrap(), 0.75);
+    assert_eq!(nmea.geometry_spread(50.), None);
+
+    // three satellites clustered in the north-east, one low in the south
+    let mut nmea = Nmea::default();
+    nmea.parse("$GPGSV,1,1,04,01,45,010,30,02,45,020,30,03,45,030,30,04,05,200,30*7F")
+        .unwrap();
+    assert_relative_eq!(nmea.geometry_spread(10.).unwrap(), 20. / 360.);
+    assert_relative_eq!(nmea.geometry_spread(0.).unwrap(), 190. / 360.);
+}
+
 #[test]
 fn test_gsv_real_data() {
     let mut nmea = Nmea::default();
@@ -471,3 +520,383 @@ fn test_gll() {
     assert_eq!(54, nmea.fix_timestamp().unwrap().minute());
     assert_eq!(44, nmea.fix_timestamp().unwrap().second());
 }
+
+#[test]
+fn test_parse_sentence() {
+    let stream = [
+        "$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76",
+        "$GPDTM,W84,,0.0,N,0.0,E,0.0,W84*6F",
+        "$GPVTG,071.9,T,061.7,M,000.48,N,0000.88,K,A*10",
+    ];
+    let parsed: Vec<ParsedSentence> = stream
+        .iter()
+        .map(|line| parse_sentence(line).unwrap())
+        .collect();
+
+    assert!(matches!(parsed[0], ParsedSentence::GGA(_)));
+    assert_eq!(parsed[1], ParsedSentence::Unsupported(SentenceType::DTM));
+    assert!(matches!(parsed[2], ParsedSentence::VTG(_)));
+
+    assert!(parse_sentence("$GPDTM,W84,,0.0,N,0.0,E,0.0,W84*00").is_err());
+}
+
+#[test]
+fn test_rechecksum() {
+    for valid in [
+        "$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76",
+        "$GPRMC,092750.000,A,5321.6802,N,00630.3372,W,0.02,31.66,280511,,,A*43",
+        "$GPVTG,071.9,T,061.7,M,000.48,N,0000.88,K,A*10",
+        "$GPGSV,3,1,11,10,63,137,17,07,61,098,15,05,59,290,20,08,54,157,30*70",
+    ] {
+        assert!(parse_nmea_sentence(valid).unwrap().rechecksum(), "{valid}");
+    }
+
+    // latitude digit changed, checksum left as is
+    let corrupted = parse_nmea_sentence(
+        "$GPGGA,092750.000,5331.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76",
+    )
+    .unwrap();
+    assert!(!corrupted.rechecksum());
+}
+
+#[test]
+fn test_parse_nmea_sentence_checked() {
+    let line = "$GPVTG,071.9,T,061.7,M,000.48,N,0000.88,K,A*10";
+    assert_eq!(
+        parse_nmea_sentence_checked(line),
+        Ok(parse_nmea_sentence(line).unwrap())
+    );
+
+    let corrupted = "$GPVTG,071.9,T,061.7,M,000.49,N,0000.88,K,A*10";
+    assert!(parse_nmea_sentence(corrupted).is_ok());
+    assert!(matches!(
+        parse_nmea_sentence_checked(corrupted),
+        Err(Error::ChecksumMismatch { found: 0x10, .. })
+    ));
+}
+
+#[test]
+fn test_parse_proprietary_sentence() {
+    let line = "$PUBX,04,073731.00,091202,113851.00,1196,15D,1930035,-2660.664,43*71";
+    assert_eq!(parse_nmea_sentence(line), Err(Error::Proprietary("UBX")));
+    assert_eq!(parse_sentence(line), Err(Error::Proprietary("UBX")));
+
+    let sentence = parse_proprietary_sentence(line).unwrap();
+    assert_eq!(sentence.manufacturer.as_str(), "UBX");
+    assert!(sentence.data.starts_with(",04,073731.00,"));
+    assert_eq!(sentence.checksum, Some(0x71));
+    assert!(sentence.rechecksum());
+
+    let sentence = parse_proprietary_sentence("$PGRMZ,2282,f,3*21").unwrap();
+    assert_eq!(sentence.manufacturer.as_str(), "GRM");
+    assert_eq!(sentence.data, "Z,2282,f,3");
+    assert!(sentence.rechecksum());
+
+    assert!(parse_proprietary_sentence("$GPHDT,274.07,T*03").is_err());
+}
+
+#[test]
+fn test_parse_tag_block() {
+    let sentence =
+        parse_nmea_sentence("\\s:r003669945,c:1241544035*79\\$GPHDT,274.07,T*03").unwrap();
+    assert_eq!(sentence.message_id, SentenceType::HDT);
+    assert!(sentence.rechecksum());
+    assert_eq!(
+        sentence.tag_block,
+        Some(TagBlock {
+            source: Some("r003669945"),
+            timestamp: Some(1241544035),
+            ..Default::default()
+        })
+    );
+
+    assert_eq!(
+        parse_nmea_sentence("$GPHDT,274.07,T*03").unwrap().tag_block,
+        None
+    );
+    assert!(matches!(
+        parse_str("\\s:r003669945,c:1241544035*79\\$GPHDT,274.07,T*03"),
+        Ok(ParseResult::HDT(_))
+    ));
+    // the TAG block checksum is verified on its own
+    assert!(matches!(
+        parse_nmea_sentence("\\s:r003669945,c:1241544035*00\\$GPHDT,274.07,T*03"),
+        Err(Error::ChecksumMismatch { found: 0x00, .. })
+    ));
+}
+
+#[test]
+fn test_parse_lenient_all() {
+    use nmea::sentences::{TtmStatus, TtmTypeOfAcquisition};
+
+    // corrupt checksum and a malformed altitude
+    let result = parse_lenient_all(
+        "$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,6x.7,M,55.2,M,,*00\r\n",
+    );
+    let Some(ParseResult::GGA(gga)) = result else {
+        panic!("Expected GGA, got {:?}", result);
+    };
+    assert_eq!(gga.fix_time, NaiveTime::from_hms_milli_opt(9, 27, 50, 0));
+    assert_relative_eq!(gga.latitude.unwrap(), 53. + 21.6802 / 60.);
+    assert_relative_eq!(gga.longitude.unwrap(), -(6. + 30.3372 / 60.));
+    assert_eq!(gga.fix_satellites, Some(8));
+    assert_eq!(gga.altitude, None);
+    assert_relative_eq!(gga.geoid_separation.unwrap(), 55.2);
+
+    // missing checksum and several malformed fields
+    let result =
+        parse_lenient_all("$GPRMC,092750.000,A,5321.6802,N,00630.3372,W,abc,31.66,280511,1x.2,E,A");
+    let Some(ParseResult::RMC(rmc)) = result else {
+        panic!("Expected RMC, got {:?}", result);
+    };
+    assert_eq!(rmc.speed_over_ground, None);
+    assert_relative_eq!(rmc.true_course.unwrap(), 31.66);
+    assert_eq!(rmc.magnetic_variation, None);
+    assert!(rmc.fix_date.is_some());
+
+    // malformed required status field
+    assert_eq!(
+        parse_lenient_all("$GPRMC,092750.000,X,5321.6802,N,00630.3372,W,0.02,31.66,280511,,,A"),
+        None
+    );
+
+    // malformed seconds of the time, parsed by a sub-parser of the field
+    let result =
+        parse_lenient_all("$GPGGA,0927xx.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76");
+    let Some(ParseResult::GGA(gga)) = result else {
+        panic!("Expected GGA, got {:?}", result);
+    };
+    assert_eq!(gga.fix_time, None);
+    assert_eq!(gga.fix_satellites, Some(8));
+    assert_relative_eq!(gga.altitude.unwrap(), 61.7);
+
+    // invalid unit indicator and a target name longer than supported
+    let result = parse_lenient_all(
+        "$RATTM,01,1.2,45.0,T,5.0,90.0,T,0.5,2.0,X,TARGET_NAME_LONGER_THAN_32_CHARSX,T,,092750,A",
+    );
+    let Some(ParseResult::TTM(ttm)) = result else {
+        panic!("Expected TTM, got {:?}", result);
+    };
+    assert_eq!(ttm.speed_or_distance_unit, None);
+    assert_eq!(ttm.target_name, None);
+    assert_eq!(ttm.target_status, Some(TtmStatus::Tracking));
+    assert_eq!(
+        ttm.type_of_acquisition,
+        Some(TtmTypeOfAcquisition::Automatic)
+    );
+
+    // malformed required steer direction indicator
+    assert_eq!(
+        parse_lenient_all("$GPAPA,A,A,0.10,X,N,V,V,011,M,DEST"),
+        None
+    );
+
+    // a waypoint too long for the data struct, which doesn't tell its position
+    let result = parse_lenient_all(&format!("$GPBOD,099.3,T,105.6,M,{},POINTA", "W".repeat(70)));
+    let Some(ParseResult::BOD(bod)) = result else {
+        panic!("Expected BOD, got {:?}", result);
+    };
+    assert_relative_eq!(bod.bearing_true.unwrap(), 99.3);
+    assert_eq!(bod.destination_waypoint_id, None);
+    assert_eq!(bod.origin_waypoint_id.as_deref(), Some("POINTA"));
+
+    // well-formed sentences parse as with `parse_str`
+    assert_eq!(
+        parse_lenient_all("$GPHDT,274.07,T*03"),
+        parse_str("$GPHDT,274.07,T*03").ok()
+    );
+
+    assert_eq!(parse_lenient_all(""), None);
+    assert_eq!(parse_lenient_all("garbage"), None);
+    assert_eq!(parse_lenient_all("$GPXYZ,1,2,3*00"), None);
+}
+
+#[test]
+fn test_sentences_iterator() {
+    let log = "\
+# GPS log\r
+$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76\r
+\r
+$GPGSA,A,3,10,07,05,02,29,04,08,13,,,,,1.72,1.03,1.38*0A
+   \t
+$GPGSV,3,1,11,10,63,137,17,07,61,098,15,05,59,290,20,08,54,157,30*71
+$GPGSV,3,2,11,02,39,223,19,13,28,070,17,26,23,252,,04,14,186,14*79
+$GPRMC,092750.000,A,5321.6802,N,00630.3372,W,0.02,31.66,280511,,,A*43
+";
+    let sentences = sentences(log).collect::<Vec<_>>();
+    assert_eq!(sentences.len(), 5);
+
+    let sentence_types = sentences
+        .iter()
+        .map(|sentence| sentence.as_ref().map(|sentence| sentence.message_id))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        sentence_types,
+        [
+            Ok(SentenceType::GGA),
+            Ok(SentenceType::GSA),
+            // corrupted checksum
+            Err(&Error::ChecksumMismatch {
+                calculated: 0x70,
+                found: 0x71
+            }),
+            Ok(SentenceType::GSV),
+            Ok(SentenceType::RMC),
+        ]
+    );
+}
+
+#[test]
+fn test_unsupported_and_wrong_header_errors() {
+    let parsed = parse_sentence("$GPDTM,W84,,0.0,N,0.0,E,0.0,W84*6F").unwrap();
+    assert_eq!(parsed, ParsedSentence::Unsupported(SentenceType::DTM));
+    assert_eq!(
+        parsed.supported(),
+        Err(Error::Unsupported(SentenceType::DTM))
+    );
+
+    let sentence = parse_nmea_sentence("$GPHDT,274.07,T*03").unwrap();
+    assert_eq!(
+        nmea::sentences::parse_gga(sentence),
+        Err(Error::WrongSentenceHeader {
+            expected: SentenceType::GGA,
+            found: SentenceType::HDT
+        })
+    );
+}
+
+#[test]
+fn test_out_of_range_coordinates() {
+    assert_eq!(
+        parse_str("$GPGGA,133605.0,9521.75946,N,03731.93769,E,0,00,,,M,,M,,*43"),
+        Err(Error::InvalidCoordinate(95. + 21.75946 / 60.))
+    );
+    assert_eq!(
+        parse_str("$GPGLL,5107.0013414,N,18102.3279144,W,205412.00,A,A*7F"),
+        Err(Error::InvalidCoordinate(-(181. + 2.3279144 / 60.)))
+    );
+    assert_eq!(
+        parse_str("$GPRMC,225446,A,9000.60,S,12311.12,W,000.5,054.7,191194,020.3,E*71"),
+        Err(Error::InvalidCoordinate(-(90. + 0.6 / 60.)))
+    );
+
+    // the poles and the antimeridian are still valid
+    let ParseResult::RMC(rmc) =
+        parse_str("$GPRMC,225446,A,9000.00,S,18000.00,W,000.5,054.7,191194,020.3,E*7D").unwrap()
+    else {
+        panic!("RMC expected");
+    };
+    assert_eq!(rmc.lat, Some(-90.));
+    assert_eq!(rmc.lon, Some(-180.));
+}
+
+#[test]
+fn test_parse_with_max_len() {
+    use nmea::sentences::*;
+
+    /// Parses `line` with a text bound of `max` characters, below the length
+    /// `text_len` of the first text field which doesn't fit, and of 64.
+    macro_rules! check_max_len {
+        ($parse:ident, $line:expr, $max:literal, $text_len:expr) => {
+            let sentence = parse_nmea_sentence_checked($line).unwrap();
+            assert_eq!(
+                $parse::<$max>(sentence).err(),
+                Some(Error::ParameterLength {
+                    max_length: $max,
+                    parameter_length: $text_len
+                }),
+                "{}",
+                $line
+            );
+            assert!($parse::<64>(sentence).is_ok(), "{}", $line);
+        };
+    }
+
+    check_max_len!(parse_aam_with_max_len, "$GPAAM,A,A,0.10,N,WPTNME*32", 3, 6);
+    check_max_len!(
+        parse_alr_with_max_len,
+        "$IIALR,020000,001,A,V,Bilge pump alarm1*48",
+        16,
+        17
+    );
+    check_max_len!(
+        parse_apa_with_max_len,
+        "$GPAPA,A,A,0.10,R,N,V,V,011,M,DEST*3F",
+        3,
+        4
+    );
+    check_max_len!(
+        parse_bod_with_max_len,
+        "$GPBOD,097.0,T,103.2,M,POINTB,POINTA*4A",
+        3,
+        6
+    );
+    check_max_len!(
+        parse_bwc_with_max_len,
+        "$GPBWC,220516,5130.02,N,00046.34,W,213.8,T,218.0,M,0004.6,N,EGLM*21",
+        3,
+        4
+    );
+    check_max_len!(
+        parse_bwr_with_max_len,
+        "$GPBWR,220516,5130.02,N,00046.34,W,213.8,T,218.0,M,0004.6,N,EGLM*30",
+        3,
+        4
+    );
+    check_max_len!(
+        parse_bww_with_max_len,
+        "$GPBWW,213.8,T,218.0,M,TOWPT,FROMWPT*42",
+        3,
+        5
+    );
+    check_max_len!(
+        parse_epv_with_max_len,
+        "$ECEPV,C,AI,211239680,102,10*24",
+        1,
+        2
+    );
+    check_max_len!(
+        parse_gmp_with_max_len,
+        "$GNGMP,123519.00,UTM,33U,5457812.345,402567.890,AA,08,1.2,105.3,47.1,2.5,0138*63",
+        2,
+        3
+    );
+    check_max_len!(
+        parse_nrx_with_max_len,
+        "$CRNRX,007,001,00,IE69,1,135600,27,06,2001,241,3,A,==========================*09",
+        16,
+        26
+    );
+    check_max_len!(
+        parse_rmb_with_max_len,
+        "$GPRMB,A,4.08,L,EGLL,EGLM,5130.02,N,00046.34,W,004.6,213.9,122.9,A,D*55",
+        3,
+        4
+    );
+    check_max_len!(parse_rmm_with_max_len, "$GPRMM,WGS 84*06", 3, 6);
+    check_max_len!(
+        parse_tll_with_max_len,
+        "$RATLL,01,3646.54,N,00235.37,W,TGT01,124545.00,T,*7A",
+        3,
+        5
+    );
+    check_max_len!(parse_wcv_with_max_len, "$GPWCV,2.5,N,DEST*18", 3, 4);
+    check_max_len!(
+        parse_wnc_with_max_len,
+        "$GPWNC,200.00,N,370.40,K,Dest,Origin*58",
+        3,
+        4
+    );
+    check_max_len!(
+        parse_zfo_with_max_len,
+        "$GPZFO,145832.12,042359.17,WPT*3E",
+        2,
+        3
+    );
+    check_max_len!(
+        parse_ztg_with_max_len,
+        "$GPZTG,145832.12,042359.17,WPT*24",
+        2,
+        3
+    );
+}